@@ -9,65 +9,49 @@
 //! TLDR;
 //! ```
 //! stripe listen --forward-to localhost:4242/stripe_webhooks
-//! Provide webhook secret to construct_event
+//! Provide webhook secret to WebhookConfig
 //! stripe trigger checkout.session.completed
 //! stripe trigger account.updated
 //! ```
 
-use std::borrow::Borrow;
-
-use actix_web::{post, web, App, HttpRequest, HttpResponse, HttpServer};
-use stripe::{EventObject, EventType, Webhook, WebhookError};
+use actix_web::{post, web, App, HttpResponse, HttpServer};
+use stripe::extract::{actix_web::StripeEvent, WebhookConfig};
+use stripe::{EventObject, EventType};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    HttpServer::new(|| App::new().service(webhook_handler)).bind(("127.0.0.1", 4242))?.run().await
+    let config = web::Data::new(WebhookConfig::new("whsec_xxxxx"));
+    HttpServer::new(move || App::new().app_data(config.clone()).service(webhook_handler))
+        .bind(("127.0.0.1", 4242))?
+        .run()
+        .await
 }
 
 #[post("stripe_webhooks")]
-pub async fn webhook_handler(req: HttpRequest, payload: web::Bytes) -> HttpResponse {
-    handle_webhook(req, payload).unwrap();
-    HttpResponse::Ok().finish()
-}
-
-pub fn handle_webhook(req: HttpRequest, payload: web::Bytes) -> Result<(), WebhookError> {
-    let payload_str = std::str::from_utf8(payload.borrow()).unwrap();
-
-    let stripe_signature = get_header_value(&req, "Stripe-Signature").unwrap_or_default();
-
-    if let Ok(event) = Webhook::construct_event(payload_str, stripe_signature, "whsec_xxxxx") {
-        match event.type_ {
-            EventType::AccountUpdated => {
-                if let EventObject::Account(account) = event.data.object {
-                    handle_account_updated(account)?;
-                }
+pub async fn webhook_handler(StripeEvent(event): StripeEvent) -> HttpResponse {
+    match event.type_ {
+        EventType::AccountUpdated => {
+            if let EventObject::Account(account) = event.data.object {
+                handle_account_updated(account);
             }
-            EventType::CheckoutSessionCompleted => {
-                if let EventObject::CheckoutSession(session) = event.data.object {
-                    handle_checkout_session(session)?;
-                }
-            }
-            _ => {
-                println!("Unknown event encountered in webhook: {:?}", event.type_);
+        }
+        EventType::CheckoutSessionCompleted => {
+            if let EventObject::CheckoutSession(session) = event.data.object {
+                handle_checkout_session(session);
             }
         }
-    } else {
-        println!("Failed to construct webhook event, ensure your webhook secret is correct.");
+        _ => {
+            println!("Unknown event encountered in webhook: {:?}", event.type_);
+        }
     }
 
-    Ok(())
-}
-
-fn get_header_value<'b>(req: &'b HttpRequest, key: &'b str) -> Option<&'b str> {
-    req.headers().get(key)?.to_str().ok()
+    HttpResponse::Ok().finish()
 }
 
-fn handle_account_updated(account: stripe::Account) -> Result<(), WebhookError> {
+fn handle_account_updated(account: stripe::Account) {
     println!("Received account updated webhook for account: {:?}", account.id);
-    Ok(())
 }
 
-fn handle_checkout_session(session: stripe::CheckoutSession) -> Result<(), WebhookError> {
+fn handle_checkout_session(session: stripe::CheckoutSession) {
     println!("Received checkout session completed webhook with id: {:?}", session.id);
-    Ok(())
 }
@@ -12,23 +12,16 @@
 //! stripe trigger checkout.session.completed
 //! ```
 
-use axum::{
-    async_trait,
-    body::Body,
-    extract::FromRequest,
-    http::{Request, StatusCode},
-    response::{IntoResponse, Response},
-    routing::post,
-    Error, Router,
-};
-use stripe::{Event, EventObject, EventType};
-
-pub type Result<T, E = Error> = std::result::Result<T, E>;
+use axum::{routing::post, Router};
+use stripe::extract::{axum::StripeEvent, WebhookConfig};
+use stripe::{EventObject, EventType};
 
 #[tokio::main]
 async fn main() {
-    // build our application with a route
-    let app = Router::new().route("/stripe_webhooks", post(handle_webhook));
+    // build our application with a route, verifying webhooks with the endpoint secret
+    let app = Router::new()
+        .route("/stripe_webhooks", post(handle_webhook))
+        .with_state(WebhookConfig::new("whsec_xxxxx"));
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:4242").await.unwrap();
 
@@ -37,34 +30,7 @@ async fn main() {
     axum::serve(listener, app).await.unwrap();
 }
 
-struct StripeEvent(Event);
-
-#[async_trait]
-impl<S> FromRequest<S> for StripeEvent
-where
-    String: FromRequest<S>,
-    S: Send + Sync,
-{
-    type Rejection = Response;
-
-    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
-        let signature = if let Some(sig) = req.headers().get("stripe-signature") {
-            sig.to_owned()
-        } else {
-            return Err(StatusCode::BAD_REQUEST.into_response());
-        };
-
-        let payload =
-            String::from_request(req, state).await.map_err(IntoResponse::into_response)?;
-
-        Ok(Self(
-            stripe::Webhook::construct_event(&payload, signature.to_str().unwrap(), "whsec_xxxxx")
-                .map_err(|_| StatusCode::BAD_REQUEST.into_response())?,
-        ))
-    }
-}
-
-#[axum::debug_handler]
+#[axum::debug_handler(state = WebhookConfig)]
 async fn handle_webhook(StripeEvent(event): StripeEvent) {
     match event.type_ {
         EventType::CheckoutSessionCompleted => {
@@ -575,6 +575,18 @@ pub fn gen_inferred_params(
                 self.starting_after = Some(item.id());
             }",
             );
+            out.push_str("}\n");
+
+            state.use_params.insert("ReversePaginable");
+
+            out.push_str("impl ReversePaginable for ");
+            out.push_str(&params.rust_type);
+            out.push_str("<'_> {\n");
+            out.push_str(
+                "    fn set_first(&mut self, item: Self::O) {
+                self.ending_before = Some(item.id());
+            }",
+            );
             out.push('}');
         }
     }
@@ -1390,6 +1402,14 @@ pub fn gen_impl_requests(
                        client.get_query("/{query_path}", params)
                     }}
                 "#).unwrap();
+                print_with_options(
+                    &mut out,
+                    &rust_struct,
+                    "list",
+                    &format!("params: &{params_name}<'_>"),
+                    &format!("List<{rust_struct}>"),
+                    &format!("client.get_query_with_options(\"/{query_path}\", params, options)"),
+                );
                 methods.insert(MethodTypes::List, out);
             } else if segments.len() == 2 && !methods.contains_key(&MethodTypes::Retrieve) {
                 let id_param = match get_id_param(&get_request.parameters) {
@@ -1415,6 +1435,18 @@ pub fn gen_impl_requests(
                         out.push_str("        client.get_query(");
                         out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                         out.push_str(", Expand { expand })\n");
+                        out.push_str("    }");
+                        print_with_options(
+                            &mut out,
+                            &rust_struct,
+                            "retrieve",
+                            &format!("id: &{id_type}, expand: &[&str]"),
+                            &rust_struct,
+                            &format!(
+                                "client.get_query_with_options(&format!(\"/{}/{{}}\", id), Expand {{ expand }}, options)",
+                                segments[0]
+                            ),
+                        );
                     } else {
                         out.push_str(") -> Response<");
                         out.push_str(&rust_struct);
@@ -1422,8 +1454,19 @@ pub fn gen_impl_requests(
                         out.push_str("        client.get(/");
                         out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                         out.push_str(")\n");
+                        out.push_str("    }");
+                        print_with_options(
+                            &mut out,
+                            &rust_struct,
+                            "retrieve",
+                            &format!("id: &{id_type}"),
+                            &rust_struct,
+                            &format!(
+                                "client.get_with_options(&format!(\"/{}/{{}}\", id), options)",
+                                segments[0]
+                            ),
+                        );
                     }
-                    out.push_str("    }");
                     methods.insert(MethodTypes::Retrieve, out);
                 }
             }
@@ -1478,6 +1521,18 @@ pub fn gen_impl_requests(
                 out.push_str(&segments.join("/"));
                 out.push_str("\", &params)\n");
                 out.push_str("    }");
+                print_with_options(
+                    &mut out,
+                    &rust_struct,
+                    "create",
+                    &format!("params: {params_name}<'_>"),
+                    &return_type,
+                    &format!(
+                        "#[allow(clippy::needless_borrows_for_generic_args)]\n        \
+                         client.post_form_with_options(\"/{}\", &params, options)",
+                        segments.join("/")
+                    ),
+                );
                 methods.insert(MethodTypes::Create, out);
             } else if !methods.contains_key(&MethodTypes::Update) && parameter_count == 1 && update
             {
@@ -1519,6 +1574,18 @@ pub fn gen_impl_requests(
                     out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                     out.push_str(", &params)\n");
                     out.push_str("    }");
+                    print_with_options(
+                        &mut out,
+                        &rust_struct,
+                        "update",
+                        &format!("id: &{id_type}, params: {params_name}<'_>"),
+                        &return_type,
+                        &format!(
+                            "#[allow(clippy::needless_borrows_for_generic_args)]\n        \
+                             client.post_form_with_options(&format!(\"/{}/{{}}\", id), &params, options)",
+                            segments[0]
+                        ),
+                    );
                     methods.insert(MethodTypes::Update, out);
                 }
             } else {
@@ -1567,6 +1634,17 @@ pub fn gen_impl_requests(
                     out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                     out.push_str(")\n");
                     out.push_str("    }");
+                    print_with_options(
+                        &mut out,
+                        &rust_struct,
+                        "delete",
+                        &format!("id: &{id_type}"),
+                        &format!("Deleted<{id_type}>"),
+                        &format!(
+                            "client.delete_with_options(&format!(\"/{}/{{}}\", id), options)",
+                            segments[0]
+                        ),
+                    );
                     methods.insert(MethodTypes::Delete, out);
                 }
             } else {
@@ -1580,6 +1658,7 @@ pub fn gen_impl_requests(
     } else {
         // Add imports
         state.use_config.insert("Client");
+        state.use_config.insert("RequestOptions");
         state.use_config.insert("Response");
 
         // Output the impl block
@@ -1590,3 +1669,25 @@ pub fn gen_impl_requests(
         ))
     }
 }
+
+/// Print the variant of an api method which applies [`RequestOptions`] to the request only.
+fn print_with_options(
+    out: &mut String,
+    rust_struct: &str,
+    method: &str,
+    params: &str,
+    return_type: &str,
+    call: &str,
+) {
+    writedoc!(
+        out,
+        r#"
+
+
+            /// Like [`{rust_struct}::{method}`], but with [`RequestOptions`] for this request only.
+            pub fn {method}_with_options(client: &Client, {params}, options: RequestOptions) -> Response<{return_type}> {{
+                {call}
+            }}"#
+    )
+    .unwrap();
+}
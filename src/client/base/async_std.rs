@@ -1,12 +1,12 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::time::Duration;
 
-use async_std::task::sleep;
-use http_types::{Request, StatusCode};
-use serde::de::DeserializeOwned;
+pub(crate) use async_std::task::sleep;
+use http_types::Request;
 
-use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use crate::client::transport::{Transport, TransportFuture};
+use crate::error::StripeError;
 
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
 
@@ -22,6 +22,53 @@ pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
     Box::pin(future::ready(Err(err)))
 }
 
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn execute<T: Send + 'static>(
+    future: impl Future<Output = Result<T, StripeError>> + Send + 'static,
+) -> Response<T> {
+    Box::pin(future)
+}
+
+/// Run a response in the background, so it makes progress without being polled.
+#[cfg(feature = "stream")]
+pub(crate) fn spawn<T: Send + 'static>(response: Response<T>) -> Response<T> {
+    Box::pin(async_std::task::spawn(response))
+}
+
+/// Run blocking I/O from async code, such as the webhook router, on a separate thread.
+///
+/// N.B. `async_std::task::spawn_blocking` is unstable in the supported versions of
+///      async-std, so the I/O runs on its own thread and the result is sent back.
+#[cfg(feature = "webhook-events")]
+pub(crate) async fn spawn_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> std::io::Result<T> {
+    let (tx, rx) = async_std::channel::bounded(1);
+    std::thread::spawn(move || {
+        // the receiver is gone if the caller stopped waiting
+        let _ = tx.try_send(f());
+    });
+    // N.B. the sender is dropped without a value only if `f` panicked
+    rx.recv().await.unwrap_or_else(|_| Err(std::io::Error::other("blocking task panicked")))
+}
+
+/// Apply a fallible function to the result of a response once it resolves.
+pub(crate) fn and_then<T: Send + 'static, U: Send + 'static>(
+    response: Response<T>,
+    f: impl FnOnce(T) -> Result<U, StripeError> + Send + 'static,
+) -> Response<U> {
+    Box::pin(async move { f(response.await?) })
+}
+
+/// Run a request future, failing with [`StripeError::Timeout`] if it takes longer than `duration`.
+pub(crate) async fn timeout<T>(
+    duration: Duration,
+    future: impl Future<Output = Result<T, StripeError>>,
+) -> Result<T, StripeError> {
+    async_std::future::timeout(duration, future).await.unwrap_or(Err(StripeError::Timeout))
+}
+
 #[derive(Clone)]
 pub struct AsyncStdClient {
     client: surf::Client,
@@ -39,116 +86,55 @@ impl AsyncStdClient {
         Self { client: surf::Client::new() }
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
-        &self,
-        request: Request,
-        strategy: &RequestStrategy,
-    ) -> Response<T> {
-        // need to clone here since client could be used across threads.
-        // N.B. Client is send sync; cloned clients share the same pool.
-        let client = self.client.clone();
-        let strategy = strategy.clone();
-
-        Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
-        })
+    /// Creates a new client which gives up on connecting to stripe after the given duration.
+    ///
+    /// # Panics
+    ///
+    /// If the curl backend of surf can't be initialised, like [`surf::Client::new`].
+    pub fn with_connect_timeout(timeout: Duration) -> Self {
+        use isahc::config::Configurable;
+
+        // N.B. surf doesn't expose the connection phase of a request, so the timeout is set
+        //      on its default curl backend directly
+        let client = isahc::HttpClient::builder()
+            .connect_timeout(timeout)
+            .build()
+            .expect("failed to initialise the curl client");
+        let client = http_client::isahc::IsahcClient::from_client(client);
+        Self { client: surf::Client::with_http_client(client) }
     }
 }
 
-async fn send_inner(
-    client: &surf::Client,
-    mut request: Request,
-    strategy: &RequestStrategy,
-) -> Result<Vec<u8>, StripeError> {
-    let mut tries = 0;
-    let mut last_status: Option<StatusCode> = None;
-    let mut last_retry_header: Option<bool> = None;
-
-    // if we have no last error, then the strategy is invalid
-    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
-
-    if let Some(key) = strategy.get_key() {
-        request.insert_header("Idempotency-Key", key);
-    }
-
-    let body = request.body_bytes().await?;
-
-    loop {
-        return match strategy.test(last_status, last_retry_header, tries) {
-            Outcome::Stop => Err(last_error),
-            Outcome::Continue(duration) => {
-                if let Some(duration) = duration {
-                    sleep(duration).await;
-                }
-
-                // we need to clone the request before sending it so we can
-                // re-use it if we need to retry. ditto for the body
-                let mut request = request.clone();
-                request.set_body(body.clone());
-
-                let mut response = match client.send(request).await {
-                    Ok(response) => response,
-                    Err(err) => {
-                        last_error = StripeError::from(err);
-                        tries += 1;
-                        continue;
-                    }
-                };
-
-                let status = response.status();
-                let retry = response
-                    .header("Stripe-Should-Retry")
-                    .and_then(|s| s.last().as_str().parse().ok());
-
-                // if this fails parsing, we can probably just exit
-                let bytes = response.body_bytes().await?;
-
-                if !status.is_success() {
-                    tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
-                    last_status = Some(status);
-                    last_retry_header = retry;
-
-                    continue;
-                }
+impl Transport for AsyncStdClient {
+    fn send(&self, request: Request) -> TransportFuture {
+        // need to clone here since client could be used across threads.
+        // N.B. Client is send sync; cloned clients share the same pool.
+        let client = self.client.clone();
 
-                Ok(bytes)
-            }
-        };
+        Box::pin(async move { Ok(client.send(request).await?.into()) })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use http_types::{Request, Url};
     use httpmock::prelude::*;
 
-    use super::AsyncStdClient;
-    use crate::{client::request_strategy::RequestStrategy, StripeError};
+    use crate::{client::request_strategy::RequestStrategy, Client, StripeError};
 
     #[async_std::test]
     async fn retry() {
-        let client = AsyncStdClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(GET).path("/server-errors");
+            when.method(GET).path("/v1/server-errors");
             then.status(500);
         });
 
-        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.get::<()>("server-errors").await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
@@ -156,10 +142,10 @@ mod tests {
 
     #[async_std::test]
     async fn user_error() {
-        let client = AsyncStdClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3));
 
         let mock = server.mock(|when, then| {
             when.method(GET).path("/v1/missing");
@@ -172,8 +158,7 @@ mod tests {
               ");
         });
 
-        let req = Request::get(Url::parse(&server.url("/v1/missing")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.get::<()>("missing").await;
 
         mock.assert_hits_async(1).await;
 
@@ -185,19 +170,18 @@ mod tests {
 
     #[async_std::test]
     async fn retry_header() {
-        let client = AsyncStdClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(GET).path("/server-errors");
+            when.method(GET).path("/v1/server-errors");
             then.status(500).header("Stripe-Should-Retry", "false");
         });
 
-        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.get::<()>("server-errors").await;
 
         hello_mock.assert_hits_async(1).await;
         assert!(res.is_err());
@@ -205,22 +189,64 @@ mod tests {
 
     #[async_std::test]
     async fn retry_body() {
-        let client = AsyncStdClient::new();
+        use std::collections::HashMap;
 
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(POST).path("/server-errors").body("body");
+            when.method(POST).path("/v1/server-errors").body("body=test");
             then.status(500);
         });
 
-        let mut req = Request::post(Url::parse(&server.url("/server-errors")).unwrap());
-        req.set_body("body");
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res =
+            client.post_form::<(), _>("server-errors", HashMap::from([("body", "test")])).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
     }
+
+    #[async_std::test]
+    async fn attempt_timeout() {
+        use std::time::Duration;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3))
+            .with_attempt_timeout(Duration::from_millis(50));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/slow");
+            then.status(200).body("{}").delay(Duration::from_secs(1));
+        });
+
+        let res = client.get::<()>("slow").await;
+
+        mock.assert_hits_async(3).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+
+    #[async_std::test]
+    async fn connect_timeout() {
+        use std::time::Duration;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_connect_timeout(Duration::from_secs(5));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/fast");
+            then.status(200).body("{}");
+        });
+
+        let res = client.get::<serde_json::Value>("fast").await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_ok());
+    }
 }
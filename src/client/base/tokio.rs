@@ -1,54 +1,72 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::time::Duration;
 
 use http_types::{Request, StatusCode};
 use hyper::http;
 use hyper::{client::HttpConnector, Body};
-use serde::de::DeserializeOwned;
-use tokio::time::sleep;
+pub(crate) use tokio::time::sleep;
 
-use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use crate::client::transport::{Transport, TransportFuture};
+use crate::error::StripeError;
 
 #[cfg(feature = "hyper-rustls-native")]
 mod connector {
+    use std::time::Duration;
+
     use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_rustls::HttpsConnector;
     use hyper_rustls::HttpsConnectorBuilder;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
+    pub fn create(connect_timeout: Option<Duration>) -> HttpsConnector<HttpConnector<GaiResolver>> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(connect_timeout);
+
         HttpsConnectorBuilder::new()
             .with_native_roots()
             .https_or_http()
             .enable_http1()
             .enable_http2()
-            .build()
+            .wrap_connector(http)
     }
 }
 
 #[cfg(feature = "hyper-rustls-webpki")]
 mod connector {
+    use std::time::Duration;
+
     use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_rustls::HttpsConnector;
     use hyper_rustls::HttpsConnectorBuilder;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
+    pub fn create(connect_timeout: Option<Duration>) -> HttpsConnector<HttpConnector<GaiResolver>> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(connect_timeout);
+
         HttpsConnectorBuilder::new()
             .with_webpki_roots()
             .https_or_http()
             .enable_http1()
             .enable_http2()
-            .build()
+            .wrap_connector(http)
     }
 }
 
 #[cfg(feature = "hyper-tls")]
 mod connector {
+    use std::time::Duration;
+
     use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_tls::HttpsConnector;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
-        HttpsConnector::new()
+    pub fn create(connect_timeout: Option<Duration>) -> HttpsConnector<HttpConnector<GaiResolver>> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(connect_timeout);
+
+        HttpsConnector::new_with_connector(http)
     }
 }
 
@@ -71,6 +89,58 @@ pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
     Box::pin(future::ready(Err(err)))
 }
 
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn execute<T: Send + 'static>(
+    future: impl Future<Output = Result<T, StripeError>> + Send + 'static,
+) -> Response<T> {
+    Box::pin(future)
+}
+
+/// Run a response in the background, so it makes progress without being polled.
+#[cfg(feature = "stream")]
+#[allow(dead_code)]
+pub(crate) fn spawn<T: Send + 'static>(response: Response<T>) -> Response<T> {
+    let handle = tokio::spawn(response);
+    Box::pin(async move {
+        match handle.await {
+            Ok(res) => res,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => Err(StripeError::ClientError(e.to_string())),
+        }
+    })
+}
+
+/// Run blocking I/O on the blocking thread pool of the runtime.
+#[cfg(feature = "webhook-events")]
+#[allow(dead_code)]
+pub(crate) async fn spawn_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> std::io::Result<T> {
+    match tokio::task::spawn_blocking(f).await {
+        Ok(res) => res,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => Err(std::io::Error::other(e)),
+    }
+}
+
+/// Apply a fallible function to the result of a response once it resolves.
+#[allow(dead_code)]
+pub(crate) fn and_then<T: Send + 'static, U: Send + 'static>(
+    response: Response<T>,
+    f: impl FnOnce(T) -> Result<U, StripeError> + Send + 'static,
+) -> Response<U> {
+    Box::pin(async move { f(response.await?) })
+}
+
+/// Run a request future, failing with [`StripeError::Timeout`] if it takes longer than `duration`.
+pub(crate) async fn timeout<T>(
+    duration: Duration,
+    future: impl Future<Output = Result<T, StripeError>>,
+) -> Result<T, StripeError> {
+    tokio::time::timeout(duration, future).await.unwrap_or(Err(StripeError::Timeout))
+}
+
 #[derive(Clone)]
 pub struct TokioClient {
     client: HttpClient,
@@ -84,104 +154,32 @@ impl Default for TokioClient {
 
 impl TokioClient {
     pub fn new() -> Self {
-        Self {
-            client: hyper::Client::builder().pool_max_idle_per_host(0).build(connector::create()),
-        }
+        Self::from_connector(connector::create(None))
+    }
+
+    /// Create a client which gives up on connecting to stripe after the given duration.
+    pub fn with_connect_timeout(timeout: Duration) -> Self {
+        Self::from_connector(connector::create(Some(timeout)))
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
-        &self,
-        request: Request,
-        strategy: &RequestStrategy,
-    ) -> Response<T> {
+    fn from_connector(connector: connector::HttpsConnector<HttpConnector>) -> Self {
+        Self { client: hyper::Client::builder().pool_max_idle_per_host(0).build(connector) }
+    }
+}
+
+impl Transport for TokioClient {
+    fn send(&self, request: Request) -> TransportFuture {
         // need to clone here since client could be used across threads.
         // N.B. Client is send sync; cloned clients share the same pool.
         let client = self.client.clone();
-        let strategy = strategy.clone();
 
         Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
+            let response = client.request(convert_request(request).await).await?;
+            convert_response(response).await
         })
     }
 }
 
-async fn send_inner(
-    client: &HttpClient,
-    mut request: Request,
-    strategy: &RequestStrategy,
-) -> Result<hyper::body::Bytes, StripeError> {
-    let mut tries = 0;
-    let mut last_status: Option<StatusCode> = None;
-    let mut last_retry_header: Option<bool> = None;
-
-    // if we have no last error, then the strategy is invalid
-    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
-
-    if let Some(key) = strategy.get_key() {
-        request.insert_header("Idempotency-Key", key);
-    }
-
-    let body = request.body_bytes().await?;
-
-    loop {
-        return match strategy.test(last_status, last_retry_header, tries) {
-            Outcome::Stop => Err(last_error),
-            Outcome::Continue(duration) => {
-                if let Some(duration) = duration {
-                    sleep(duration).await;
-                }
-
-                // note: http::Request provides no easy way to clone, so we perform
-                //       the conversion from the clonable http_types::Request each time
-                //       obviously cloning before the first request is not ideal
-                let mut request = request.clone();
-                request.set_body(body.clone());
-
-                let response = match client.request(convert_request(request).await).await {
-                    Ok(response) => response,
-                    Err(err) => {
-                        last_error = StripeError::from(err);
-                        tries += 1;
-                        continue;
-                    }
-                };
-
-                let status = response.status();
-                let retry = response
-                    .headers()
-                    .get("Stripe-Should-Retry")
-                    .and_then(|s| s.to_str().ok())
-                    .and_then(|s| s.parse().ok());
-
-                let bytes = hyper::body::to_bytes(response.into_body()).await?;
-
-                if !status.is_success() {
-                    tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
-                    last_status = Some(
-                        // NOTE: StatusCode::from can panic here, so fall back to InternalServerError
-                        //       see https://github.com/http-rs/http-types/blob/ac5d645ce5294554b86ebd49233d3ec01665d1d7/src/hyperium_http.rs#L20-L24
-                        StatusCode::try_from(u16::from(status))
-                            .unwrap_or(StatusCode::InternalServerError),
-                    );
-                    last_retry_header = retry;
-                    continue;
-                }
-
-                Ok(bytes)
-            }
-        };
-    }
-}
-
 /// convert an http_types::Request with a http_types::Body into a http::Request<hyper::Body>
 ///
 /// note: this is necesarry because `http` deliberately does not support a `Body` type
@@ -192,16 +190,40 @@ async fn convert_request(mut request: http_types::Request) -> http::Request<hype
     http::Request::from_parts(request.into_parts().0, hyper::Body::from(body))
 }
 
+/// convert a http::Response<hyper::Body> into an http_types::Response, buffering the body
+async fn convert_response(
+    response: http::Response<hyper::Body>,
+) -> Result<http_types::Response, StripeError> {
+    let (parts, body) = response.into_parts();
+
+    // NOTE: StatusCode::from can panic here, so fall back to InternalServerError
+    //       see https://github.com/http-rs/http-types/blob/ac5d645ce5294554b86ebd49233d3ec01665d1d7/src/hyperium_http.rs#L20-L24
+    let status =
+        StatusCode::try_from(parts.status.as_u16()).unwrap_or(StatusCode::InternalServerError);
+
+    let mut response = http_types::Response::new(status);
+    for (name, value) in parts.headers.iter() {
+        if let Ok(value) = value.to_str() {
+            response.append_header(name.as_str(), value);
+        }
+    }
+
+    let bytes = hyper::body::to_bytes(body).await?;
+    response.set_body(bytes.to_vec());
+
+    Ok(response)
+}
+
 #[cfg(test)]
 mod tests {
-    use http_types::{Method, Request, Url};
+    use http_types::{Method, Request};
+    #[cfg(feature = "async")]
     use httpmock::prelude::*;
     use hyper::{body::to_bytes, Body, Request as HyperRequest};
 
     use super::convert_request;
-    use super::TokioClient;
-    use crate::client::request_strategy::RequestStrategy;
-    use crate::StripeError;
+    #[cfg(feature = "async")]
+    use crate::{Client, RequestStrategy, StripeError};
 
     const TEST_URL: &str = "https://api.stripe.com/v1/";
 
@@ -254,32 +276,33 @@ mod tests {
         assert_eq!(to_bytes(a_body).await.unwrap().len(), to_bytes(b_body).await.unwrap().len());
     }
 
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn retry() {
-        let client = TokioClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(GET).path("/server-errors");
+            when.method(GET).path("/v1/server-errors");
             then.status(500);
         });
 
-        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.get::<()>("server-errors").await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
     }
 
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn user_error() {
-        let client = TokioClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3));
 
         let mock = server.mock(|when, then| {
             when.method(GET).path("/v1/missing");
@@ -292,8 +315,7 @@ mod tests {
               ");
         });
 
-        let req = Request::get(Url::parse(&server.url("/v1/missing")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.get::<()>("missing").await;
 
         mock.assert_hits_async(1).await;
 
@@ -303,6 +325,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn nice_serde_error() {
         use serde::Deserialize;
@@ -316,10 +339,10 @@ mod tests {
             name: String,
         }
 
-        let client = TokioClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3));
 
         let mock = server.mock(|when, then| {
             when.method(GET).path("/v1/odd_data");
@@ -332,8 +355,7 @@ mod tests {
             );
         });
 
-        let req = Request::get(Url::parse(&server.url("/v1/odd_data")).unwrap());
-        let res = client.execute::<DataType>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.get::<DataType>("odd_data").await;
 
         mock.assert_hits_async(1).await;
 
@@ -345,44 +367,138 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn retry_header() {
-        let client = TokioClient::new();
-
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(GET).path("/server-errors");
+            when.method(GET).path("/v1/server-errors");
             then.status(500).header("Stripe-Should-Retry", "false");
         });
 
-        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.get::<()>("server-errors").await;
 
         hello_mock.assert_hits_async(1).await;
         assert!(res.is_err());
     }
 
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retry_after() {
+        use std::time::Duration;
+
+        use crate::RetryPolicy;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let policy = RetryPolicy::new(5).budget(Duration::from_millis(500));
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Policy(policy));
+
+        // Create a mock on the server.
+        let hello_mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/rate-limited");
+            then.status(429).header("Retry-After", "1");
+        });
+
+        let res = client.get::<()>("rate-limited").await;
+
+        // waiting for the retry would exceed the budget
+        hello_mock.assert_hits_async(1).await;
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn retry_body() {
-        let client = TokioClient::new();
+        use std::collections::HashMap;
 
         // Start a lightweight mock server.
         let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(5));
 
         // Create a mock on the server.
         let hello_mock = server.mock(|when, then| {
-            when.method(POST).path("/server-errors").body("body");
+            when.method(POST).path("/v1/server-errors").body("body=test");
             then.status(500);
         });
 
-        let mut req = Request::post(Url::parse(&server.url("/server-errors")).unwrap());
-        req.set_body("body");
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res =
+            client.post_form::<(), _>("server-errors", HashMap::from([("body", "test")])).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
     }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn attempt_timeout() {
+        use std::time::Duration;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3))
+            .with_attempt_timeout(Duration::from_millis(50));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/slow");
+            then.status(200).body("{}").delay(Duration::from_secs(1));
+        });
+
+        let res = client.get::<()>("slow").await;
+
+        mock.assert_hits_async(3).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn timeout_cuts_off_backoff() {
+        use std::time::Duration;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::ExponentialBackoff(5))
+            .with_timeout(Duration::from_secs(2));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/server-errors");
+            then.status(500);
+        });
+
+        // the delays are 1 then 2 seconds, so the timeout expires before the second attempt
+        let res = client.get::<()>("server-errors").await;
+
+        mock.assert_hits_async(1).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn connect_timeout() {
+        use std::time::Duration;
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_connect_timeout(Duration::from_secs(5));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/fast");
+            then.status(200).body("{}");
+        });
+
+        let res = client.get::<serde_json::Value>("fast").await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_ok());
+    }
 }
@@ -1,15 +1,7 @@
-use std::{sync::Arc, time::Duration};
+use std::{future::Future, sync::OnceLock};
 
-use http_types::Request;
-use serde::de::DeserializeOwned;
-
-use crate::client::base::tokio::TokioClient;
-use crate::client::request_strategy::RequestStrategy;
 use crate::error::StripeError;
 
-/// The delay after which the blocking `Client` will assume the request has failed.
-const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
-
 pub type Response<T> = Result<T, StripeError>;
 
 #[inline(always)]
@@ -22,46 +14,98 @@ pub(crate) fn err<T>(err: crate::StripeError) -> Response<T> {
     Err(err)
 }
 
-#[derive(Clone)]
-pub struct TokioBlockingClient {
-    inner: TokioClient,
-    runtime: Arc<tokio::runtime::Runtime>,
+/// Drive a request future to completion on the shared blocking runtime.
+///
+/// N.B. Timeouts are applied by the `Client` inside the future, since the
+///      `tokio::time` timers must be created from within a running async context.
+pub(crate) fn execute<T: Send + 'static>(
+    future: impl Future<Output = Result<T, StripeError>> + Send + 'static,
+) -> Response<T> {
+    runtime().block_on(future)
 }
 
-impl Default for TokioBlockingClient {
-    fn default() -> Self {
-        Self::new()
+/// Run blocking I/O from async code, such as the webhook router, on the blocking thread
+/// pool of the shared runtime.
+///
+/// N.B. The handle can be awaited from any executor, so this works whichever one the
+///      async code runs on.
+#[cfg(feature = "webhook-events")]
+pub(crate) async fn spawn_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> std::io::Result<T> {
+    match runtime().spawn_blocking(f).await {
+        Ok(res) => res,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => Err(std::io::Error::other(e)),
     }
 }
 
-impl TokioBlockingClient {
-    /// Creates a new client pointed to `https://api.stripe.com/`
-    pub fn new() -> TokioBlockingClient {
-        TokioBlockingClient::from_async(TokioClient::new())
-    }
+/// Apply a fallible function to the result of a response.
+pub(crate) fn and_then<T, U>(
+    response: Response<T>,
+    f: impl FnOnce(T) -> Result<U, StripeError>,
+) -> Response<U> {
+    response.and_then(f)
+}
 
-    fn from_async(inner: TokioClient) -> TokioBlockingClient {
-        let runtime = tokio::runtime::Builder::new_current_thread()
+/// The runtime used to drive requests made by blocking clients.
+///
+/// N.B. This is a current thread runtime shared by all clients, so requests
+///      made from several threads at once take turns driving it.
+fn runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_current_thread()
             .enable_io()
             .enable_time() // use separate `io/time` instead of `all` to ensure `tokio/time` is enabled
             .build()
-            .expect("should be able to get a runtime");
-        TokioBlockingClient { inner, runtime: Arc::new(runtime) }
+            .expect("should be able to get a runtime")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use httpmock::prelude::*;
+
+    use crate::{Client, RequestStrategy, StripeError};
+
+    #[test]
+    fn attempt_timeout() {
+        // Start a lightweight mock server.
+        let server = MockServer::start();
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3))
+            .with_attempt_timeout(Duration::from_millis(50));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/slow");
+            then.status(200).body("{}").delay(Duration::from_secs(1));
+        });
+
+        let res = client.get::<()>("slow");
+
+        mock.assert_hits(3);
+        assert!(matches!(res, Err(StripeError::Timeout)));
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
-        &self,
-        request: Request,
-        strategy: &RequestStrategy,
-    ) -> Response<T> {
-        let future = self.inner.execute(request, strategy);
-        match self.runtime.block_on(async {
-            // N.B. The `tokio::time::timeout` must be called from within a running async
-            //      context or else it will panic (it registers with the thread-local timer).
-            tokio::time::timeout(DEFAULT_TIMEOUT, future).await
-        }) {
-            Ok(finished) => finished,
-            Err(_) => Err(StripeError::Timeout),
-        }
+    #[test]
+    fn request_timeout() {
+        // Start a lightweight mock server.
+        let server = MockServer::start();
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Retry(3))
+            .with_timeout(Duration::from_millis(50));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/slow");
+            then.status(200).body("{}").delay(Duration::from_secs(1));
+        });
+
+        let res = client.get::<()>("slow");
+
+        mock.assert_hits(1);
+        assert!(matches!(res, Err(StripeError::Timeout)));
     }
 }
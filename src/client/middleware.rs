@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use http_types::Request;
+
+use crate::client::{Transport, TransportFuture};
+
+/// A hook which is called for every attempt at a request made by the [`Client`](crate::Client).
+///
+/// Middleware sits between the client and its [`Transport`], so it sees each request after
+/// the idempotency key and headers are set, and each raw response before it is checked for
+/// errors and deserialized. It can change the request, hand it on using [`Next::run`], and
+/// inspect or change the response. Retries are made above the middleware, so a request
+/// which is retried passes through it several times.
+///
+/// ```no_run
+/// use std::time::Instant;
+///
+/// use http_types::Request;
+/// use stripe::{Client, Middleware, Next, TransportFuture};
+///
+/// struct Timing;
+///
+/// impl Middleware for Timing {
+///     fn handle(&self, request: Request, next: Next) -> TransportFuture {
+///         let path = request.url().path().to_string();
+///         Box::pin(async move {
+///             let start = Instant::now();
+///             let response = next.run(request).await;
+///             println!("{} took {:?}", path, start.elapsed());
+///             response
+///         })
+///     }
+/// }
+///
+/// let client = Client::new("sk_test_123").with_middleware(Timing);
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handle an attempt at a request, usually by passing it on to `next`.
+    fn handle(&self, request: Request, next: Next) -> TransportFuture;
+}
+
+/// The rest of the middleware chain, ending with the transport.
+#[derive(Clone)]
+pub struct Next {
+    middleware: Arc<Vec<Arc<dyn Middleware>>>,
+    transport: Arc<dyn Transport>,
+    index: usize,
+}
+
+impl Next {
+    pub(crate) fn new(
+        middleware: Arc<Vec<Arc<dyn Middleware>>>,
+        transport: Arc<dyn Transport>,
+    ) -> Self {
+        Self { middleware, transport, index: 0 }
+    }
+
+    /// Pass the request on to the next middleware, or send it if there are none left.
+    pub fn run(self, request: Request) -> TransportFuture {
+        match self.middleware.get(self.index).cloned() {
+            Some(middleware) => {
+                let next = Next { index: self.index + 1, ..self };
+                middleware.handle(request, next)
+            }
+            None => self.transport.send(request),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use http_types::{Request, Response, StatusCode};
+
+    use super::{Middleware, Next};
+    use crate::{Client, Transport, TransportFuture};
+
+    /// A transport which echoes the `Test-Header` of the request in its response.
+    struct Echo;
+
+    impl Transport for Echo {
+        fn send(&self, request: Request) -> TransportFuture {
+            let header = request.header("Test-Header").map(|h| h.last().to_string());
+            Box::pin(async move {
+                let mut response = Response::new(StatusCode::Ok);
+                response.set_body(serde_json::json!({ "header": header }));
+                Ok(response)
+            })
+        }
+    }
+
+    /// Records the order it is called in, and adds a header to the request.
+    struct Recorder(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+    impl Middleware for Recorder {
+        fn handle(&self, mut request: Request, next: Next) -> TransportFuture {
+            self.1.lock().unwrap().push(self.0);
+            let value = match request.header("Test-Header") {
+                Some(h) => format!("{},{}", h.last(), self.0),
+                None => self.0.to_string(),
+            };
+            request.insert_header("Test-Header", value);
+            next.run(request)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn middleware_chain() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let client = Client::new("sk_test_123")
+            .with_transport(Echo)
+            .with_middleware(Recorder("first", calls.clone()))
+            .with_middleware(Recorder("second", calls.clone()));
+
+        let res = client.get::<serde_json::Value>("customers").await.unwrap();
+
+        assert_eq!(res["header"], "first,second");
+        assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn middleware_chain() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let client = Client::new("sk_test_123")
+            .with_transport(Echo)
+            .with_middleware(Recorder("first", calls.clone()))
+            .with_middleware(Recorder("second", calls.clone()));
+
+        let res = client.get::<serde_json::Value>("customers").unwrap();
+
+        assert_eq!(res["header"], "first,second");
+        assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn middleware_sees_every_attempt() {
+        use crate::RequestStrategy;
+
+        struct Failing;
+
+        impl Transport for Failing {
+            fn send(&self, _request: Request) -> TransportFuture {
+                Box::pin(async { Ok(Response::new(StatusCode::InternalServerError)) })
+            }
+        }
+
+        /// Replaces server errors with an empty object.
+        struct Recover;
+
+        impl Middleware for Recover {
+            fn handle(&self, request: Request, next: Next) -> TransportFuture {
+                Box::pin(async move {
+                    let mut response = next.run(request).await?;
+                    if response.status().is_server_error() {
+                        response = Response::new(StatusCode::Ok);
+                        response.set_body("{}");
+                    }
+                    Ok(response)
+                })
+            }
+        }
+
+        let calls = Arc::new(Mutex::new(vec![]));
+        let client = Client::new("sk_test_123")
+            .with_transport(Failing)
+            .with_strategy(RequestStrategy::Retry(3))
+            .with_middleware(Recorder("attempt", calls.clone()));
+
+        let res = client.get::<serde_json::Value>("customers").await;
+        assert!(res.is_err());
+        assert_eq!(calls.lock().unwrap().len(), 3);
+
+        // responses can be changed before the client checks them
+        let res = client.with_middleware(Recover).get::<serde_json::Value>("customers").await;
+        assert!(res.is_ok());
+    }
+}
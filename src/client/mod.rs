@@ -1,5 +1,11 @@
+mod middleware;
+mod rate_limiter;
+mod request_options;
 mod request_strategy;
+mod response;
+mod retry_policy;
 mod stripe;
+mod transport;
 
 mod base {
     #[cfg(any(
@@ -29,8 +35,12 @@ mod base {
     feature = "runtime-blocking-rustls-webpki"
 ))]
 pub(crate) mod config {
-    pub(crate) use super::base::tokio_blocking::{err, ok};
-    pub use super::base::tokio_blocking::{Response, TokioBlockingClient as BaseClient};
+    pub use super::base::tokio::TokioClient as BaseClient;
+    pub(crate) use super::base::tokio::{sleep, timeout};
+    #[cfg(feature = "webhook-events")]
+    pub(crate) use super::base::tokio_blocking::spawn_blocking;
+    pub use super::base::tokio_blocking::Response;
+    pub(crate) use super::base::tokio_blocking::{and_then, err, execute, ok};
 }
 
 #[cfg(any(
@@ -39,13 +49,21 @@ pub(crate) mod config {
     feature = "runtime-tokio-hyper-rustls-webpki"
 ))]
 pub(crate) mod config {
-    pub(crate) use super::base::tokio::{err, ok};
+    #[cfg(feature = "stream")]
+    pub(crate) use super::base::tokio::spawn;
+    #[cfg(feature = "webhook-events")]
+    pub(crate) use super::base::tokio::spawn_blocking;
+    pub(crate) use super::base::tokio::{and_then, err, execute, ok, sleep, timeout};
     pub use super::base::tokio::{Response, TokioClient as BaseClient};
 }
 
 #[cfg(feature = "runtime-async-std-surf")]
 pub(crate) mod config {
-    pub(crate) use super::base::async_std::{err, ok};
+    #[cfg(feature = "stream")]
+    pub(crate) use super::base::async_std::spawn;
+    #[cfg(feature = "webhook-events")]
+    pub(crate) use super::base::async_std::spawn_blocking;
+    pub(crate) use super::base::async_std::{and_then, err, execute, ok, sleep, timeout};
     pub use super::base::async_std::{AsyncStdClient as BaseClient, Response};
 }
 
@@ -64,6 +82,12 @@ pub use config::BaseClient;
 /// type Response<T> = Box<dyn Future<Result<T, Error>>>;
 /// ```
 pub use config::Response;
+pub use middleware::{Middleware, Next};
+pub use rate_limiter::RateLimiter;
+pub use request_options::RequestOptions;
 pub use request_strategy::RequestStrategy;
+pub use response::StripeResponse;
+pub use retry_policy::{Jitter, RetryPolicy};
 
 pub use self::stripe::Client;
+pub use self::transport::{Transport, TransportFuture};
@@ -0,0 +1,266 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use http_types::Method;
+
+/// A client-side rate limiter, used with [`Client::with_rate_limiter`](crate::Client::with_rate_limiter).
+///
+/// Requests are throttled before they are sent using a token bucket per mode (live or test,
+/// depending on the secret key) and kind of request (reads are `GET` requests, everything
+/// else is a write). The defaults match stripe's documented limits of 100 requests per second
+/// in live mode and 25 requests per second in test mode.
+///
+/// When stripe responds with `429 Too Many Requests` the rate of the bucket is halved, and it
+/// recovers gradually as requests succeed.
+///
+/// Clones of a limiter, and of any client using it, share the same budget.
+///
+/// ```no_run
+/// use stripe::{Client, RateLimiter};
+///
+/// let limiter = RateLimiter::new().test_read(10.0).test_write(5.0);
+/// let client = Client::new("sk_test_123").with_rate_limiter(limiter);
+/// ```
+///
+/// See: <https://stripe.com/docs/rate-limits>
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    buckets: Arc<[Mutex<Bucket>; 4]>,
+}
+
+impl RateLimiter {
+    /// Create a limiter using stripe's default limits.
+    pub fn new() -> Self {
+        Self {
+            buckets: Arc::new([
+                Mutex::new(Bucket::new(100.0)),
+                Mutex::new(Bucket::new(100.0)),
+                Mutex::new(Bucket::new(25.0)),
+                Mutex::new(Bucket::new(25.0)),
+            ]),
+        }
+    }
+
+    /// Set the number of read requests per second allowed in live mode.
+    pub fn live_read(self, per_second: f64) -> Self {
+        self.set_rate(Mode::Live, Access::Read, per_second)
+    }
+
+    /// Set the number of write requests per second allowed in live mode.
+    pub fn live_write(self, per_second: f64) -> Self {
+        self.set_rate(Mode::Live, Access::Write, per_second)
+    }
+
+    /// Set the number of read requests per second allowed in test mode.
+    pub fn test_read(self, per_second: f64) -> Self {
+        self.set_rate(Mode::Test, Access::Read, per_second)
+    }
+
+    /// Set the number of write requests per second allowed in test mode.
+    pub fn test_write(self, per_second: f64) -> Self {
+        self.set_rate(Mode::Test, Access::Write, per_second)
+    }
+
+    fn set_rate(self, mode: Mode, access: Access, per_second: f64) -> Self {
+        *self.bucket(mode, access) = Bucket::new(per_second);
+        self
+    }
+
+    /// Take a token for the request, returning how long to wait before sending it.
+    pub(crate) fn acquire(&self, mode: Mode, access: Access) -> Duration {
+        self.bucket(mode, access).acquire(Instant::now())
+    }
+
+    /// Slow down after stripe rejected a request for exceeding the rate limit.
+    pub(crate) fn throttle(&self, mode: Mode, access: Access) {
+        self.bucket(mode, access).throttle();
+    }
+
+    /// Speed back up after a request was accepted.
+    pub(crate) fn recover(&self, mode: Mode, access: Access) {
+        self.bucket(mode, access).recover();
+    }
+
+    fn bucket(&self, mode: Mode, access: Access) -> std::sync::MutexGuard<'_, Bucket> {
+        let index = match (mode, access) {
+            (Mode::Live, Access::Read) => 0,
+            (Mode::Live, Access::Write) => 1,
+            (Mode::Test, Access::Read) => 2,
+            (Mode::Test, Access::Write) => 3,
+        };
+        // a panic while holding the lock cannot leave the bucket in an invalid state
+        self.buckets[index].lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a request is made with a live or a test key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Mode {
+    Live,
+    Test,
+}
+
+impl Mode {
+    pub(crate) fn from_key(secret_key: &str) -> Self {
+        if secret_key.starts_with("sk_live_") || secret_key.starts_with("rk_live_") {
+            Mode::Live
+        } else {
+            Mode::Test
+        }
+    }
+}
+
+/// Whether a request reads or writes data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Access {
+    Read,
+    Write,
+}
+
+impl Access {
+    pub(crate) fn from_method(method: Method) -> Self {
+        match method {
+            Method::Get | Method::Head => Access::Read,
+            _ => Access::Write,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// The configured number of requests per second.
+    limit: f64,
+    /// The current number of requests per second, lowered after rate limit errors.
+    rate: f64,
+    /// The number of requests which can be sent right away. Negative if requests are waiting.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: f64) -> Self {
+        let limit = limit.max(f64::MIN_POSITIVE);
+        Self { limit, rate: limit, tokens: limit, updated: Instant::now() }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.limit);
+        self.updated = now;
+    }
+
+    fn acquire(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::try_from_secs_f64(-self.tokens / self.rate).unwrap_or(Duration::MAX)
+        }
+    }
+
+    fn throttle(&mut self) {
+        self.refill(Instant::now());
+        self.rate = (self.rate / 2.0).max(self.limit / 10.0);
+        self.tokens = self.tokens.min(0.0);
+    }
+
+    fn recover(&mut self) {
+        self.refill(Instant::now());
+        self.rate = (self.rate + self.limit / 20.0).min(self.limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{Access, Bucket, Mode, RateLimiter};
+
+    #[test]
+    fn bucket_allows_bursts() {
+        let now = Instant::now();
+        let mut bucket = Bucket { updated: now, ..Bucket::new(10.0) };
+
+        for _ in 0..10 {
+            assert_eq!(bucket.acquire(now), Duration::ZERO);
+        }
+        let close_to = |wait: Duration, ms: f64| (wait.as_secs_f64() * 1000.0 - ms).abs() < 1.0;
+        assert!(close_to(bucket.acquire(now), 100.0));
+        assert!(close_to(bucket.acquire(now), 200.0));
+
+        // tokens are refilled over time
+        let later = now + Duration::from_millis(350);
+        assert_eq!(bucket.acquire(later), Duration::ZERO);
+    }
+
+    #[test]
+    fn bucket_adapts_to_rate_limits() {
+        let mut bucket = Bucket::new(10.0);
+
+        bucket.throttle();
+        assert_eq!(bucket.rate, 5.0);
+        assert!(bucket.tokens <= 0.0);
+        for _ in 0..10 {
+            bucket.throttle();
+        }
+        assert_eq!(bucket.rate, 1.0);
+
+        for _ in 0..100 {
+            bucket.recover();
+        }
+        assert_eq!(bucket.rate, 10.0);
+    }
+
+    #[test]
+    fn limiter_budgets() {
+        let limiter = RateLimiter::new().test_read(1.0);
+        let clone = limiter.clone();
+
+        assert_eq!(limiter.acquire(Mode::Test, Access::Read), Duration::ZERO);
+        // clones share the budget, but modes and kinds of requests do not
+        assert!(clone.acquire(Mode::Test, Access::Read) > Duration::ZERO);
+        assert_eq!(clone.acquire(Mode::Test, Access::Write), Duration::ZERO);
+        assert_eq!(clone.acquire(Mode::Live, Access::Read), Duration::ZERO);
+    }
+
+    #[test]
+    fn mode_from_key() {
+        assert_eq!(Mode::from_key("sk_live_123"), Mode::Live);
+        assert_eq!(Mode::from_key("rk_live_123"), Mode::Live);
+        assert_eq!(Mode::from_key("sk_test_123"), Mode::Test);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn client_is_throttled() {
+        use httpmock::prelude::*;
+
+        use crate::Client;
+
+        let server = MockServer::start_async().await;
+        let limiter = RateLimiter::new().test_read(20.0);
+        let client = Client::from_url(&*server.url("/"), "sk_test_123").with_rate_limiter(limiter);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body("{}");
+        });
+
+        // the first 20 requests are sent right away, the others wait for tokens
+        let start = Instant::now();
+        for _ in 0..25 {
+            let res = client.clone().get::<serde_json::Value>("customers").await;
+            assert!(res.is_ok());
+        }
+
+        mock.assert_hits_async(25).await;
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use crate::{AccountId, ApiVersion, ApplicationId};
+
+/// Options which override the configuration of a [`Client`](crate::Client) for a single request.
+///
+/// Every api method has a `_with_options` variant, such as `Customer::create_with_options`,
+/// which applies the options to that request only and leaves the client untouched.
+///
+/// ```rust,ignore
+/// let options = RequestOptions::new()
+///     .stripe_account("acct_123".parse().unwrap())
+///     .idempotency_key("create-customer-123");
+///
+/// let customer = Customer::create_with_options(&client, CreateCustomer::new(), options).await?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    /// Overrides the `Client-Id` header.
+    pub client_id: Option<ApplicationId>,
+    /// Overrides the `Stripe-Account` header, making the request on behalf of a connected account.
+    pub stripe_account: Option<AccountId>,
+    /// Overrides the `Stripe-Version` header.
+    pub stripe_version: Option<ApiVersion>,
+    /// The `Idempotency-Key` to send, instead of the one provided by the [`RequestStrategy`](crate::RequestStrategy).
+    ///
+    /// It is reused if the request is retried.
+    pub idempotency_key: Option<String>,
+    /// The maximum amount of time the request may take, including any retries.
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the client id for the request.
+    pub fn client_id(mut self, id: ApplicationId) -> Self {
+        self.client_id = Some(id);
+        self
+    }
+
+    /// Set the stripe account for the request.
+    pub fn stripe_account(mut self, id: AccountId) -> Self {
+        self.stripe_account = Some(id);
+        self
+    }
+
+    /// Set the api version for the request.
+    ///
+    /// Note: the types in this crate match the api version they were generated from,
+    ///       so responses rendered with another version may fail to deserialize.
+    pub fn stripe_version(mut self, version: ApiVersion) -> Self {
+        self.stripe_version = Some(version);
+        self
+    }
+
+    /// Set the idempotency key for the request.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Set the timeout for the request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "async")]
+    use std::time::Duration;
+
+    #[cfg(feature = "async")]
+    use super::RequestOptions;
+    #[cfg(feature = "async")]
+    use crate::{ApiVersion, Client, StripeError};
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn request_options() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/customers")
+                .header("Stripe-Account", "acct_123")
+                .header("Stripe-Version", "2020-08-27")
+                .header("Idempotency-Key", "key");
+            then.status(200).body("{}");
+        });
+
+        let options = RequestOptions::new()
+            .stripe_account("acct_123".parse().unwrap())
+            .stripe_version(ApiVersion::V2020_08_27)
+            .idempotency_key("key");
+        let res = client.get_with_options::<serde_json::Value>("customers", options).await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_ok());
+
+        // later requests are unaffected
+        let unscoped = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").matches(|req| {
+                req.headers.iter().flatten().all(|(name, _)| {
+                    !name.eq_ignore_ascii_case("stripe-account")
+                        && !name.eq_ignore_ascii_case("idempotency-key")
+                })
+            });
+            then.status(200).body("{}");
+        });
+        let res = client.get::<serde_json::Value>("customers").await;
+
+        unscoped.assert_hits_async(1).await;
+        assert!(res.is_ok());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn request_options_api_method() {
+        use httpmock::prelude::*;
+
+        use crate::{Customer, CustomerId};
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+        let id: CustomerId = "cus_123".parse().unwrap();
+
+        let keyed = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/v1/customers/cus_123")
+                .header("Stripe-Account", "acct_123")
+                .header("Idempotency-Key", "key");
+            then.status(200).body(r#"{"id": "cus_123", "deleted": true}"#);
+        });
+        let unkeyed = server.mock(|when, then| {
+            when.method(DELETE).path("/v1/customers/cus_123").matches(|req| {
+                req.headers
+                    .iter()
+                    .flatten()
+                    .all(|(name, _)| !name.eq_ignore_ascii_case("idempotency-key"))
+            });
+            then.status(200).body(r#"{"id": "cus_123", "deleted": true}"#);
+        });
+
+        let options = RequestOptions::new()
+            .stripe_account("acct_123".parse().unwrap())
+            .idempotency_key("key");
+        let deleted = Customer::delete_with_options(&client, &id, options).await.unwrap();
+        assert!(deleted.deleted);
+
+        // the key is not sent with the next request
+        Customer::delete(&client, &id).await.unwrap();
+
+        keyed.assert_hits_async(1).await;
+        unkeyed.assert_hits_async(1).await;
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn request_options_timeout() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body("{}").delay(Duration::from_secs(1));
+        });
+
+        let options = RequestOptions::new().timeout(Duration::from_millis(50));
+        let res = client.get_with_options::<serde_json::Value>("customers", options).await;
+
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+}
@@ -2,6 +2,8 @@ use std::time::Duration;
 
 use http_types::StatusCode;
 
+use crate::client::retry_policy::{Attempts, RetryPolicy};
+
 #[derive(Clone, Debug)]
 pub enum RequestStrategy {
     Once,
@@ -14,7 +16,13 @@ pub enum RequestStrategy {
     /// This strategy will retry the request up to the
     /// specified number of times using the same, random,
     /// idempotency key with exponential backoff, up to n times.
+    ///
+    /// The delays count towards the timeout of the client, so the retries stop early
+    /// once it is exceeded (see [`Client::with_timeout`](crate::Client::with_timeout)).
     ExponentialBackoff(u32),
+    /// This strategy will retry the request according to the
+    /// given policy, using the same, random, idempotency key.
+    Policy(RetryPolicy),
 }
 
 impl RequestStrategy {
@@ -31,6 +39,16 @@ impl RequestStrategy {
 
         use RequestStrategy::*;
 
+        if let Policy(policy) = self {
+            let attempts = Attempts {
+                retries: retry_count,
+                status,
+                stripe_should_retry,
+                ..Default::default()
+            };
+            return policy.test(&attempts);
+        }
+
         match (self, status, retry_count) {
             // a strategy of once or idempotent should run once
             (Once | Idempotent(_), _, 0) => Outcome::Continue(None),
@@ -52,6 +70,14 @@ impl RequestStrategy {
         }
     }
 
+    /// Like [`RequestStrategy::test`], with everything known about the previous attempts.
+    pub(crate) fn test_attempts(&self, attempts: &Attempts<'_>) -> Outcome {
+        match self {
+            RequestStrategy::Policy(policy) => policy.test(attempts),
+            _ => self.test(attempts.status, attempts.stripe_should_retry, attempts.retries),
+        }
+    }
+
     #[cfg(feature = "uuid")]
     pub fn idempotent_with_uuid() -> Self {
         use uuid::Uuid;
@@ -63,11 +89,13 @@ impl RequestStrategy {
             RequestStrategy::Once => None,
             RequestStrategy::Idempotent(key) => Some(key.clone()),
             #[cfg(feature = "uuid")]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => {
-                Some(uuid::Uuid::new_v4().to_string())
-            }
+            RequestStrategy::Retry(_)
+            | RequestStrategy::ExponentialBackoff(_)
+            | RequestStrategy::Policy(_) => Some(uuid::Uuid::new_v4().to_string()),
             #[cfg(not(feature = "uuid"))]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => None,
+            RequestStrategy::Retry(_)
+            | RequestStrategy::ExponentialBackoff(_)
+            | RequestStrategy::Policy(_) => None,
         }
     }
 }
@@ -142,4 +170,19 @@ mod tests {
         let strategy = RequestStrategy::Retry(3);
         assert_eq!(strategy.test(None, Some(false), 0), Outcome::Stop);
     }
+
+    #[test]
+    fn test_policy_strategy() {
+        use http_types::StatusCode;
+
+        use crate::{Jitter, RetryPolicy};
+
+        let strategy = RequestStrategy::Policy(RetryPolicy::new(2).jitter(Jitter::None));
+        let delay = |ms| Outcome::Continue(Some(Duration::from_millis(ms)));
+        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(None));
+        assert_eq!(strategy.test(None, None, 1), delay(500));
+        assert_eq!(strategy.test(Some(StatusCode::TooManyRequests), None, 2), delay(1000));
+        assert_eq!(strategy.test(None, None, 3), Outcome::Stop);
+        assert_eq!(strategy.test(Some(StatusCode::BadRequest), None, 1), Outcome::Stop);
+    }
 }
@@ -0,0 +1,147 @@
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use http_types::headers::Headers;
+use http_types::StatusCode;
+
+/// A deserialized response from stripe, along with the metadata of the HTTP response.
+///
+/// Returned by [`Client::capture_metadata`](crate::Client::capture_metadata).
+/// It dereferences to the deserialized object.
+#[derive(Clone, Debug)]
+pub struct StripeResponse<T> {
+    /// The deserialized body of the response.
+    pub data: T,
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The `Request-Id` stripe assigned to the request, useful when contacting support.
+    pub request_id: Option<String>,
+    /// The `Idempotency-Key` the request was sent with, if any.
+    pub idempotency_key: Option<String>,
+    /// Whether stripe replayed the response of an earlier request with the same idempotency key.
+    pub idempotent_replayed: bool,
+    /// The raw headers of the response.
+    pub headers: Headers,
+}
+
+impl<T> StripeResponse<T> {
+    pub(crate) fn new(data: T, metadata: ResponseMetadata) -> Self {
+        let header = |name: &str| metadata.headers.get(name).map(|h| h.last().to_string());
+        Self {
+            data,
+            status: metadata.status,
+            request_id: header("Request-Id"),
+            idempotency_key: metadata.idempotency_key,
+            idempotent_replayed: header("Idempotent-Replayed").as_deref() == Some("true"),
+            headers: metadata.headers,
+        }
+    }
+
+    /// Discard the metadata, returning the deserialized object.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Deref for StripeResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+/// The metadata of the last successful response received by a client.
+pub(crate) type MetadataSlot = Arc<Mutex<Option<ResponseMetadata>>>;
+
+#[derive(Debug)]
+pub(crate) struct ResponseMetadata {
+    pub status: StatusCode,
+    pub headers: Headers,
+    pub idempotency_key: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn capture_metadata() {
+        use httpmock::prelude::*;
+
+        use crate::{Client, RequestStrategy};
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123")
+            .with_strategy(RequestStrategy::Idempotent("key".to_string()));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/cus_123");
+            then.status(200)
+                .header("Request-Id", "req_123")
+                .header("Idempotent-Replayed", "true")
+                .body(r#"{"id": "cus_123"}"#);
+        });
+
+        let res = client
+            .capture_metadata(|client| client.get::<serde_json::Value>("customers/cus_123"))
+            .await
+            .unwrap();
+
+        mock.assert_hits_async(1).await;
+        assert_eq!(res["id"], "cus_123");
+        assert_eq!(res.status, http_types::StatusCode::Ok);
+        assert_eq!(res.request_id.as_deref(), Some("req_123"));
+        assert_eq!(res.idempotency_key.as_deref(), Some("key"));
+        assert!(res.idempotent_replayed);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn capture_metadata() {
+        use httpmock::prelude::*;
+
+        use crate::Client;
+
+        let server = MockServer::start();
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/cus_123");
+            then.status(200).header("Request-Id", "req_123").body(r#"{"id": "cus_123"}"#);
+        });
+
+        let res = client
+            .capture_metadata(|client| client.get::<serde_json::Value>("customers/cus_123"))
+            .unwrap();
+
+        mock.assert_hits(1);
+        assert_eq!(res.request_id.as_deref(), Some("req_123"));
+        assert!(!res.idempotent_replayed);
+        assert_eq!(res.into_inner()["id"], "cus_123");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn error_request_id() {
+        use httpmock::prelude::*;
+
+        use crate::{Client, StripeError};
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/cus_missing");
+            then.status(404)
+                .header("Request-Id", "req_123")
+                .body(r#"{"error": {"type": "invalid_request_error"}}"#);
+        });
+
+        let res = client.get::<serde_json::Value>("customers/cus_missing").await;
+
+        match res {
+            Err(StripeError::Stripe(e)) => assert_eq!(e.request_id.as_deref(), Some("req_123")),
+            res => panic!("expected a stripe error, got {:?}", res),
+        }
+    }
+}
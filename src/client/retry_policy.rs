@@ -0,0 +1,376 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http_types::StatusCode;
+
+use crate::client::request_strategy::Outcome;
+use crate::error::StripeError;
+
+/// A user provided hook deciding whether a failed request should be retried.
+type RetryHook = Arc<dyn Fn(&StripeError) -> Option<bool> + Send + Sync>;
+
+/// How much randomness is added to the delay between retries.
+///
+/// See: <https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Jitter {
+    /// Wait for exactly the exponential backoff.
+    None,
+    /// Wait for a random duration between zero and the exponential backoff.
+    #[default]
+    Full,
+    /// Wait for a random duration between the base delay and three times the previous delay.
+    Decorrelated,
+}
+
+/// A configurable retry policy, used with [`RequestStrategy::Policy`](crate::RequestStrategy::Policy).
+///
+/// By default a failed request is retried when:
+///
+/// - stripe sets the `Stripe-Should-Retry` header to `true`,
+/// - no response could be received, or the attempt timed out,
+/// - stripe responds with `409 Conflict` (usually a lock timeout) or `429 Too Many Requests`,
+/// - stripe responds with a server error.
+///
+/// A `Stripe-Should-Retry` header of `false` always stops the request, and a `Retry-After`
+/// header is used as the minimum delay before the next attempt. Both can be overridden
+/// using [`RetryPolicy::retry_if`].
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use stripe::{Client, Jitter, RequestStrategy, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(5)
+///     .base_delay(Duration::from_millis(250))
+///     .max_delay(Duration::from_secs(5))
+///     .jitter(Jitter::Decorrelated)
+///     .budget(Duration::from_secs(20));
+/// let client = Client::new("sk_test_123").with_strategy(RequestStrategy::Policy(policy));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    multiplier: f64,
+    max_delay: Duration,
+    jitter: Jitter,
+    budget: Option<Duration>,
+    retry_if: Option<RetryHook>,
+}
+
+impl RetryPolicy {
+    /// Create a policy which retries a request up to `max_retries` times, waiting
+    /// 500ms before the first retry and doubling the delay up to 8 seconds.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(8),
+            jitter: Jitter::default(),
+            budget: None,
+            retry_if: None,
+        }
+    }
+
+    /// Set the delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the factor the delay grows by after every retry.
+    ///
+    /// Factors below `1.0`, which would shrink the delay, and non-finite factors are
+    /// replaced by `1.0`, which keeps the delay constant.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = if multiplier.is_finite() { multiplier.max(1.0) } else { 1.0 };
+        self
+    }
+
+    /// Set the maximum delay between two attempts, not counting any `Retry-After`.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the jitter applied to the delay between attempts.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the total amount of time which may be spent on a request.
+    ///
+    /// A retry is not attempted if waiting for it would exceed the budget.
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Decide whether a failed request is retried based on its error.
+    ///
+    /// Returning `None` falls back to the default behaviour of the policy.
+    ///
+    /// ```no_run
+    /// use stripe::{RetryPolicy, StripeError};
+    ///
+    /// let policy = RetryPolicy::new(3).retry_if(|err| match err {
+    ///     StripeError::Stripe(e) if e.http_status == 409 => Some(false),
+    ///     StripeError::Timeout => Some(false),
+    ///     _ => None,
+    /// });
+    /// ```
+    pub fn retry_if(
+        mut self,
+        hook: impl Fn(&StripeError) -> Option<bool> + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_if = Some(Arc::new(hook));
+        self
+    }
+
+    /// Decide whether to make another attempt, and how long to wait before it.
+    pub(crate) fn test(&self, attempts: &Attempts<'_>) -> Outcome {
+        if attempts.retries == 0 {
+            return Outcome::Continue(None);
+        }
+
+        if attempts.retries > self.max_retries || !self.is_retryable(attempts) {
+            return Outcome::Stop;
+        }
+
+        let mut delay = self.backoff(attempts.retries - 1, attempts.last_delay);
+        if let Some(retry_after) = attempts.retry_after {
+            delay = delay.max(retry_after);
+        }
+
+        match self.budget {
+            Some(budget) if attempts.elapsed.saturating_add(delay) > budget => Outcome::Stop,
+            _ => Outcome::Continue(Some(delay)),
+        }
+    }
+
+    fn is_retryable(&self, attempts: &Attempts<'_>) -> bool {
+        let hook = self.retry_if.as_ref().zip(attempts.error);
+        if let Some(retry) = hook.and_then(|(hook, error)| hook(error)) {
+            return retry;
+        }
+
+        if let Some(retry) = attempts.stripe_should_retry {
+            return retry;
+        }
+
+        match attempts.status {
+            None => true,
+            Some(StatusCode::Conflict | StatusCode::TooManyRequests) => true,
+            Some(status) => status.is_server_error(),
+        }
+    }
+
+    /// Calculate the delay before retry number `retry_count`, starting from zero.
+    fn backoff(&self, retry_count: u32, last_delay: Option<Duration>) -> Duration {
+        let max = self.max_delay.as_secs_f64();
+        let base = self.base_delay.as_secs_f64().min(max);
+        let exponential = (base * self.multiplier.powf(retry_count as f64)).min(max);
+
+        let delay = match self.jitter {
+            Jitter::None => exponential,
+            Jitter::Full => fastrand::f64() * exponential,
+            Jitter::Decorrelated => {
+                let upper = last_delay.map_or(base, |d| d.as_secs_f64() * 3.0).max(base);
+                (base + fastrand::f64() * (upper - base)).min(max)
+            }
+        };
+
+        // N.B. `max_delay` may not round trip through an `f64`, so fall back to it on overflow
+        Duration::try_from_secs_f64(delay.max(0.0)).unwrap_or(self.max_delay)
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("multiplier", &self.multiplier)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("budget", &self.budget)
+            .field("retry_if", &self.retry_if.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// What is known about the previous attempts at a request.
+#[derive(Debug, Default)]
+pub(crate) struct Attempts<'a> {
+    /// The number of failed attempts so far.
+    pub retries: u32,
+    pub status: Option<StatusCode>,
+    pub stripe_should_retry: Option<bool>,
+    pub retry_after: Option<Duration>,
+    pub error: Option<&'a StripeError>,
+    /// The time spent on the request so far.
+    pub elapsed: Duration,
+    /// The delay before the previous attempt, if any.
+    pub last_delay: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http_types::StatusCode;
+
+    use super::{Attempts, Jitter, RetryPolicy};
+    use crate::client::request_strategy::Outcome;
+    use crate::{RequestError, StripeError};
+
+    fn failed(status: Option<StatusCode>) -> Attempts<'static> {
+        Attempts { retries: 1, status, ..Default::default() }
+    }
+
+    #[test]
+    fn first_attempt() {
+        let policy = RetryPolicy::new(0);
+        assert_eq!(policy.test(&Attempts::default()), Outcome::Continue(None));
+        assert_eq!(policy.test(&failed(None)), Outcome::Stop);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = RetryPolicy::new(10)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .jitter(Jitter::None);
+
+        let delays: Vec<_> = (1..=5)
+            .map(|retries| policy.test(&Attempts { retries, ..Default::default() }))
+            .collect();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 5, 5].map(|s| Outcome::Continue(Some(Duration::from_secs(s))))
+        );
+        assert_eq!(policy.test(&Attempts { retries: 11, ..Default::default() }), Outcome::Stop);
+    }
+
+    #[test]
+    fn invalid_multiplier() {
+        for multiplier in [-2.0, 0.5, f64::NAN, f64::INFINITY] {
+            let policy = RetryPolicy::new(3)
+                .base_delay(Duration::from_secs(1))
+                .multiplier(multiplier)
+                .jitter(Jitter::None);
+
+            let delays: Vec<_> = (1..=3)
+                .map(|retries| policy.test(&Attempts { retries, ..Default::default() }))
+                .collect();
+            assert_eq!(delays, [1, 1, 1].map(|s| Outcome::Continue(Some(Duration::from_secs(s)))));
+        }
+    }
+
+    #[test]
+    fn unbounded_max_delay() {
+        for jitter in [Jitter::None, Jitter::Full, Jitter::Decorrelated] {
+            let policy = RetryPolicy::new(u32::MAX)
+                .base_delay(Duration::MAX)
+                .max_delay(Duration::MAX)
+                .jitter(jitter)
+                .budget(Duration::from_secs(1));
+
+            let attempts =
+                Attempts { retries: 100, last_delay: Some(Duration::MAX), ..failed(None) };
+            assert_eq!(policy.test(&attempts), Outcome::Stop);
+            // must not panic converting the delay back into a `Duration`
+            policy.backoff(100, Some(Duration::MAX));
+        }
+
+        let policy = RetryPolicy::new(3).max_delay(Duration::MAX).jitter(Jitter::None);
+        assert_eq!(policy.backoff(u32::MAX, None), Duration::MAX);
+    }
+
+    #[test]
+    fn jitter_is_bounded() {
+        let full = RetryPolicy::new(10).max_delay(Duration::from_secs(2));
+        let decorrelated = full.clone().jitter(Jitter::Decorrelated);
+
+        for retries in 1..=10 {
+            let last_delay = Some(Duration::from_secs(1));
+            let attempts = Attempts { retries, last_delay, ..Default::default() };
+            for policy in [&full, &decorrelated] {
+                match policy.test(&attempts) {
+                    Outcome::Continue(Some(delay)) => assert!(delay <= Duration::from_secs(2)),
+                    outcome => panic!("unexpected outcome {:?}", outcome),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        let policy = RetryPolicy::new(3).jitter(Jitter::None);
+        let retry = Outcome::Continue(Some(Duration::from_millis(500)));
+
+        assert_eq!(policy.test(&failed(None)), retry);
+        assert_eq!(policy.test(&failed(Some(StatusCode::Conflict))), retry);
+        assert_eq!(policy.test(&failed(Some(StatusCode::TooManyRequests))), retry);
+        assert_eq!(policy.test(&failed(Some(StatusCode::BadGateway))), retry);
+        assert_eq!(policy.test(&failed(Some(StatusCode::BadRequest))), Outcome::Stop);
+        assert_eq!(policy.test(&failed(Some(StatusCode::NotFound))), Outcome::Stop);
+    }
+
+    #[test]
+    fn stripe_should_retry() {
+        let policy = RetryPolicy::new(3).jitter(Jitter::None);
+
+        let attempts = Attempts { stripe_should_retry: Some(false), ..failed(None) };
+        assert_eq!(policy.test(&attempts), Outcome::Stop);
+
+        let attempts =
+            Attempts { stripe_should_retry: Some(true), ..failed(Some(StatusCode::BadRequest)) };
+        assert_eq!(policy.test(&attempts), Outcome::Continue(Some(Duration::from_millis(500))));
+    }
+
+    #[test]
+    fn retry_after() {
+        let policy = RetryPolicy::new(3).jitter(Jitter::None);
+        let attempts = Attempts {
+            retry_after: Some(Duration::from_secs(3)),
+            ..failed(Some(StatusCode::TooManyRequests))
+        };
+        assert_eq!(policy.test(&attempts), Outcome::Continue(Some(Duration::from_secs(3))));
+    }
+
+    #[test]
+    fn budget() {
+        let policy = RetryPolicy::new(3).jitter(Jitter::None).budget(Duration::from_secs(1));
+
+        let attempts = Attempts { elapsed: Duration::from_millis(400), ..failed(None) };
+        assert_eq!(policy.test(&attempts), Outcome::Continue(Some(Duration::from_millis(500))));
+
+        let attempts = Attempts { elapsed: Duration::from_millis(600), ..failed(None) };
+        assert_eq!(policy.test(&attempts), Outcome::Stop);
+    }
+
+    #[test]
+    fn retry_if() {
+        let policy = RetryPolicy::new(3).jitter(Jitter::None).retry_if(|err| match err {
+            StripeError::Stripe(e) if e.http_status == 400 => Some(true),
+            StripeError::Timeout => Some(false),
+            _ => None,
+        });
+
+        let error = StripeError::Stripe(RequestError { http_status: 400, ..Default::default() });
+        let attempts = Attempts { error: Some(&error), ..failed(Some(StatusCode::BadRequest)) };
+        assert_eq!(policy.test(&attempts), Outcome::Continue(Some(Duration::from_millis(500))));
+
+        let attempts = Attempts { error: Some(&StripeError::Timeout), ..failed(None) };
+        assert_eq!(policy.test(&attempts), Outcome::Stop);
+
+        // the hook can defer to the default behaviour
+        let error = StripeError::Stripe(RequestError { http_status: 404, ..Default::default() });
+        let attempts = Attempts { error: Some(&error), ..failed(Some(StatusCode::NotFound)) };
+        assert_eq!(policy.test(&attempts), Outcome::Stop);
+    }
+}
@@ -1,9 +1,20 @@
-use http_types::{Body, Method, Request, Url};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use http_types::{Body, Method, Request, StatusCode, Url};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    client::{request_strategy::RequestStrategy, BaseClient, Response},
-    config::err,
+    client::{
+        rate_limiter::{Access, Mode},
+        request_strategy::{Outcome, RequestStrategy},
+        response::{MetadataSlot, ResponseMetadata},
+        retry_policy::Attempts,
+        BaseClient, Middleware, Next, RateLimiter, RequestOptions, Response, StripeResponse,
+        Transport,
+    },
+    config::{and_then, err, execute, sleep, timeout},
+    error::ErrorResponse,
     generated::core::version::VERSION,
     params::AppInfo,
     AccountId, ApplicationId, Headers, StripeError,
@@ -11,12 +22,27 @@ use crate::{
 
 static USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
 
+/// The delay after which the blocking `Client` will assume the request has failed.
+///
+/// N.B. Async clients have no timeout unless one is set with [`Client::with_timeout`].
+#[cfg(feature = "blocking")]
+const DEFAULT_TIMEOUT: Option<Duration> = Some(Duration::from_secs(30));
+#[cfg(not(feature = "blocking"))]
+const DEFAULT_TIMEOUT: Option<Duration> = None;
+
 #[derive(Clone)]
 pub struct Client {
-    client: crate::client::BaseClient,
+    client: Arc<dyn Transport>,
+    /// Whether the transport was set with [`Client::with_transport`].
+    custom_transport: bool,
     secret_key: String,
     headers: Headers,
     strategy: RequestStrategy,
+    timeout: Option<Duration>,
+    attempt_timeout: Option<Duration>,
+    rate_limiter: Option<RateLimiter>,
+    middleware: Arc<Vec<Arc<dyn Middleware>>>,
+    metadata: Option<MetadataSlot>,
     app_info: Option<AppInfo>,
     api_base: Url,
     api_root: String,
@@ -34,7 +60,8 @@ impl Client {
     /// If the url can't be parsed
     pub fn from_url<'a>(url: impl Into<&'a str>, secret_key: impl Into<String>) -> Self {
         Client {
-            client: BaseClient::new(),
+            client: Arc::new(BaseClient::new()),
+            custom_transport: false,
             secret_key: secret_key.into(),
             headers: Headers {
                 stripe_version: VERSION,
@@ -43,6 +70,11 @@ impl Client {
                 stripe_account: None,
             },
             strategy: RequestStrategy::Once,
+            timeout: DEFAULT_TIMEOUT,
+            attempt_timeout: None,
+            rate_limiter: None,
+            middleware: Arc::default(),
+            metadata: None,
             app_info: None,
             api_base: Url::parse(url.into()).expect("invalid url"),
             api_root: "v1".to_string(),
@@ -61,6 +93,65 @@ impl Client {
         self
     }
 
+    /// Set the transport used to send requests to stripe.
+    ///
+    /// By default, requests are sent using the [`BaseClient`] of the enabled runtime.
+    /// Clones of the returned client share the same transport.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.client = Arc::new(transport);
+        self.custom_transport = true;
+        self
+    }
+
+    /// Set the maximum amount of time a request may take, including any retries
+    /// and the delays between them.
+    ///
+    /// Once exceeded, the request fails with [`StripeError::Timeout`], even if the
+    /// [`RequestStrategy`] would have retried it again. Defaults to 30 seconds on the
+    /// blocking runtime, while async requests have no timeout unless one is set.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum amount of time a single attempt at a request may take.
+    ///
+    /// An attempt which times out is retried if the [`RequestStrategy`] allows it,
+    /// in the same way as any other error communicating with stripe.
+    pub fn with_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum amount of time spent connecting to stripe.
+    ///
+    /// This configures the default [`BaseClient`], and is ignored if a custom [`Transport`]
+    /// was set with [`Client::with_transport`], whose timeouts should be configured instead.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        if !self.custom_transport {
+            self.client = Arc::new(BaseClient::with_connect_timeout(timeout));
+        }
+        self
+    }
+
+    /// Set the rate limiter used to throttle requests before they are sent.
+    ///
+    /// Clones of the returned client share the same limiter, so the budget is shared
+    /// between every task using them.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Add a middleware which is called for every attempt at a request.
+    ///
+    /// Middleware is called in the order it is added, so the first one added sees
+    /// the request first and the response last.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        Arc::make_mut(&mut self.middleware).push(Arc::new(middleware));
+        self
+    }
+
     /// Set the request strategy for the client.
     ///
     /// Note: the client is cheap to clone so if you require a new client
@@ -79,6 +170,34 @@ impl Client {
         self
     }
 
+    /// Make a request, returning the deserialized object along with the metadata
+    /// of the response, such as its `Request-Id`.
+    ///
+    /// If the closure makes several requests, the metadata of the last successful
+    /// one is returned.
+    ///
+    /// ```rust,ignore
+    /// let response = client
+    ///     .capture_metadata(|client| Customer::retrieve(client, &customer_id, &[]))
+    ///     .await?;
+    /// println!("{} in request {:?}", response.id, response.request_id);
+    /// ```
+    pub fn capture_metadata<T: Send + 'static>(
+        &self,
+        request: impl FnOnce(&Client) -> Response<T>,
+    ) -> Response<StripeResponse<T>> {
+        let slot = MetadataSlot::default();
+        let mut client = self.clone();
+        client.metadata = Some(slot.clone());
+
+        and_then(request(&client), move |data| {
+            let metadata = slot.lock().unwrap_or_else(|e| e.into_inner()).take();
+            metadata
+                .map(|metadata| StripeResponse::new(data, metadata))
+                .ok_or_else(|| StripeError::ClientError("no response was received".to_string()))
+        })
+    }
+
     /// Set the application info for the client.
     ///
     /// It is recommended that applications set this so that
@@ -98,8 +217,17 @@ impl Client {
 
     /// Make a `GET` http request with just a path
     pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        self.get_with_options(path, RequestOptions::default())
+    }
+
+    /// Make a `GET` http request with just a path, applying the options to this request only
+    pub fn get_with_options<T: DeserializeOwned + Send + 'static>(
+        &self,
+        path: &str,
+        options: RequestOptions,
+    ) -> Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Get, url), &self.strategy)
+        self.execute::<T>(self.create_request(Method::Get, url, &options), options)
     }
 
     /// Make a `GET` http request with url query parameters
@@ -107,18 +235,37 @@ impl Client {
         &self,
         path: &str,
         params: P,
+    ) -> Response<T> {
+        self.get_query_with_options(path, params, RequestOptions::default())
+    }
+
+    /// Make a `GET` http request with url query parameters, applying the options to this request only
+    pub fn get_query_with_options<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+        options: RequestOptions,
     ) -> Response<T> {
         let url = match self.url_with_params(path, params) {
             Err(e) => return err(e),
             Ok(ok) => ok,
         };
-        self.client.execute::<T>(self.create_request(Method::Get, url), &self.strategy)
+        self.execute::<T>(self.create_request(Method::Get, url, &options), options)
     }
 
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        self.delete_with_options(path, RequestOptions::default())
+    }
+
+    /// Make a `DELETE` http request with just a path, applying the options to this request only
+    pub fn delete_with_options<T: DeserializeOwned + Send + 'static>(
+        &self,
+        path: &str,
+        options: RequestOptions,
+    ) -> Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Delete, url), &self.strategy)
+        self.execute::<T>(self.create_request(Method::Delete, url, &options), options)
     }
 
     /// Make a `DELETE` http request with url query parameters
@@ -126,18 +273,37 @@ impl Client {
         &self,
         path: &str,
         params: P,
+    ) -> Response<T> {
+        self.delete_query_with_options(path, params, RequestOptions::default())
+    }
+
+    /// Make a `DELETE` http request with url query parameters, applying the options to this request only
+    pub fn delete_query_with_options<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+        options: RequestOptions,
     ) -> Response<T> {
         let url = match self.url_with_params(path, params) {
             Err(e) => return err(e),
             Ok(ok) => ok,
         };
-        self.client.execute::<T>(self.create_request(Method::Delete, url), &self.strategy)
+        self.execute::<T>(self.create_request(Method::Delete, url, &options), options)
     }
 
     /// Make a `POST` http request with just a path
     pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        self.post_with_options(path, RequestOptions::default())
+    }
+
+    /// Make a `POST` http request with just a path, applying the options to this request only
+    pub fn post_with_options<T: DeserializeOwned + Send + 'static>(
+        &self,
+        path: &str,
+        options: RequestOptions,
+    ) -> Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Post, url), &self.strategy)
+        self.execute::<T>(self.create_request(Method::Post, url, &options), options)
     }
 
     /// Make a `POST` http request with urlencoded body
@@ -148,9 +314,22 @@ impl Client {
         &self,
         path: &str,
         form: F,
+    ) -> Response<T> {
+        self.post_form_with_options(path, form, RequestOptions::default())
+    }
+
+    /// Make a `POST` http request with urlencoded body, applying the options to this request only
+    ///
+    /// # Panics
+    /// If the form is not serialized to an utf8 string.
+    pub fn post_form_with_options<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+        options: RequestOptions,
     ) -> Response<T> {
         let url = self.url(path);
-        let mut req = self.create_request(Method::Post, url);
+        let mut req = self.create_request(Method::Post, url, &options);
 
         let mut params_buffer = Vec::new();
         let qs_ser = &mut serde_qs::Serializer::new(&mut params_buffer);
@@ -165,7 +344,46 @@ impl Client {
         req.set_body(Body::from_string(body));
 
         req.insert_header("content-type", "application/x-www-form-urlencoded");
-        self.client.execute::<T>(req, &self.strategy)
+        self.execute::<T>(req, options)
+    }
+
+    /// Send a request using the client's transport and strategy, deserializing the response.
+    ///
+    /// The idempotency key and timeout of the options override those of the client.
+    fn execute<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: Request,
+        options: RequestOptions,
+    ) -> Response<T> {
+        // need to clone here since the future may outlive the borrow of self.
+        // N.B. cloning the client is cheap; clones share the same transport and limiter.
+        let client = self.clone();
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "stripe_request",
+            method = %request.method(),
+            path = request.url().path(),
+            status = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            idempotency_key = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+        );
+
+        let future = async move {
+            let send = send_inner(&client, request, options.idempotency_key);
+            let bytes = match options.timeout.or(client.timeout) {
+                Some(duration) => timeout(duration, send).await?,
+                None => send.await?,
+            };
+            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        execute(future)
     }
 
     fn url(&self, path: &str) -> Url {
@@ -189,11 +407,22 @@ impl Client {
         Ok(url)
     }
 
-    fn create_request(&self, method: Method, url: Url) -> Request {
+    fn create_request(&self, method: Method, url: Url, options: &RequestOptions) -> Request {
         let mut req = Request::new(method, url);
         req.insert_header("authorization", format!("Bearer {}", self.secret_key));
 
-        for (key, value) in self.headers.to_array().iter().filter_map(|(k, v)| v.map(|v| (*k, v))) {
+        let mut headers = self.headers.clone();
+        if let Some(id) = &options.client_id {
+            headers.client_id = Some(id.clone());
+        }
+        if let Some(id) = &options.stripe_account {
+            headers.stripe_account = Some(id.clone());
+        }
+        if let Some(version) = options.stripe_version {
+            headers.stripe_version = version;
+        }
+
+        for (key, value) in headers.to_array().iter().filter_map(|(k, v)| v.map(|v| (*k, v))) {
             req.insert_header(key, value);
         }
 
@@ -201,6 +430,156 @@ impl Client {
     }
 }
 
+/// Send a request with the client's transport, retrying it according to its strategy,
+/// and return the body of the first successful response.
+///
+/// An explicit idempotency key, from the [`RequestOptions`] of the request, takes precedence
+/// over the one provided by the strategy.
+/// Attempts which exceed the attempt timeout are treated like any other failure to
+/// communicate with stripe, so they are retried if the strategy allows it.
+async fn send_inner(
+    client: &Client,
+    mut request: Request,
+    explicit_key: Option<String>,
+) -> Result<Vec<u8>, StripeError> {
+    let strategy = &client.strategy;
+    let mode = Mode::from_key(&client.secret_key);
+    let access = Access::from_method(request.method());
+
+    let start = Instant::now();
+    let mut tries = 0;
+    let mut last_status: Option<StatusCode> = None;
+    let mut last_retry_header: Option<bool> = None;
+    let mut last_retry_after: Option<Duration> = None;
+    let mut last_delay: Option<Duration> = None;
+
+    // if we have no last error, then the strategy is invalid
+    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
+
+    let idempotency_key = explicit_key.or_else(|| strategy.get_key());
+    if let Some(key) = &idempotency_key {
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("idempotency_key", key.as_str());
+        request.insert_header("Idempotency-Key", key.as_str());
+    }
+
+    let body = request.body_bytes().await?;
+
+    loop {
+        let attempts = Attempts {
+            retries: tries,
+            status: last_status,
+            stripe_should_retry: last_retry_header,
+            retry_after: last_retry_after,
+            error: (tries > 0).then_some(&last_error),
+            elapsed: start.elapsed(),
+            last_delay,
+        };
+
+        return match strategy.test_attempts(&attempts) {
+            Outcome::Stop => Err(last_error),
+            Outcome::Continue(duration) => {
+                if let Some(duration) = duration {
+                    sleep(duration).await;
+                }
+                last_delay = duration;
+
+                if let Some(limiter) = &client.rate_limiter {
+                    let wait = limiter.acquire(mode, access);
+                    if !wait.is_zero() {
+                        sleep(wait).await;
+                    }
+                }
+
+                // we need to clone the request before sending it so we can
+                // re-use it if we need to retry. ditto for the body
+                let mut request = request.clone();
+                request.set_body(body.clone());
+
+                let attempt = async {
+                    let next = Next::new(client.middleware.clone(), client.client.clone());
+                    let mut response = next.run(request).await?;
+                    let bytes = response.body_bytes().await?;
+                    Ok((response, bytes))
+                };
+                let attempt = match client.attempt_timeout {
+                    Some(duration) => timeout(duration, attempt).await,
+                    None => attempt.await,
+                };
+
+                let (response, bytes) = match attempt {
+                    Ok(attempt) => attempt,
+                    Err(err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(error = %err, retries = tries, "stripe request failed");
+                        last_error = err;
+                        last_status = None;
+                        last_retry_header = None;
+                        last_retry_after = None;
+                        tries += 1;
+                        continue;
+                    }
+                };
+
+                let status = response.status();
+
+                #[cfg(feature = "tracing")]
+                {
+                    let span = tracing::Span::current();
+                    span.record("status", u16::from(status));
+                    span.record("retries", tries);
+                    if let Some(id) = response.header("Request-Id") {
+                        span.record("request_id", id.last().as_str());
+                    }
+                }
+                let retry = response
+                    .header("Stripe-Should-Retry")
+                    .and_then(|s| s.last().as_str().parse().ok());
+                let retry_after = response
+                    .header("Retry-After")
+                    .and_then(|s| s.last().as_str().parse().ok())
+                    .map(Duration::from_secs);
+
+                if let Some(limiter) = &client.rate_limiter {
+                    match status {
+                        StatusCode::TooManyRequests => limiter.throttle(mode, access),
+                        _ if status.is_success() => limiter.recover(mode, access),
+                        _ => {}
+                    }
+                }
+
+                if !status.is_success() {
+                    tries += 1;
+                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+                    last_error = serde_path_to_error::deserialize(json_deserializer)
+                        .map(|mut e: ErrorResponse| {
+                            e.error.http_status = status.into();
+                            e.error.request_id =
+                                response.header("Request-Id").map(|id| id.last().to_string());
+                            StripeError::from(e.error)
+                        })
+                        .unwrap_or_else(StripeError::from);
+                    last_status = Some(status);
+                    last_retry_header = retry;
+                    last_retry_after = retry_after;
+
+                    continue;
+                }
+
+                if let Some(slot) = &client.metadata {
+                    *slot.lock().unwrap_or_else(|e| e.into_inner()) = Some(ResponseMetadata {
+                        status,
+                        headers: response.as_ref().clone(),
+                        idempotency_key,
+                    });
+                }
+
+                Ok(bytes)
+            }
+        };
+    }
+}
+
 #[cfg(test)]
 mod test {
     //! Ensures our user agent matches the format of the other stripe clients.
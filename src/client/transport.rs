@@ -0,0 +1,183 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use http_types::{Request, Response};
+
+use crate::error::StripeError;
+
+/// The future returned by [`Transport::send`].
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response, StripeError>> + Send>>;
+
+/// The HTTP layer used by the [`Client`](crate::Client) to talk to Stripe.
+///
+/// A transport sends a single request and hands back the raw response. Idempotency
+/// keys, retries and deserialization are handled by the client on top of it, so an
+/// implementation should not retry requests on its own.
+///
+/// Each runtime feature provides a default transport, exported as [`BaseClient`](crate::BaseClient),
+/// but any implementation can be plugged in using [`Client::with_transport`](crate::Client::with_transport).
+/// This makes it possible to share a connection pool with the rest of an application,
+/// or to run the client against an in-memory transport in tests.
+///
+/// ```no_run
+/// use http_types::{Request, Response, StatusCode};
+/// use stripe::{Client, Transport, TransportFuture};
+///
+/// struct AlwaysNotFound;
+///
+/// impl Transport for AlwaysNotFound {
+///     fn send(&self, _request: Request) -> TransportFuture {
+///         Box::pin(async { Ok(Response::new(StatusCode::NotFound)) })
+///     }
+/// }
+///
+/// let client = Client::new("sk_test_123").with_transport(AlwaysNotFound);
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send a request to the Stripe API, returning the response regardless of its status.
+    ///
+    /// Errors should only be returned if no response could be received at all.
+    fn send(&self, request: Request) -> TransportFuture;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    use http_types::{Request, Response, StatusCode};
+
+    use super::{Transport, TransportFuture};
+    use crate::{Client, RequestStrategy};
+
+    /// An in-memory transport that replays canned responses and records requests.
+    #[derive(Default)]
+    struct MockTransport {
+        responses: Mutex<VecDeque<Response>>,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockTransport {
+        fn new(responses: impl IntoIterator<Item = (StatusCode, &'static str)>) -> Self {
+            let responses = responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let mut response = Response::new(status);
+                    response.set_body(body);
+                    response
+                })
+                .collect();
+            Self { responses: Mutex::new(responses), ..Default::default() }
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn send(&self, request: Request) -> TransportFuture {
+            self.requests.lock().unwrap().push(request);
+            let response = self.responses.lock().unwrap().pop_front();
+            Box::pin(async move { Ok(response.expect("no more responses")) })
+        }
+    }
+
+    fn idempotency_keys(requests: &Mutex<Vec<Request>>) -> Vec<String> {
+        let requests = requests.lock().unwrap();
+        requests
+            .iter()
+            .filter_map(|r| r.header("Idempotency-Key").map(|h| h.last().to_string()))
+            .collect()
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retries_above_transport() {
+        let transport = MockTransport::new([
+            (StatusCode::InternalServerError, "{}"),
+            (StatusCode::InternalServerError, "{}"),
+            (StatusCode::Ok, r#"{"id": "cus_123"}"#),
+        ]);
+        let requests = transport.requests.clone();
+        let client = Client::new("sk_test_123")
+            .with_transport(transport)
+            .with_strategy(RequestStrategy::Idempotent("key".to_string()));
+
+        // the idempotent strategy only tries once
+        let res = client.get::<serde_json::Value>("customers/cus_123").await;
+        assert!(res.is_err());
+
+        let client = client.with_strategy(RequestStrategy::Retry(3));
+        let res = client.get::<serde_json::Value>("customers/cus_123").await.unwrap();
+        assert_eq!(res["id"], "cus_123");
+
+        let keys = idempotency_keys(&requests);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+        assert_eq!(keys[0], "key");
+        #[cfg(feature = "uuid")]
+        assert_eq!(keys[1], keys[2]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retry_policy() {
+        use std::time::Duration;
+
+        use crate::RetryPolicy;
+
+        let transport = MockTransport::new([
+            (StatusCode::Conflict, r#"{"error": {"type": "invalid_request_error"}}"#),
+            (StatusCode::TooManyRequests, r#"{"error": {"type": "invalid_request_error"}}"#),
+            (StatusCode::Ok, r#"{"id": "cus_123"}"#),
+            (StatusCode::BadRequest, r#"{"error": {"type": "invalid_request_error"}}"#),
+        ]);
+        let requests = transport.requests.clone();
+        let policy = RetryPolicy::new(3).base_delay(Duration::from_millis(1));
+        let client = Client::new("sk_test_123")
+            .with_transport(transport)
+            .with_strategy(RequestStrategy::Policy(policy));
+
+        // conflicts and rate limits are retried
+        let res = client.get::<serde_json::Value>("customers/cus_123").await.unwrap();
+        assert_eq!(res["id"], "cus_123");
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        // but other client errors are not
+        let res = client.get::<serde_json::Value>("customers/cus_123").await;
+        assert!(matches!(res, Err(crate::StripeError::Stripe(e)) if e.http_status == 400));
+        assert_eq!(requests.lock().unwrap().len(), 4);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn connect_timeout_keeps_transport() {
+        use std::time::Duration;
+
+        let transport = MockTransport::new([(StatusCode::Ok, r#"{"id": "cus_123"}"#)]);
+        let requests = transport.requests.clone();
+        let client = Client::new("sk_test_123")
+            .with_transport(transport)
+            .with_connect_timeout(Duration::from_secs(5));
+
+        let res = client.get::<serde_json::Value>("customers/cus_123").await.unwrap();
+        assert_eq!(res["id"], "cus_123");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn retries_above_transport() {
+        let transport = MockTransport::new([
+            (StatusCode::InternalServerError, "{}"),
+            (StatusCode::Ok, r#"{"id": "cus_123"}"#),
+        ]);
+        let requests = transport.requests.clone();
+        let client = Client::new("sk_test_123")
+            .with_transport(transport)
+            .with_strategy(RequestStrategy::Retry(3));
+
+        let res = client.get::<serde_json::Value>("customers/cus_123").unwrap();
+        assert_eq!(res["id"], "cus_123");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        // the key is stable across retries
+        assert!(idempotency_keys(&requests).windows(2).all(|keys| keys[0] == keys[1]));
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::params::to_snakecase;
+use crate::resources::ApiVersion;
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug, Error)]
@@ -187,6 +188,10 @@ pub struct RequestError {
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// The `Request-Id` of the failed request, useful when contacting support.
+    #[serde(skip_deserializing)]
+    pub request_id: Option<String>,
 }
 
 /// The structure of the json body when an error is included in
@@ -209,4 +214,113 @@ pub enum WebhookError {
     BadTimestamp(i64),
     #[error("error parsing event object")]
     BadParse(#[from] serde_json::Error),
+    #[error("{0}")]
+    ApiVersionMismatch(ApiVersionMismatch),
+}
+
+/// The reason a webhook was rejected by a [`WebhookVerifier`](crate::WebhookVerifier).
+#[derive(Debug, Error)]
+pub enum WebhookVerifyError {
+    #[error("missing Stripe-Signature header")]
+    MissingHeader,
+    #[error("malformed Stripe-Signature header")]
+    MalformedHeader,
+    #[error("no signature matches the endpoint secrets")]
+    NoMatchingSignature,
+    #[error("invalid endpoint secret")]
+    InvalidSecret,
+    #[error("signature timestamp {timestamp} is outside the tolerance at the current time {now}")]
+    OutsideTolerance { timestamp: i64, now: i64 },
+    #[error("error parsing event object: {0}")]
+    BadParse(#[from] serde_json::Error),
+    #[error("{0}")]
+    ApiVersionMismatch(ApiVersionMismatch),
+}
+
+impl From<WebhookVerifyError> for WebhookError {
+    fn from(err: WebhookVerifyError) -> Self {
+        match err {
+            WebhookVerifyError::MissingHeader
+            | WebhookVerifyError::MalformedHeader
+            | WebhookVerifyError::NoMatchingSignature => WebhookError::BadSignature,
+            WebhookVerifyError::InvalidSecret => WebhookError::BadKey,
+            WebhookVerifyError::OutsideTolerance { timestamp, .. } => {
+                WebhookError::BadTimestamp(timestamp)
+            }
+            WebhookVerifyError::BadParse(err) => WebhookError::BadParse(err),
+            WebhookVerifyError::ApiVersionMismatch(mismatch) => {
+                WebhookError::ApiVersionMismatch(mismatch)
+            }
+        }
+    }
+}
+
+/// The api version of an event differs from the version the models of this crate match,
+/// so some of its fields may be missing or fail to parse.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("event rendered with api version {}, expected {expected}", .found.as_deref().unwrap_or("unknown"))]
+pub struct ApiVersionMismatch {
+    /// The api version of this crate.
+    pub expected: ApiVersion,
+    /// The api version the event was rendered with, if known.
+    pub found: Option<String>,
+}
+
+/// An error encountered when dispatching an event with an [`EventRouter`](crate::EventRouter).
+#[cfg(feature = "webhook-events")]
+#[derive(Debug, Error)]
+pub enum EventRouterError<E> {
+    #[error("expected a {expected} object for a {type_} event, got a {found} object")]
+    ObjectMismatch { type_: crate::resources::EventType, expected: &'static str, found: String },
+    #[error("error handling event: {0}")]
+    Handler(E),
+    #[error("error recording event: {0}")]
+    Store(std::io::Error),
+}
+
+/// An error encountered when answering an `issuing_authorization.request` webhook.
+#[cfg(feature = "issuing")]
+#[derive(Debug, Error)]
+pub enum IssuingAuthorizationError {
+    #[cfg(feature = "events")]
+    #[error("expected an issuing_authorization.request event, got {0}")]
+    UnexpectedEvent(crate::resources::EventType),
+    #[error("expected an issuing.authorization object, got a {0} object")]
+    UnexpectedObject(String),
+    #[error("the authorization has no pending request")]
+    NoPendingRequest,
+    #[error("the amount of the pending request is not controllable")]
+    AmountNotControllable,
+    #[error("invalid amount {0}, the amount must be positive and at most the pending amount")]
+    InvalidAmount(i64),
+}
+
+/// An error encountered when dispatching an event with a [`ConnectRouter`](crate::ConnectRouter).
+///
+/// The resolver and the handlers of a router return the same error type `E`, so
+/// [`ConnectRouterError::Resolver`] and [`ConnectRouterError::Handler`] tell which of them
+/// failed.
+#[cfg(feature = "webhook-events")]
+#[derive(Debug, Error)]
+pub enum ConnectRouterError<E> {
+    #[error("expected an event with livemode {expected}, got livemode {found}")]
+    LivemodeMismatch { expected: bool, found: bool },
+    #[error("invalid account `{account}` in event: {source}")]
+    InvalidAccount { account: String, source: crate::ids::ParseIdError },
+    #[error("error resolving tenant: {0}")]
+    Resolver(E),
+    #[error("error handling event: {0}")]
+    Handler(E),
+}
+
+/// An error encountered when polling events with an [`EventPoller`](crate::EventPoller).
+#[cfg(all(feature = "webhook-events", feature = "async", feature = "stream"))]
+#[derive(Debug, Error)]
+pub enum EventPollerError {
+    #[error("error listing events: {0}")]
+    Stripe(#[from] StripeError),
+    #[error("error accessing checkpoint: {0}")]
+    Checkpoint(#[from] std::io::Error),
+    #[error("the checkpoint at {0} is older than the events kept by stripe")]
+    Expired(crate::params::Timestamp),
 }
@@ -0,0 +1,133 @@
+//! Extractors for verified webhook events in web frameworks.
+//!
+//! Each framework is enabled by the cargo feature of the same name, and provides a
+//! `StripeEvent` extractor which reads the `Stripe-Signature` header, buffers the body
+//! and verifies it using the [`WebhookConfig`] stored in the state of the application.
+//! Requests with a missing or invalid signature are rejected with `400 Bad Request`, and
+//! requests received by an application without a config or with an invalid secret with
+//! `500 Internal Server Error`, see [`WebhookRejection::is_client_error`].
+
+use std::time::Duration;
+
+use crate::error::WebhookVerifyError;
+use crate::resources::{Event, WebhookVerifier};
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+
+/// The secrets used to verify the webhooks received by an application.
+///
+/// Several secrets can be provided while an endpoint secret is being rolled.
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    verifier: WebhookVerifier,
+}
+
+impl WebhookConfig {
+    /// Create a config which verifies webhooks using the given endpoint secret.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self { verifier: WebhookVerifier::new(secret) }
+    }
+
+    /// Also accept webhooks signed with the given endpoint secret.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.verifier = self.verifier.secret(secret);
+        self
+    }
+
+    /// Set how far the timestamp of a signature may be from the current time.
+    ///
+    /// This applies to timestamps ahead of the current time as well, so that a captured
+    /// webhook signed with a timestamp in the future can't be replayed indefinitely.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.verifier = self.verifier.tolerance(tolerance);
+        self
+    }
+
+    /// Set whether events rendered with another api version than the one this crate
+    /// matches are rejected, see [`WebhookVerifier::strict`].
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.verifier = self.verifier.strict(strict);
+        self
+    }
+
+    /// Verify a webhook request, returning the event it contains.
+    pub fn construct_event(
+        &self,
+        payload: &[u8],
+        signature: Option<&str>,
+    ) -> Result<Event, WebhookRejection> {
+        let signature = signature.ok_or(WebhookRejection::MissingSignature)?;
+        self.verifier.construct_event(payload, signature).map_err(WebhookRejection::Invalid)
+    }
+}
+
+/// The reason a webhook request was rejected by an extractor.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookRejection {
+    #[error("missing Stripe-Signature header")]
+    MissingSignature,
+    #[error("invalid webhook: {0}")]
+    Invalid(#[from] WebhookVerifyError),
+    #[error("failed to read the request body: {0}")]
+    Body(#[source] std::io::Error),
+    #[error("no WebhookConfig was provided to the application")]
+    MissingConfig,
+}
+
+impl WebhookRejection {
+    /// Whether the request was rejected because of the request itself, rather than because
+    /// the application is misconfigured.
+    pub fn is_client_error(&self) -> bool {
+        !matches!(self, Self::MissingConfig | Self::Invalid(WebhookVerifyError::InvalidSecret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookConfig, WebhookRejection};
+    use crate::{Customer, EventObject, EventType, TestEvent, WebhookVerifyError};
+
+    #[test]
+    fn construct_event() {
+        let event = TestEvent::new(
+            EventType::CustomerCreated,
+            EventObject::Customer(Customer {
+                id: "cus_123".parse().unwrap(),
+                ..Default::default()
+            }),
+        )
+        .sign("whsec_new")
+        .unwrap();
+        let config = WebhookConfig::new("whsec_old").secret("whsec_new");
+
+        let res = config.construct_event(event.payload.as_bytes(), Some(&event.signature));
+        assert_eq!(res.unwrap().type_, EventType::CustomerCreated);
+
+        let res = config.construct_event(event.payload.as_bytes(), None);
+        assert!(matches!(res, Err(WebhookRejection::MissingSignature)));
+
+        let res = WebhookConfig::new("whsec_old")
+            .construct_event(event.payload.as_bytes(), Some(&event.signature));
+        assert!(matches!(
+            &res,
+            Err(err @ WebhookRejection::Invalid(WebhookVerifyError::NoMatchingSignature))
+                if err.is_client_error()
+        ));
+
+        let res = config.construct_event(event.payload.as_bytes(), Some("v1=abc"));
+        assert!(matches!(res, Err(WebhookRejection::Invalid(WebhookVerifyError::MalformedHeader))));
+
+        assert!(!WebhookRejection::Invalid(WebhookVerifyError::InvalidSecret).is_client_error());
+        assert!(!WebhookRejection::MissingConfig.is_client_error());
+
+        // the event of the test has the api version of this crate
+        let res =
+            config.strict(true).construct_event(event.payload.as_bytes(), Some(&event.signature));
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,103 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{web, FromRequest, HttpRequest, ResponseError};
+
+use super::{WebhookConfig, WebhookRejection};
+use crate::resources::Event;
+
+/// A webhook event, verified using the [`WebhookConfig`] in the app data.
+///
+/// The config can be registered either directly or wrapped in [`web::Data`].
+///
+/// ```no_run
+/// use actix_web::{post, App};
+/// use stripe::extract::{actix_web::StripeEvent, WebhookConfig};
+///
+/// #[post("/stripe_webhooks")]
+/// async fn handle_webhook(StripeEvent(event): StripeEvent) -> &'static str {
+///     println!("received {:?}", event.type_);
+///     "ok"
+/// }
+///
+/// let app = App::new().app_data(WebhookConfig::new("whsec_xxxxx")).service(handle_webhook);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+impl FromRequest for StripeEvent {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let payload = web::Bytes::from_request(&req, payload);
+
+        Box::pin(async move {
+            let payload = payload.await?;
+            let config = req
+                .app_data::<WebhookConfig>()
+                .or_else(|| req.app_data::<web::Data<WebhookConfig>>().map(|data| data.as_ref()))
+                .ok_or(WebhookRejection::MissingConfig)?;
+            let signature =
+                req.headers().get("stripe-signature").and_then(|value| value.to_str().ok());
+
+            Ok(config.construct_event(&payload, signature).map(Self)?)
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        if self.is_client_error() {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, FromRequest};
+
+    use super::StripeEvent;
+    use crate::extract::WebhookConfig;
+    use crate::{Customer, EventObject, EventType, TestEvent};
+
+    #[tokio::test]
+    async fn extract_event() {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let event = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .sign("whsec_123")
+            .unwrap();
+
+        let (req, mut payload) = TestRequest::post()
+            .app_data(web::Data::new(WebhookConfig::new("whsec_123")))
+            .insert_header(("Stripe-Signature", event.signature.as_str()))
+            .set_payload(event.payload.clone())
+            .to_http_parts();
+        let StripeEvent(extracted) = StripeEvent::from_request(&req, &mut payload).await.unwrap();
+        assert_eq!(extracted.type_, EventType::CustomerCreated);
+
+        let (req, mut payload) = TestRequest::post()
+            .app_data(WebhookConfig::new("whsec_other"))
+            .insert_header(("Stripe-Signature", event.signature.as_str()))
+            .set_payload(event.payload.clone())
+            .to_http_parts();
+        let err = StripeEvent::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), StatusCode::BAD_REQUEST);
+
+        // the config must be registered
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("Stripe-Signature", event.signature.as_str()))
+            .set_payload(event.payload)
+            .to_http_parts();
+        let err = StripeEvent::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,104 @@
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use super::{WebhookConfig, WebhookRejection};
+use crate::resources::Event;
+
+/// A webhook event, verified using the [`WebhookConfig`] in the state of the router.
+///
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use stripe::extract::{axum::StripeEvent, WebhookConfig};
+///
+/// async fn handle_webhook(StripeEvent(event): StripeEvent) {
+///     println!("received {:?}", event.type_);
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/stripe_webhooks", post(handle_webhook))
+///     .with_state(WebhookConfig::new("whsec_xxxxx"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+#[async_trait]
+impl<S> FromRequest<S> for StripeEvent
+where
+    WebhookConfig: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signature = req
+            .headers()
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let payload = Bytes::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+
+        WebhookConfig::from_ref(state)
+            .construct_event(&payload, signature.as_deref())
+            .map(Self)
+            .map_err(IntoResponse::into_response)
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        let status = if self.is_client_error() {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::{FromRequest, Request};
+    use axum::http::StatusCode;
+
+    use super::StripeEvent;
+    use crate::extract::WebhookConfig;
+    use crate::{Customer, EventObject, EventType, TestEvent};
+
+    fn request(payload: String, signature: Option<&str>) -> Request {
+        let mut builder = Request::post("/stripe_webhooks");
+        if let Some(signature) = signature {
+            builder = builder.header("Stripe-Signature", signature);
+        }
+        builder.body(Body::from(payload)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn extract_event() {
+        let config = WebhookConfig::new("whsec_123");
+        let event = TestEvent::new(
+            EventType::CustomerCreated,
+            EventObject::Customer(Customer {
+                id: "cus_123".parse().unwrap(),
+                ..Default::default()
+            }),
+        )
+        .sign("whsec_123")
+        .unwrap();
+
+        let req = request(event.payload.clone(), Some(&event.signature));
+        let StripeEvent(extracted) = StripeEvent::from_request(req, &config).await.unwrap();
+        assert_eq!(extracted.type_, EventType::CustomerCreated);
+
+        let req = request(event.payload.clone(), None);
+        let res = StripeEvent::from_request(req, &config).await.unwrap_err();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let req = request(event.payload, Some("t=1,v1=00"));
+        let res = StripeEvent::from_request(req, &config).await.unwrap_err();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,108 @@
+use std::io;
+
+use rocket::data::{self, Data, FromData, Limits};
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::Request;
+
+use super::{WebhookConfig, WebhookRejection};
+use crate::resources::Event;
+
+/// A webhook event, verified using the [`WebhookConfig`] managed by the application.
+///
+/// The body is read up to the `json` limit of the application, which defaults to 1 MiB.
+///
+/// ```no_run
+/// use rocket::{post, routes};
+/// use stripe::extract::{rocket::StripeEvent, WebhookConfig};
+///
+/// #[post("/stripe_webhooks", data = "<event>")]
+/// fn handle_webhook(event: StripeEvent) {
+///     println!("received {:?}", event.0.type_);
+/// }
+///
+/// let rocket = rocket::build()
+///     .manage(WebhookConfig::new("whsec_xxxxx"))
+///     .mount("/", routes![handle_webhook]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for StripeEvent {
+    type Error = WebhookRejection;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let Some(config) = req.rocket().state::<WebhookConfig>() else {
+            return Outcome::Error((Status::InternalServerError, WebhookRejection::MissingConfig));
+        };
+
+        let limit = req.limits().get("json").unwrap_or(Limits::JSON);
+        let payload = match data.open(limit).into_bytes().await {
+            Ok(payload) if payload.is_complete() => payload.into_inner(),
+            Ok(_) => {
+                let err = io::Error::new(io::ErrorKind::InvalidData, "the body is too large");
+                return Outcome::Error((Status::PayloadTooLarge, WebhookRejection::Body(err)));
+            }
+            Err(err) => return Outcome::Error((Status::BadRequest, WebhookRejection::Body(err))),
+        };
+
+        match config.construct_event(&payload, req.headers().get_one("Stripe-Signature")) {
+            Ok(event) => Outcome::Success(Self(event)),
+            Err(err) if err.is_client_error() => Outcome::Error((Status::BadRequest, err)),
+            Err(err) => Outcome::Error((Status::InternalServerError, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::{Header, Status};
+    use rocket::local::asynchronous::Client;
+    use rocket::{post, routes};
+
+    use super::StripeEvent;
+    use crate::extract::WebhookConfig;
+    use crate::{Customer, EventObject, EventType, TestEvent};
+
+    #[post("/stripe_webhooks", data = "<event>")]
+    fn handle_webhook(event: StripeEvent) -> String {
+        event.0.id.to_string()
+    }
+
+    #[tokio::test]
+    async fn extract_event() {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let event = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .sign("whsec_123")
+            .unwrap();
+
+        let rocket = rocket::build()
+            .manage(WebhookConfig::new("whsec_123"))
+            .mount("/", routes![handle_webhook]);
+        let client = Client::untracked(rocket).await.unwrap();
+
+        let res = client
+            .post("/stripe_webhooks")
+            .header(Header::new("Stripe-Signature", event.signature.clone()))
+            .body(&event.payload)
+            .dispatch()
+            .await;
+        assert_eq!(res.status(), Status::Ok);
+        assert_eq!(res.into_string().await.as_deref(), Some("evt_test"));
+
+        let res = client.post("/stripe_webhooks").body(&event.payload).dispatch().await;
+        assert_eq!(res.status(), Status::BadRequest);
+
+        // the config must be managed by the application
+        let rocket = rocket::build().mount("/", routes![handle_webhook]);
+        let client = Client::untracked(rocket).await.unwrap();
+        let res = client
+            .post("/stripe_webhooks")
+            .header(Header::new("Stripe-Signature", event.signature))
+            .body(&event.payload)
+            .dispatch()
+            .await;
+        assert_eq!(res.status(), Status::InternalServerError);
+    }
+}
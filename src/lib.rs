@@ -48,6 +48,10 @@
 //! - [`RequestStrategy::ExponentialBackoff`]: Make a request to the Stripe API and, if the request fails, retry
 //!                                            it up to n times with exponential backoff. The idempotency key is
 //!                                            generated automatically and is stable across retries.
+//! - [`RequestStrategy::Policy`]: Make a request to the Stripe API and, if the request fails, retry it according
+//!                                to a [`RetryPolicy`], which supports jitter, a total time budget and stripe's
+//!                                `Retry-After` header. The idempotency key is generated automatically and is
+//!                                stable across retries.
 //!
 //! > Want to implement your own? If it is a common strategy, please consider opening a PR to add it to the library.
 //! > Otherwise, we are open to turning this into an open trait so that you can implement your own strategy.
@@ -66,6 +70,8 @@
 
 mod client;
 mod error;
+#[cfg(any(feature = "axum", feature = "actix-web", feature = "rocket"))]
+pub mod extract;
 mod ids;
 mod params;
 mod resources;
@@ -78,8 +84,21 @@ mod resources;
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
 // See https://github.com/rust-lang/rust/issues/44265
 pub use crate::client::*;
-pub use crate::error::{ErrorCode, ErrorType, RequestError, StripeError, WebhookError};
+#[cfg(all(feature = "webhook-events", feature = "async", feature = "stream"))]
+pub use crate::error::EventPollerError;
+#[cfg(feature = "issuing")]
+pub use crate::error::IssuingAuthorizationError;
+pub use crate::error::{
+    ApiVersionMismatch, ErrorCode, ErrorType, RequestError, StripeError, WebhookError,
+    WebhookVerifyError,
+};
+#[cfg(feature = "webhook-events")]
+pub use crate::error::{ConnectRouterError, EventRouterError};
 pub use crate::ids::*;
+#[cfg(feature = "async")]
+pub use crate::params::PaginationLimits;
+#[cfg(all(feature = "async", feature = "stream"))]
+pub use crate::params::PartitionedExport;
 pub use crate::params::{
     Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, SearchList,
     Timestamp,
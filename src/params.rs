@@ -180,6 +180,18 @@ pub trait Paginable {
     fn set_last(&mut self, item: Self::O);
 }
 
+/// Implemented by the parameters of list requests which can also be paginated backwards,
+/// using the id of the first object as the `ending_before` cursor.
+pub trait ReversePaginable: Paginable {
+    fn set_first(&mut self, item: Self::O);
+}
+
+/// Implemented by the parameters of search requests, which are paginated using the
+/// `next_page` token of a [`SearchList`] rather than the id of the last object.
+pub trait PaginableSearch {
+    fn set_page(&mut self, page: String);
+}
+
 pub trait PaginableList {
     type O: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::Debug;
     fn new(data: Vec<Self::O>, url: String, has_more: bool, total_count: Option<u64>) -> Self;
@@ -217,17 +229,12 @@ impl<T> Default for SearchList<T> {
 }
 
 impl<T: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::Debug> PaginableList
-    for SearchList<T>
+    for List<T>
 {
     type O = T;
 
-    fn new(
-        data: Vec<Self::O>,
-        url: String,
-        has_more: bool,
-        total_count: Option<u64>,
-    ) -> SearchList<T> {
-        Self { object: "".to_string(), url, has_more, data, next_page: None, total_count }
+    fn new(data: Vec<Self::O>, url: String, has_more: bool, total_count: Option<u64>) -> List<T> {
+        Self { url, has_more, data, total_count }
     }
 
     fn get_data_mut(&mut self) -> &mut Vec<Self::O> {
@@ -237,6 +244,7 @@ impl<T: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::
     fn get_data(&self) -> &Vec<Self::O> {
         &self.data
     }
+
     fn get_url(&self) -> String {
         self.url.clone()
     }
@@ -248,37 +256,143 @@ impl<T: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::
     }
 }
 
-impl<T: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::Debug> PaginableList
-    for List<T>
-{
-    type O = T;
+impl<T> SearchList<T> {
+    pub fn paginate<P>(self, params: P) -> SearchPaginator<T, P> {
+        SearchPaginator { page: self, params }
+    }
+}
 
-    fn new(data: Vec<Self::O>, url: String, has_more: bool, total_count: Option<u64>) -> List<T> {
-        Self { url, has_more, data, total_count }
+/// Paginates the results of a search, following the `next_page` token of each page.
+#[derive(Debug)]
+pub struct SearchPaginator<T, P> {
+    pub page: SearchList<T>,
+    pub params: P,
+}
+
+impl<
+        T: DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::Debug,
+        P: Clone + Serialize + Send + 'static + std::fmt::Debug,
+    > SearchPaginator<T, P>
+where
+    P: PaginableSearch,
+{
+    /// Repeatedly queries Stripe for more results until all of them are fetched, using
+    /// Stripe's default page size.
+    ///
+    /// Requires `feature = "blocking"`.
+    #[cfg(feature = "blocking")]
+    pub fn get_all(self, client: &Client) -> Response<Vec<T>> {
+        let mut data = Vec::with_capacity(self.page.total_count.unwrap_or(0) as usize);
+        let mut paginator = self;
+        loop {
+            data.append(&mut paginator.page.data);
+            if !paginator.has_more() {
+                break;
+            }
+            paginator = paginator.next(client)?;
+        }
+        Ok(data)
     }
 
-    fn get_data_mut(&mut self) -> &mut Vec<Self::O> {
-        &mut self.data
+    /// Get all the results of the search, consuming self and lazily paginating until all
+    /// of them are fetched.
+    ///
+    /// ```no_run
+    /// # use stripe::{Customer, CustomerSearchParams, StripeError, Client};
+    /// # use futures_util::TryStreamExt;
+    /// # async fn run() -> Result<(), StripeError> {
+    /// # let client = Client::new("sk_test_123");
+    /// let params = CustomerSearchParams {
+    ///     query: "email~'example.com'".to_string(),
+    ///     ..CustomerSearchParams::new()
+    /// };
+    /// let customers = Customer::search(&client, params.clone()).await?.paginate(params);
+    /// let all_customers = customers.stream(&client).try_collect::<Vec<_>>().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires `feature = ["async", "stream"]`.
+    #[cfg(all(feature = "async", feature = "stream"))]
+    pub fn stream(
+        mut self,
+        client: &Client,
+    ) -> impl futures_util::Stream<Item = Result<T, StripeError>> + Unpin {
+        // We are going to be popping items off the end of the page, so we need to reverse it.
+        self.page.data.reverse();
+
+        Box::pin(futures_util::stream::unfold(Some((self, client.clone())), Self::unfold_stream))
     }
 
-    fn get_data(&self) -> &Vec<Self::O> {
-        &self.data
+    /// unfold a single item from the stream
+    #[cfg(all(feature = "async", feature = "stream"))]
+    async fn unfold_stream(
+        state: Option<(Self, Client)>,
+    ) -> Option<(Result<T, StripeError>, Option<(Self, Client)>)> {
+        let (mut paginator, client) = state?; // If none, we sent the last item or an error
+
+        loop {
+            if let Some(item) = paginator.page.data.pop() {
+                return Some((Ok(item), Some((paginator, client))));
+            }
+            if !paginator.has_more() {
+                return None;
+            }
+            match paginator.next(&client).await {
+                Ok(mut next_paginator) => {
+                    next_paginator.page.data.reverse();
+                    paginator = next_paginator;
+                }
+                Err(e) => return Some((Err(e), None)), // The last value of the stream will be the error.
+            }
+        }
     }
 
-    fn get_url(&self) -> String {
-        self.url.clone()
+    /// Fetch the next page of results from stripe.
+    pub fn next(&self, client: &Client) -> Response<Self> {
+        match &self.page.next_page {
+            Some(next_page) if self.page.url.starts_with("/v1/") => {
+                let path = self.page.url.trim_start_matches("/v1/").to_string(); // the url we get back is prefixed
+
+                // clone the params and set the page token
+                let params_next = {
+                    let mut p = self.params.clone();
+                    p.set_page(next_page.clone());
+                    p
+                };
+
+                let page = client.get_query(&path, &params_next);
+
+                SearchPaginator::create_paginator(page, params_next)
+            }
+            Some(_) => err(StripeError::UnsupportedVersion),
+            None => ok(SearchPaginator {
+                page: SearchList {
+                    url: self.page.url.clone(),
+                    total_count: self.page.total_count,
+                    ..SearchList::default()
+                },
+                params: self.params.clone(),
+            }),
+        }
     }
-    fn get_total_count(&self) -> Option<u64> {
-        self.total_count
+
+    /// Whether there are more pages to fetch with [`SearchPaginator::next`].
+    pub fn has_more(&self) -> bool {
+        self.page.has_more && self.page.next_page.is_some()
     }
-    fn has_more(&self) -> bool {
-        self.has_more
+
+    /// Pin a new future which maps the result inside the page future into
+    /// a SearchPaginator
+    #[cfg(feature = "async")]
+    fn create_paginator(page: Response<SearchList<T>>, params: P) -> Response<Self> {
+        use futures_util::FutureExt;
+        Box::pin(page.map(|page| page.map(|page| SearchPaginator { page, params })))
     }
-}
 
-impl<T> SearchList<T> {
-    pub fn paginate<P>(self, params: P) -> ListPaginator<SearchList<T>, P> {
-        ListPaginator { page: self, params }
+    #[cfg(feature = "blocking")]
+    fn create_paginator(page: Response<SearchList<T>>, params: P) -> Response<Self> {
+        page.map(|page| SearchPaginator { page, params })
     }
 }
 
@@ -303,6 +417,26 @@ impl<T> List<T> {
     pub fn paginate<P>(self, params: P) -> ListPaginator<List<T>, P> {
         ListPaginator { page: self, params }
     }
+
+    /// Paginate backwards through the list, from a page fetched with `ending_before`.
+    ///
+    /// Stripe returns lists newest first, so this walks towards the newest objects, which is
+    /// what incremental syncs need to fetch the objects created after a checkpoint.
+    pub fn paginate_reverse<P>(self, params: P) -> ReverseListPaginator<List<T>, P> {
+        ReverseListPaginator { page: self, params }
+    }
+}
+
+/// The limits of [`ListPaginator::collect_all`].
+///
+/// Requires `feature = "async"`.
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PaginationLimits {
+    /// The maximum number of values to collect.
+    pub max_items: Option<usize>,
+    /// The maximum number of pages to fetch, including the first one.
+    pub max_pages: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -405,6 +539,185 @@ where
         }
     }
 
+    /// Get all values in this List like [`ListPaginator::stream`], but fetch the next page
+    /// while the values of the current page are being consumed.
+    ///
+    /// At most one page is fetched ahead, so this uses twice the memory of
+    /// [`ListPaginator::stream`] in exchange for hiding the latency of the requests.
+    ///
+    /// Requires `feature = ["async", "stream"]`.
+    #[cfg(all(feature = "async", feature = "stream"))]
+    pub fn prefetch_stream(
+        self,
+        client: &Client,
+    ) -> impl futures_util::Stream<Item = Result<T::O, StripeError>> + Unpin {
+        PrefetchStream::new(self, client.clone())
+    }
+
+    /// Lazily iterate over all values in this List, fetching the next page once the
+    /// values of the current one are consumed.
+    ///
+    /// The iterator ends after yielding an error.
+    ///
+    /// Requires `feature = "blocking"`.
+    #[cfg(feature = "blocking")]
+    pub fn iter(mut self, client: &Client) -> impl Iterator<Item = Result<T::O, StripeError>> {
+        // We are going to be popping items off the end of the list, so we need to reverse it.
+        self.page.get_data_mut().reverse();
+
+        let client = client.clone();
+        let mut state = Some(self);
+        std::iter::from_fn(move || {
+            let paginator = state.as_mut()?;
+
+            if paginator.page.get_data().len() > 1 || !paginator.page.has_more() {
+                let value = paginator.page.get_data_mut().pop();
+                if value.is_none() {
+                    state = None;
+                }
+                return value.map(Ok);
+            }
+
+            match paginator.next(&client) {
+                Ok(mut next_paginator) => {
+                    let value = paginator.page.get_data_mut().pop();
+                    next_paginator.page.get_data_mut().reverse();
+                    state = Some(next_paginator);
+                    value.map(Ok)
+                }
+                Err(e) => {
+                    state = None;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+
+    /// Collect the values in this List, fetching pages until the list ends or one of the
+    /// limits is reached.
+    ///
+    /// ```no_run
+    /// # use stripe::{Customer, ListCustomers, PaginationLimits, StripeError, Client};
+    /// # async fn run() -> Result<(), StripeError> {
+    /// # let client = Client::new("sk_test_123");
+    /// let params = ListCustomers { limit: Some(100), ..Default::default() };
+    /// let list = Customer::list(&client, &params).await?.paginate(params);
+    ///
+    /// let limits = PaginationLimits { max_items: Some(1000), ..Default::default() };
+    /// let customers = list.collect_all(&client, limits).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires `feature = "async"`.
+    #[cfg(feature = "async")]
+    pub async fn collect_all(
+        self,
+        client: &Client,
+        limits: PaginationLimits,
+    ) -> Result<Vec<T::O>, StripeError> {
+        let max_items = limits.max_items.unwrap_or(usize::MAX);
+        let mut data = Vec::new();
+        let mut pages = 1;
+        let mut paginator = self;
+        loop {
+            let (mut page, cursor) = paginator.split_page();
+            page.truncate(max_items - data.len());
+            data.append(&mut page);
+
+            match cursor {
+                Some(cursor)
+                    if data.len() < max_items
+                        && !limits.max_pages.is_some_and(|max_pages| pages >= max_pages) =>
+                {
+                    paginator = cursor.next(client).await?;
+                    pages += 1;
+                }
+                _ => return Ok(data),
+            }
+        }
+    }
+
+    /// Pass each page of values in this List to `f`, fetching the next page only once
+    /// `f` succeeds, which makes it a good place to checkpoint the progress.
+    ///
+    /// ```no_run
+    /// # use stripe::{Customer, ListCustomers, StripeError, Client};
+    /// # async fn run() -> Result<(), StripeError> {
+    /// # let client = Client::new("sk_test_123");
+    /// let params = ListCustomers { limit: Some(100), ..Default::default() };
+    /// let list = Customer::list(&client, &params).await?.paginate(params);
+    ///
+    /// list.try_for_each_page(&client, |customers| async move {
+    ///     if let Some(last) = customers.last() {
+    ///         println!("synced up to {}", last.id);
+    ///     }
+    ///     Ok::<_, StripeError>(())
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires `feature = "async"`.
+    #[cfg(feature = "async")]
+    pub async fn try_for_each_page<F, Fut, E>(self, client: &Client, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Vec<T::O>) -> Fut,
+        Fut: std::future::Future<Output = Result<(), E>>,
+        E: From<StripeError>,
+    {
+        let mut paginator = self;
+        loop {
+            let (data, cursor) = paginator.split_page();
+            f(data).await?;
+            match cursor {
+                Some(cursor) => paginator = cursor.next(client).await?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Pass each page of values in this List to `f`, fetching the next page only once
+    /// `f` succeeds, which makes it a good place to checkpoint the progress.
+    ///
+    /// Requires `feature = "blocking"`.
+    #[cfg(feature = "blocking")]
+    pub fn try_for_each_page<F, E>(self, client: &Client, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Vec<T::O>) -> Result<(), E>,
+        E: From<StripeError>,
+    {
+        let mut paginator = self;
+        loop {
+            let (data, cursor) = paginator.split_page();
+            f(data)?;
+            match cursor {
+                Some(cursor) => paginator = cursor.next(client)?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Take the values of the current page, leaving a paginator holding only the cursor
+    /// when there are more pages.
+    fn split_page(mut self) -> (Vec<T::O>, Option<Self>) {
+        let data = std::mem::take(self.page.get_data_mut());
+        let cursor = match data.last() {
+            Some(last) if self.page.has_more() => Some(ListPaginator {
+                page: T::new(
+                    vec![last.clone()],
+                    self.page.get_url(),
+                    true,
+                    self.page.get_total_count(),
+                ),
+                params: self.params,
+            }),
+            _ => None,
+        };
+        (data, cursor)
+    }
+
     /// Fetch an additional page of data from stripe.
     pub fn next(&self, client: &Client) -> Response<Self> {
         if let Some(last) = self.page.get_data().last() {
@@ -446,71 +759,537 @@ where
     }
 }
 
-pub type CurrencyMap<V> = HashMap<Currency, V>;
-pub type Metadata = HashMap<String, String>;
-pub type Timestamp = i64;
+/// The stream returned by [`ListPaginator::prefetch_stream`].
+///
+/// The request for the next page is spawned on the runtime, so it progresses while the
+/// buffered values of the current page are consumed. It is aborted when the stream is
+/// dropped, so that it doesn't keep running in the background.
+#[cfg(all(feature = "async", feature = "stream"))]
+struct PrefetchStream<T: PaginableList, P> {
+    client: Client,
+    buffer: std::collections::VecDeque<T::O>,
+    fetching: Option<Response<ListPaginator<T, P>>>,
+    abort: Option<futures_util::future::AbortHandle>,
+    fetched: Option<ListPaginator<T, P>>,
+    error: Option<StripeError>,
+}
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub struct RangeBounds<T> {
-    pub gt: Option<T>,
-    pub gte: Option<T>,
-    pub lt: Option<T>,
-    pub lte: Option<T>,
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<T: PaginableList, P> Drop for PrefetchStream<T, P> {
+    fn drop(&mut self) {
+        if let Some(abort) = &self.abort {
+            abort.abort();
+        }
+    }
 }
 
-impl<T> Default for RangeBounds<T> {
-    fn default() -> Self {
-        RangeBounds { gt: None, gte: None, lt: None, lte: None }
+// N.B. the fields are never pinned, the request future is boxed
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<T: PaginableList, P> Unpin for PrefetchStream<T, P> {}
+
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<
+        T: PaginableList + Send + DeserializeOwned + 'static,
+        P: Clone + Serialize + Send + 'static + std::fmt::Debug,
+    > PrefetchStream<T, P>
+where
+    P: Paginable<O = T::O>,
+{
+    fn new(paginator: ListPaginator<T, P>, client: Client) -> Self {
+        // N.B. the first page is buffered on the first poll, so nothing is spawned outside
+        // of the runtime
+        PrefetchStream {
+            client,
+            buffer: Default::default(),
+            fetching: None,
+            abort: None,
+            fetched: Some(paginator),
+            error: None,
+        }
+    }
+
+    /// Buffer the values of a page and start fetching the one after it.
+    fn advance(&mut self, mut paginator: ListPaginator<T, P>) {
+        use futures_util::FutureExt;
+
+        if paginator.page.has_more() {
+            let (next, abort) = futures_util::future::abortable(paginator.next(&self.client));
+            // N.B. the task is only aborted once the stream, and so its result, is dropped
+            let next = next.map(|res| {
+                res.unwrap_or_else(|_| Err(StripeError::ClientError("request aborted".into())))
+            });
+            self.fetching = Some(crate::client::config::spawn(Box::pin(next)));
+            self.abort = Some(abort);
+        }
+        self.buffer.extend(paginator.page.get_data_mut().drain(..));
     }
 }
 
-/// A set of generic request parameters that can be used on
-/// list endpoints to filter their results by some timestamp.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(untagged)]
-pub enum RangeQuery<T> {
-    Exact(T),
-    Bounds(RangeBounds<T>),
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<
+        T: PaginableList + Send + DeserializeOwned + 'static,
+        P: Clone + Serialize + Send + 'static + std::fmt::Debug,
+    > futures_util::Stream for PrefetchStream<T, P>
+where
+    P: Paginable<O = T::O>,
+{
+    type Item = Result<T::O, StripeError>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        loop {
+            if let Some(fetching) = &mut this.fetching {
+                if let Poll::Ready(res) = std::future::Future::poll(fetching.as_mut(), cx) {
+                    this.fetching = None;
+                    match res {
+                        Ok(paginator) => this.fetched = Some(paginator),
+                        Err(e) => this.error = Some(e),
+                    }
+                }
+            }
+
+            if let Some(value) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(value)));
+            }
+            match this.fetched.take() {
+                // the next page arrived, buffer it and loop to start polling the following one
+                Some(paginator) => this.advance(paginator),
+                None if this.fetching.is_some() => return Poll::Pending,
+                None => return Poll::Ready(this.error.take().map(Err)),
+            }
+        }
+    }
 }
 
-impl<T> RangeQuery<T> {
-    /// Filter results to exactly match a given value
-    pub fn eq(value: T) -> RangeQuery<T> {
-        RangeQuery::Exact(value)
+/// Exports a list by splitting its `created` window into partitions, which are paginated
+/// concurrently and merged in the order of the list.
+///
+/// ```no_run
+/// # use stripe::{Customer, ListCustomers, PartitionedExport, RangeBounds, RangeQuery, StripeError, Client};
+/// # use futures_util::TryStreamExt;
+/// # async fn run() -> Result<(), StripeError> {
+/// # let client = Client::new("sk_test_123");
+/// let created = RangeQuery::Bounds(RangeBounds {
+///     gte: Some(1672531200),
+///     lt: Some(1704067200),
+///     ..Default::default()
+/// });
+/// let export = PartitionedExport::new(created).partitions(12).concurrency(4);
+///
+/// let mut stream = export.stream(&client, Customer::list, |created| ListCustomers {
+///     created: Some(created),
+///     limit: Some(100),
+///     ..Default::default()
+/// });
+/// while let Some(customer) = stream.try_next().await? {
+///     println!("{}", customer.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires `feature = ["async", "stream"]`.
+#[cfg(all(feature = "async", feature = "stream"))]
+#[derive(Clone, Debug)]
+pub struct PartitionedExport {
+    created: RangeQuery<Timestamp>,
+    partitions: usize,
+    concurrency: usize,
+}
+
+#[cfg(all(feature = "async", feature = "stream"))]
+impl PartitionedExport {
+    /// Export the objects created in the given window, in 4 partitions paginated
+    /// 4 at a time.
+    pub fn new(created: RangeQuery<Timestamp>) -> Self {
+        PartitionedExport { created, partitions: 4, concurrency: 4 }
     }
 
-    /// Filter results to be after a given value
-    pub fn gt(value: T) -> RangeQuery<T> {
-        RangeQuery::Bounds(RangeBounds { gt: Some(value), ..Default::default() })
+    /// Set the number of partitions the window is split into.
+    pub fn partitions(mut self, partitions: usize) -> Self {
+        self.partitions = partitions.max(1);
+        self
     }
 
-    /// Filter results to be after or equal to a given value
-    pub fn gte(value: T) -> RangeQuery<T> {
-        RangeQuery::Bounds(RangeBounds { gte: Some(value), ..Default::default() })
+    /// Set how many partitions are paginated at the same time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
     }
 
-    /// Filter results to be before to a given value
-    pub fn lt(value: T) -> RangeQuery<T> {
-        RangeQuery::Bounds(RangeBounds { lt: Some(value), ..Default::default() })
+    /// The `created` ranges of the partitions, newest first like the lists of stripe.
+    ///
+    /// A window without both a lower and an upper bound can't be split, and is exported
+    /// as a single partition. An empty window has no partitions.
+    pub fn ranges(&self) -> Vec<RangeQuery<Timestamp>> {
+        let (start, end) = match &self.created {
+            RangeQuery::Bounds(bounds) => (
+                bounds.gte.or(bounds.gt.map(|gt| gt.saturating_add(1))),
+                bounds.lt.or(bounds.lte.map(|lte| lte.saturating_add(1))),
+            ),
+            RangeQuery::Exact(_) => (None, None),
+        };
+        let (Some(start), Some(end)) = (start, end) else {
+            return vec![self.created.clone()];
+        };
+
+        if end <= start {
+            return Vec::new();
+        }
+
+        // N.B. the bounds are computed in i128, since the products overflow an i64 for
+        //      wide windows
+        let (start, span) = (i128::from(start), i128::from(end) - i128::from(start));
+        let partitions = (self.partitions as i128).min(span);
+        let bound = |i: i128| (start + span * i / partitions) as Timestamp;
+        (0..partitions)
+            .rev()
+            .map(|i| {
+                RangeQuery::Bounds(RangeBounds {
+                    gte: Some(bound(i)),
+                    lt: Some(bound(i + 1)),
+                    ..Default::default()
+                })
+            })
+            .collect()
     }
 
-    /// Filter results to be before or equal to a given value
-    pub fn lte(value: T) -> RangeQuery<T> {
-        RangeQuery::Bounds(RangeBounds { lte: Some(value), ..Default::default() })
+    /// Paginate the partitions, using `list` to fetch their first page with the parameters
+    /// built by `params` for their range.
+    ///
+    /// The values of the oldest partition being paginated are yielded as they are fetched,
+    /// while the values of the partitions after it are buffered until it ends. Each of them
+    /// holds up to 100 buffered values, plus the rest of the page it is reading and the
+    /// page it is prefetching, after which it waits for its turn.
+    ///
+    /// Dropping the stream cancels the requests of the partitions.
+    pub fn stream<T, P, L, B>(
+        &self,
+        client: &Client,
+        list: L,
+        params: B,
+    ) -> impl futures_util::Stream<Item = Result<T, StripeError>> + Unpin
+    where
+        T: Paginate + DeserializeOwned + Send + Sync + 'static + Clone + std::fmt::Debug,
+        P: Paginable<O = T> + Clone + Serialize + Send + 'static + std::fmt::Debug,
+        L: Fn(&Client, &P) -> Response<List<T>>,
+        B: Fn(RangeQuery<Timestamp>) -> P,
+    {
+        use futures_util::{StreamExt, TryStreamExt};
+
+        let client = client.clone();
+        let partitions = self.ranges().into_iter().map(move |created| {
+            let params = params(created);
+            let page = list(&client, &params);
+            let client = client.clone();
+            let paginator = async move {
+                page.await.map(|page| page.paginate(params).prefetch_stream(&client))
+            };
+            futures_util::stream::once(paginator).try_flatten().boxed()
+        });
+
+        PartitionedStream {
+            partitions,
+            active: std::collections::VecDeque::new(),
+            concurrency: self.concurrency,
+        }
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
-#[serde(untagged)]
-pub enum IdOrCreate<'a, T> {
-    Id(&'a str),
-    Create(&'a T),
+/// The stream returned by [`PartitionedExport::stream`].
+///
+/// Up to `concurrency` partitions are polled at the same time, the first of which is the
+/// current one: its values are yielded directly, and the values of the others are buffered.
+#[cfg(all(feature = "async", feature = "stream"))]
+struct PartitionedStream<T, I> {
+    partitions: I,
+    active: std::collections::VecDeque<Partition<T>>,
+    concurrency: usize,
 }
 
-// NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
-//   This function is used to convert static Rust identifiers to snakecase
-// TODO: pub(crate) fn
+/// How many values are buffered for a partition which isn't the current one, which is the
+/// largest page stripe returns.
+#[cfg(all(feature = "async", feature = "stream"))]
+const PARTITION_BUFFER: usize = 100;
+
+#[cfg(all(feature = "async", feature = "stream"))]
+struct Partition<T> {
+    stream: futures_util::stream::BoxStream<'static, Result<T, StripeError>>,
+    buffer: std::collections::VecDeque<Result<T, StripeError>>,
+    done: bool,
+}
+
+// N.B. the fields are never pinned, the streams of the partitions are boxed
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<T, I> Unpin for PartitionedStream<T, I> {}
+
+#[cfg(all(feature = "async", feature = "stream"))]
+impl<T, I> futures_util::Stream for PartitionedStream<T, I>
+where
+    I: Iterator<Item = futures_util::stream::BoxStream<'static, Result<T, StripeError>>>,
+{
+    type Item = Result<T, StripeError>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        use futures_util::StreamExt;
+
+        let this = self.get_mut();
+        loop {
+            while this.active.len() < this.concurrency {
+                match this.partitions.next() {
+                    Some(stream) => this.active.push_back(Partition {
+                        stream,
+                        buffer: Default::default(),
+                        done: false,
+                    }),
+                    None => break,
+                }
+            }
+
+            // the partitions after the current one are buffered until it ends, and aren't polled
+            // while their buffer is full, until they become the current one
+            for partition in this.active.iter_mut().skip(1).filter(|partition| !partition.done) {
+                while partition.buffer.len() < PARTITION_BUFFER {
+                    let Poll::Ready(item) = partition.stream.poll_next_unpin(cx) else {
+                        break;
+                    };
+                    match item {
+                        Some(item) => partition.buffer.push_back(item),
+                        None => {
+                            partition.done = true;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            let Some(current) = this.active.front_mut() else {
+                return Poll::Ready(None);
+            };
+            if let Some(item) = current.buffer.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if !current.done {
+                match current.stream.poll_next_unpin(cx) {
+                    Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                    Poll::Ready(None) => current.done = true,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            // the current partition ended, loop to start the next one
+            this.active.pop_front();
+        }
+    }
+}
+
+/// A paginator walking a list backwards using `ending_before`.
+///
+/// The objects are yielded in the reverse order of the list, starting from the object
+/// next to the cursor. For lists ordered newest first, this is the order in which the
+/// objects were created.
+#[derive(Debug)]
+pub struct ReverseListPaginator<T, P> {
+    pub page: T,
+    pub params: P,
+}
+
+impl<
+        T: PaginableList + Send + DeserializeOwned + 'static,
+        P: Clone + Serialize + Send + 'static + std::fmt::Debug,
+    > ReverseListPaginator<T, P>
+where
+    P: ReversePaginable<O = T::O>,
+{
+    /// Repeatedly queries Stripe for the previous pages until the start of the list is
+    /// reached, using Stripe's default page size.
+    ///
+    /// Requires `feature = "blocking"`.
+    #[cfg(feature = "blocking")]
+    pub fn get_all(self, client: &Client) -> Response<Vec<T::O>> {
+        let mut data = Vec::with_capacity(self.page.get_total_count().unwrap_or(0) as usize);
+        let mut paginator = self;
+        loop {
+            let has_more = paginator.page.has_more();
+            let next_paginator = if has_more { Some(paginator.next(client)?) } else { None };
+            data.extend(paginator.page.get_data_mut().drain(..).rev());
+            match next_paginator {
+                Some(next_paginator) => paginator = next_paginator,
+                None => break,
+            }
+        }
+        Ok(data)
+    }
+
+    /// Get all values before the cursor, consuming self and lazily paginating backwards
+    /// until the start of the list is reached.
+    ///
+    /// ```no_run
+    /// # use stripe::{Charge, ChargeId, ListCharges, StripeError, Client};
+    /// # use futures_util::TryStreamExt;
+    /// # async fn run(checkpoint: ChargeId) -> Result<(), StripeError> {
+    /// # let client = Client::new("sk_test_123");
+    /// let params = ListCharges { ending_before: Some(checkpoint), ..Default::default() };
+    /// let list = Charge::list(&client, &params).await?.paginate_reverse(params);
+    ///
+    /// // the charges created after the checkpoint, oldest first
+    /// let mut stream = list.stream(&client);
+    /// while let Some(charge) = stream.try_next().await? {
+    ///     println!("synced {}", charge.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires `feature = ["async", "stream"]`.
+    #[cfg(all(feature = "async", feature = "stream"))]
+    pub fn stream(
+        self,
+        client: &Client,
+    ) -> impl futures_util::Stream<Item = Result<T::O, StripeError>> + Unpin {
+        // N.B. popping items off the end of the page yields them in reverse order
+        Box::pin(futures_util::stream::unfold(Some((self, client.clone())), Self::unfold_stream))
+    }
+
+    /// unfold a single item from the stream
+    #[cfg(all(feature = "async", feature = "stream"))]
+    async fn unfold_stream(
+        state: Option<(Self, Client)>,
+    ) -> Option<(Result<T::O, StripeError>, Option<(Self, Client)>)> {
+        let (mut paginator, client) = state?; // If none, we sent the last item in the last iteration
+
+        if paginator.page.get_data().len() > 1 {
+            return Some((Ok(paginator.page.get_data_mut().pop()?), Some((paginator, client))));
+        }
+
+        if !paginator.page.has_more() {
+            return Some((Ok(paginator.page.get_data_mut().pop()?), None));
+        }
+
+        match paginator.next(&client).await {
+            Ok(next_paginator) => {
+                let data = paginator.page.get_data_mut().pop()?;
+                Some((Ok(data), Some((next_paginator, client))))
+            }
+            Err(e) => Some((Err(e), None)),
+        }
+    }
+
+    /// Fetch the previous page of data from stripe.
+    pub fn next(&self, client: &Client) -> Response<Self> {
+        if let Some(first) = self.page.get_data().first() {
+            if self.page.get_url().starts_with("/v1/") {
+                let path = self.page.get_url().trim_start_matches("/v1/").to_string(); // the url we get back is prefixed
+
+                // clone the params and set the cursor
+                let params_next = {
+                    let mut p = self.params.clone();
+                    p.set_first(first.clone());
+                    p
+                };
+
+                let page = client.get_query(&path, &params_next);
+
+                ReverseListPaginator::create_paginator(page, params_next)
+            } else {
+                err(StripeError::UnsupportedVersion)
+            }
+        } else {
+            ok(ReverseListPaginator {
+                page: T::new(Vec::new(), self.page.get_url(), false, self.page.get_total_count()),
+                params: self.params.clone(),
+            })
+        }
+    }
+
+    /// Pin a new future which maps the result inside the page future into
+    /// a ReverseListPaginator
+    #[cfg(feature = "async")]
+    fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
+        use futures_util::FutureExt;
+        Box::pin(page.map(|page| page.map(|page| ReverseListPaginator { page, params })))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
+        page.map(|page| ReverseListPaginator { page, params })
+    }
+}
+
+pub type CurrencyMap<V> = HashMap<Currency, V>;
+pub type Metadata = HashMap<String, String>;
+pub type Timestamp = i64;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub struct RangeBounds<T> {
+    pub gt: Option<T>,
+    pub gte: Option<T>,
+    pub lt: Option<T>,
+    pub lte: Option<T>,
+}
+
+impl<T> Default for RangeBounds<T> {
+    fn default() -> Self {
+        RangeBounds { gt: None, gte: None, lt: None, lte: None }
+    }
+}
+
+/// A set of generic request parameters that can be used on
+/// list endpoints to filter their results by some timestamp.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum RangeQuery<T> {
+    Exact(T),
+    Bounds(RangeBounds<T>),
+}
+
+impl<T> RangeQuery<T> {
+    /// Filter results to exactly match a given value
+    pub fn eq(value: T) -> RangeQuery<T> {
+        RangeQuery::Exact(value)
+    }
+
+    /// Filter results to be after a given value
+    pub fn gt(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { gt: Some(value), ..Default::default() })
+    }
+
+    /// Filter results to be after or equal to a given value
+    pub fn gte(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { gte: Some(value), ..Default::default() })
+    }
+
+    /// Filter results to be before to a given value
+    pub fn lt(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { lt: Some(value), ..Default::default() })
+    }
+
+    /// Filter results to be before or equal to a given value
+    pub fn lte(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { lte: Some(value), ..Default::default() })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdOrCreate<'a, T> {
+    Id(&'a str),
+    Create(&'a T),
+}
+
+// NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
+//   This function is used to convert static Rust identifiers to snakecase
+// TODO: pub(crate) fn
 pub fn to_snakecase(camel: &str) -> String {
     let mut i = 0;
     let mut snake = String::new();
@@ -918,4 +1697,639 @@ mod tests {
         items.assert_hits_async(1).await;
         next_item.assert_hits_async(1).await;
     }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[tokio::test]
+    async fn search_stream() {
+        use futures_util::TryStreamExt;
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, CustomerSearchParams};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let next_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/search").query_param("page", "page_2");
+            then.status(200).body(
+                r#"{"object": "search_result", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "next_page": null, "url": "/v1/customers/search"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/search").query_param("query", "name:'test'");
+            then.status(200).body(
+                r#"{"object": "search_result", "data": [{
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316731,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "next_page": "page_2", "url": "/v1/customers/search"}"#,
+            );
+        });
+
+        let params = CustomerSearchParams {
+            query: "name:'test'".to_string(),
+            ..CustomerSearchParams::new()
+        };
+        let res = Customer::search(&client, params.clone()).await.unwrap().paginate(params);
+
+        let customers = res.stream(&client).try_collect::<Vec<_>>().await.unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_1", "cus_2", "cus_3"]);
+
+        first_page.assert_hits_async(1).await;
+        next_page.assert_hits_async(1).await;
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn search_get_all() {
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, CustomerSearchParams};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start();
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let next_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/search").query_param("page", "page_2");
+            then.status(200).body(
+                r#"{"object": "search_result", "data": [{
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "next_page": null, "url": "/v1/customers/search"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers/search");
+            then.status(200).body(
+                r#"{"object": "search_result", "data": [{
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316731,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "next_page": "page_2", "url": "/v1/customers/search"}"#,
+            );
+        });
+
+        let params = CustomerSearchParams {
+            query: "name:'test'".to_string(),
+            ..CustomerSearchParams::new()
+        };
+        let res = Customer::search(&client, params.clone()).unwrap().paginate(params);
+
+        let customers = res.get_all(&client).unwrap();
+
+        assert_eq!(customers.len(), 2);
+        first_page.assert_hits(1);
+        next_page.assert_hits(1);
+    }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[tokio::test]
+    async fn reverse_stream() {
+        use futures_util::TryStreamExt;
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, ListCustomers};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let previous_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("ending_before", "cus_3");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_4",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316734,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("ending_before", "cus_1");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let params =
+            ListCustomers { ending_before: Some("cus_1".parse().unwrap()), ..Default::default() };
+        let res = Customer::list(&client, &params).await.unwrap().paginate_reverse(params);
+
+        let customers = res.stream(&client).try_collect::<Vec<_>>().await.unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_2", "cus_3", "cus_4"]);
+
+        first_page.assert_hits_async(1).await;
+        previous_page.assert_hits_async(1).await;
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn reverse_get_all() {
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, ListCustomers};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start();
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let previous_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("ending_before", "cus_3");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_4",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316734,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("ending_before", "cus_1");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let params =
+            ListCustomers { ending_before: Some("cus_1".parse().unwrap()), ..Default::default() };
+        let res = Customer::list(&client, &params).unwrap().paginate_reverse(params);
+
+        let customers = res.get_all(&client).unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_2", "cus_3", "cus_4"]);
+
+        first_page.assert_hits(1);
+        previous_page.assert_hits(1);
+    }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[tokio::test]
+    async fn prefetch_stream() {
+        use futures_util::TryStreamExt;
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, ListCustomers};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let next_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("starting_after", "cus_2");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316731,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let params = ListCustomers::default();
+        let res = Customer::list(&client, &params).await.unwrap().paginate(params);
+
+        let mut stream = res.prefetch_stream(&client);
+        let first = stream.try_next().await.unwrap().unwrap();
+        assert_eq!(first.id.as_str(), "cus_1");
+        // the next page is fetched while the current one is consumed, without polling
+        let fetched = async {
+            while next_page.hits_async().await == 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(std::time::Duration::from_secs(5), fetched).await.unwrap();
+
+        let rest = stream.try_collect::<Vec<_>>().await.unwrap();
+        let ids: Vec<_> = rest.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_2", "cus_3"]);
+
+        first_page.assert_hits_async(1).await;
+        next_page.assert_hits_async(1).await;
+    }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[tokio::test]
+    async fn partitioned_export() {
+        use futures_util::TryStreamExt;
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use super::{PartitionedExport, RangeBounds, RangeQuery};
+        use crate::Client;
+        use crate::{Customer, ListCustomers};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let newer = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("created[gte]", "200").matches(
+                |req| {
+                    let params = req.query_params.as_deref().unwrap_or_default();
+                    !params.iter().any(|(key, _)| key == "starting_after")
+                },
+            );
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_4",
+                "object": "customer",
+                "balance": 0,
+                "created": 280,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        // the next page of the current partition is slow
+        let newer_next = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("starting_after", "cus_4");
+            then.status(200).delay(std::time::Duration::from_secs(3)).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 250,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let older = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("created[gte]", "100");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 150,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 100,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let created = RangeQuery::Bounds(RangeBounds {
+            gte: Some(100),
+            lte: Some(299),
+            ..Default::default()
+        });
+        let export = PartitionedExport::new(created).partitions(2).concurrency(2);
+
+        let ranges: Vec<_> = export
+            .ranges()
+            .into_iter()
+            .map(|range| match range {
+                RangeQuery::Bounds(RangeBounds { gte, lt, .. }) => (gte, lt),
+                RangeQuery::Exact(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(ranges, [(Some(200), Some(300)), (Some(100), Some(200))]);
+
+        let mut stream = export.stream(&client, Customer::list, |created| ListCustomers {
+            created: Some(created),
+            ..Default::default()
+        });
+
+        // the values of the current partition are yielded before it ends
+        let first = tokio::time::timeout(std::time::Duration::from_secs(2), stream.try_next())
+            .await
+            .expect("the first value is yielded before the next page is fetched")
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.id.as_str(), "cus_4");
+
+        let customers = stream.try_collect::<Vec<_>>().await.unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_3", "cus_2", "cus_1"]);
+
+        newer.assert_hits_async(1).await;
+        newer_next.assert_hits_async(1).await;
+        older.assert_hits_async(1).await;
+    }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[test]
+    fn partitioned_export_ranges() {
+        use super::{PartitionedExport, RangeBounds, RangeQuery};
+
+        let ranges = |bounds| {
+            PartitionedExport::new(RangeQuery::Bounds(bounds))
+                .partitions(2)
+                .ranges()
+                .into_iter()
+                .map(|range| match range {
+                    RangeQuery::Bounds(RangeBounds { gte, lt, .. }) => (gte.unwrap(), lt.unwrap()),
+                    RangeQuery::Exact(_) => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // an empty window is not widened
+        assert!(
+            ranges(RangeBounds { gte: Some(100), lt: Some(100), ..Default::default() }).is_empty()
+        );
+        assert!(
+            ranges(RangeBounds { gt: Some(99), lte: Some(99), ..Default::default() }).is_empty()
+        );
+
+        // a window too small to split is a single partition
+        let one = ranges(RangeBounds { gte: Some(100), lte: Some(100), ..Default::default() });
+        assert_eq!(one, [(100, 101)]);
+
+        // the bounds of wide windows don't overflow
+        let wide = ranges(RangeBounds { gte: Some(0), lt: Some(i64::MAX), ..Default::default() });
+        assert_eq!(wide, [(i64::MAX / 2, i64::MAX), (0, i64::MAX / 2)]);
+    }
+
+    #[cfg(all(feature = "async", feature = "stream"))]
+    #[test]
+    fn partitioned_stream_buffer_is_bounded() {
+        use futures_util::{stream, FutureExt, StreamExt};
+
+        use super::{PartitionedStream, PARTITION_BUFFER};
+
+        // the current partition never yields, while the next one never ends
+        let partitions = [stream::pending().boxed(), stream::repeat(1).map(Ok).boxed()];
+        let mut stream = PartitionedStream {
+            partitions: partitions.into_iter(),
+            active: Default::default(),
+            concurrency: 2,
+        };
+
+        assert!(stream.next().now_or_never().is_none());
+        assert_eq!(stream.active[1].buffer.len(), PARTITION_BUFFER);
+        assert!(stream.next().now_or_never().is_none());
+        assert_eq!(stream.active[1].buffer.len(), PARTITION_BUFFER);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn collect_all() {
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use super::PaginationLimits;
+        use crate::Client;
+        use crate::{Customer, ListCustomers, StripeError};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let next_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("starting_after", "cus_2");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316731,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let params = ListCustomers::default();
+        let list =
+            || async { Customer::list(&client, &params).await.unwrap().paginate(params.clone()) };
+
+        let limits = PaginationLimits { max_items: Some(1), ..Default::default() };
+        let customers = list().await.collect_all(&client, limits).await.unwrap();
+        assert_eq!(customers.len(), 1);
+
+        let limits = PaginationLimits { max_pages: Some(1), ..Default::default() };
+        let customers = list().await.collect_all(&client, limits).await.unwrap();
+        assert_eq!(customers.len(), 2);
+        next_page.assert_hits_async(0).await;
+
+        let customers = list().await.collect_all(&client, Default::default()).await.unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_1", "cus_2", "cus_3"]);
+        next_page.assert_hits_async(1).await;
+
+        let mut pages = Vec::new();
+        list()
+            .await
+            .try_for_each_page(&client, |customers| {
+                pages.push(customers.len());
+                async { Ok::<_, StripeError>(()) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(pages, [2, 1]);
+
+        first_page.assert_hits_async(4).await;
+        next_page.assert_hits_async(2).await;
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn iter() {
+        use httpmock::Method::GET;
+        use httpmock::MockServer;
+
+        use crate::Client;
+        use crate::{Customer, ListCustomers, StripeError};
+
+        // Start a lightweight mock server.
+        let server = MockServer::start();
+
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let next_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers").query_param("starting_after", "cus_2");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_3",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316731,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": false, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body(
+                r#"{"object": "list", "data": [{
+                "id": "cus_1",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316733,
+                "livemode": false,
+                "metadata": {}
+              }, {
+                "id": "cus_2",
+                "object": "customer",
+                "balance": 0,
+                "created": 1649316732,
+                "livemode": false,
+                "metadata": {}
+              }], "has_more": true, "url": "/v1/customers"}"#,
+            );
+        });
+
+        let params = ListCustomers::default();
+        let list = || Customer::list(&client, &params).unwrap().paginate(params.clone());
+
+        let mut iter = list().iter(&client);
+        assert_eq!(iter.next().unwrap().unwrap().id.as_str(), "cus_1");
+        // the next page is only fetched once the current one is consumed
+        next_page.assert_hits(0);
+        let customers = iter.collect::<Result<Vec<_>, _>>().unwrap();
+        let ids: Vec<_> = customers.iter().map(|customer| customer.id.as_str()).collect();
+        assert_eq!(ids, ["cus_2", "cus_3"]);
+        next_page.assert_hits(1);
+
+        let mut pages = Vec::new();
+        let res = list().try_for_each_page(&client, |customers| {
+            pages.push(customers.len());
+            Err(StripeError::Timeout)
+        });
+        assert!(matches!(res, Err(StripeError::Timeout)));
+        assert_eq!(pages, [2]);
+
+        first_page.assert_hits(2);
+        next_page.assert_hits(1);
+    }
 }
@@ -10,6 +10,7 @@ mod currency;
 #[allow(clippy::module_inception)]
 #[allow(clippy::new_without_default)]
 pub mod generated;
+mod search_query;
 mod types;
 
 #[path = "resources"]
@@ -37,8 +38,22 @@ mod payment {
     pub mod source_ext;
 }
 
+#[cfg(all(feature = "webhook-events", feature = "async", feature = "stream"))]
+mod event_poller;
+#[cfg(feature = "events")]
+mod webhook_changes;
+#[cfg(feature = "webhook-events")]
+mod webhook_connect;
 #[cfg(feature = "events")]
 mod webhook_events;
+#[cfg(feature = "webhook-events")]
+mod webhook_router;
+#[cfg(feature = "webhook-events")]
+mod webhook_store;
+#[cfg(feature = "webhook-events")]
+mod webhook_test_event;
+#[cfg(feature = "webhook-events")]
+mod webhook_verifier;
 
 #[path = "resources"]
 #[cfg(feature = "billing")]
@@ -81,6 +96,7 @@ mod fraud {
 #[cfg(feature = "issuing")]
 mod issuing {
     pub mod issuing_authorization_ext;
+    pub mod issuing_authorization_response;
     pub mod issuing_card_ext;
     pub mod issuing_dispute_ext;
     pub mod issuing_merchant_data;
@@ -97,11 +113,13 @@ mod orders {
 #[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoints {
     pub mod webhook_endpoint_ext;
+    pub mod webhook_endpoint_reconciler;
 }
 
 #[rustfmt::skip]
 pub use {
     currency::*,
+    search_query::*,
     types::*,
 
     self::core::{
@@ -179,11 +197,20 @@ pub use {
 #[rustfmt::skip]
 #[cfg(feature = "events")]
 pub use {
+    webhook_changes::*,
     webhook_events::*,
     webhook_events::NotificationEventData,
     generated::event::*,
 };
 
+#[cfg(all(feature = "webhook-events", feature = "async", feature = "stream"))]
+pub use event_poller::*;
+#[cfg(feature = "webhook-events")]
+pub use {
+    webhook_connect::*, webhook_router::*, webhook_store::*, webhook_test_event::*,
+    webhook_verifier::*,
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "checkout")]
 pub use {
@@ -303,6 +330,7 @@ pub use {
 pub use {
     issuing::{
         issuing_authorization_ext::*,
+        issuing_authorization_response::*,
         issuing_card_ext::*,
         issuing_dispute_ext::*,
         issuing_merchant_data::*,
@@ -345,6 +373,7 @@ pub use {
 #[cfg(feature = "webhook-endpoints")]
 pub use {
     webhook_endpoints::webhook_endpoint_ext::*,
+    webhook_endpoints::webhook_endpoint_reconciler::*,
     generated::webhook_endpoints::webhook_endpoint::*,
 };
 
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::AccountId;
 use crate::resources::Balance;
 
@@ -7,9 +7,20 @@ impl Balance {
     ///
     /// For more details see <https://stripe.com/docs/api/balance/balance_retrieve>.
     pub fn retrieve(client: &Client, account_id: Option<AccountId>) -> Response<Balance> {
-        match account_id {
-            Some(account_id) => client.clone().with_stripe_account(account_id).get("/balance"),
-            None => client.get("/balance"),
+        Self::retrieve_with_options(client, account_id, RequestOptions::default())
+    }
+
+    /// Like [`Balance::retrieve`], but with [`RequestOptions`] for this request only.
+    ///
+    /// The `account_id` takes precedence over the stripe account of the options.
+    pub fn retrieve_with_options(
+        client: &Client,
+        account_id: Option<AccountId>,
+        mut options: RequestOptions,
+    ) -> Response<Balance> {
+        if let Some(account_id) = account_id {
+            options.stripe_account = Some(account_id);
         }
+        client.get_with_options("/balance", options)
     }
 }
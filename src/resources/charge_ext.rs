@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{AccountId, BankAccountId, CardId, ChargeId, SourceId, TokenId};
-use crate::params::{Object, SearchList};
+use crate::params::{Object, PaginableSearch, SearchList};
 use crate::resources::{Charge, Rule};
 
 /// The set of PaymentSource parameters that can be used to create a charge.
@@ -42,14 +42,33 @@ impl Charge {
         charge_id: &ChargeId,
         params: CaptureCharge<'_>,
     ) -> Response<Charge> {
-        client.post_form(&format!("/charges/{}/capture", charge_id), params)
+        Self::capture_with_options(client, charge_id, params, RequestOptions::default())
+    }
+
+    /// Like [`Charge::capture`], but with [`RequestOptions`] for this request only.
+    pub fn capture_with_options(
+        client: &Client,
+        charge_id: &ChargeId,
+        params: CaptureCharge<'_>,
+        options: RequestOptions,
+    ) -> Response<Charge> {
+        client.post_form_with_options(&format!("/charges/{}/capture", charge_id), params, options)
     }
 
     /// Searches for a charge.
     ///
     /// For more details see <https://stripe.com/docs/api/charges/search>.
     pub fn search(client: &Client, params: ChargeSearchParams) -> Response<SearchList<Charge>> {
-        client.get_query("/charges/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Charge::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: ChargeSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Charge>> {
+        client.get_query_with_options("/charges/search", params, options)
     }
 }
 
@@ -69,7 +88,7 @@ pub struct ChargeSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -78,3 +97,9 @@ impl<'a> ChargeSearchParams<'a> {
         ChargeSearchParams { query: String::new(), limit: None, page: None, expand: &[] }
     }
 }
+
+impl<'a> PaginableSearch for ChargeSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::CheckoutSessionId;
 use crate::params::Expand;
 use crate::resources::CheckoutSession;
@@ -37,14 +37,37 @@ impl CheckoutSession {
         id: &CheckoutSessionId,
         expand: &[&str],
     ) -> Response<CheckoutSession> {
-        client.get_query(&format!("/checkout/sessions/{}", id), Expand { expand })
+        Self::retrieve_with_options(client, id, expand, RequestOptions::default())
+    }
+
+    /// Like [`CheckoutSession::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &CheckoutSessionId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<CheckoutSession> {
+        client.get_query_with_options(
+            &format!("/checkout/sessions/{}", id),
+            Expand { expand },
+            options,
+        )
     }
 
     /// Expires a checkout session.
     ///
     /// For more details see <https://stripe.com/docs/api/checkout/sessions/expire>.
     pub fn expire(client: &Client, id: &CheckoutSessionId) -> Response<CheckoutSession> {
-        client.post(&format!("/checkout/sessions/{}/expire", id))
+        Self::expire_with_options(client, id, RequestOptions::default())
+    }
+
+    /// Like [`CheckoutSession::expire`], but with [`RequestOptions`] for this request only.
+    pub fn expire_with_options(
+        client: &Client,
+        id: &CheckoutSessionId,
+        options: RequestOptions,
+    ) -> Response<CheckoutSession> {
+        client.post_with_options(&format!("/checkout/sessions/{}/expire", id), options)
     }
 
     /// Retrieves a Checkout Session's line items
@@ -55,6 +78,20 @@ impl CheckoutSession {
         id: &CheckoutSessionId,
         params: &RetrieveCheckoutSessionLineItems,
     ) -> Response<List<CheckoutSessionItem>> {
-        client.get_query(&format!("/checkout/sessions/{}/line_items", id), params)
+        Self::retrieve_line_items_with_options(client, id, params, RequestOptions::default())
+    }
+
+    /// Like [`CheckoutSession::retrieve_line_items`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_line_items_with_options(
+        client: &Client,
+        id: &CheckoutSessionId,
+        params: &RetrieveCheckoutSessionLineItems,
+        options: RequestOptions,
+    ) -> Response<List<CheckoutSessionItem>> {
+        client.get_query_with_options(
+            &format!("/checkout/sessions/{}/line_items", id),
+            params,
+            options,
+        )
     }
 }
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::CreditNoteId;
 use crate::resources::CreditNote;
 
@@ -7,6 +7,15 @@ impl CreditNote {
     ///
     /// You can only void a credit note if the associated invoice is open.
     pub fn void(client: &Client, id: &CreditNoteId) -> Response<CreditNote> {
-        client.post(&format!("/credit_notes/{}/void", id))
+        Self::void_with_options(client, id, RequestOptions::default())
+    }
+
+    /// Like [`CreditNote::void`], but with [`RequestOptions`] for this request only.
+    pub fn void_with_options(
+        client: &Client,
+        id: &CreditNoteId,
+        options: RequestOptions,
+    ) -> Response<CreditNote> {
+        client.post_with_options(&format!("/credit_notes/{}/void", id), options)
     }
 }
@@ -1,8 +1,8 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerBalanceTransactionId, CustomerId};
-use crate::params::{Expand, List, Metadata, Paginable};
+use crate::params::{Expand, List, Metadata, Paginable, ReversePaginable};
 use crate::resources::{Currency, Customer, CustomerBalanceTransaction};
 
 /// The parameters for `CustomerBalanceTransaction::list`.
@@ -44,6 +44,12 @@ impl Paginable for ListCustomerBalanceTransactions<'_> {
     }
 }
 
+impl ReversePaginable for ListCustomerBalanceTransactions<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id);
+    }
+}
+
 /// The parameters that can be used when creating or updating a [`CustomerBalanceTransaction`].
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateCustomerBalanceTransaction<'a> {
@@ -96,9 +102,28 @@ impl Customer {
         client: &Client,
         customer_id: &CustomerId,
         params: ListCustomerBalanceTransactions<'_>,
+    ) -> Response<List<CustomerBalanceTransaction>> {
+        Self::list_balance_transactions_with_options(
+            client,
+            customer_id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::list_balance_transactions`], but with [`RequestOptions`] for this request only.
+    pub fn list_balance_transactions_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: ListCustomerBalanceTransactions<'_>,
+        options: RequestOptions,
     ) -> Response<List<CustomerBalanceTransaction>> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.get_query(&format!("/customers/{}/balance_transactions", customer_id), &params)
+        client.get_query_with_options(
+            &format!("/customers/{}/balance_transactions", customer_id),
+            &params,
+            options,
+        )
     }
 
     /// Create a new customer balance transaction.
@@ -106,9 +131,28 @@ impl Customer {
         client: &Client,
         customer_id: &CustomerId,
         params: CreateCustomerBalanceTransaction<'_>,
+    ) -> Response<CustomerBalanceTransaction> {
+        Self::create_balance_transaction_with_options(
+            client,
+            customer_id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::create_balance_transaction`], but with [`RequestOptions`] for this request only.
+    pub fn create_balance_transaction_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: CreateCustomerBalanceTransaction<'_>,
+        options: RequestOptions,
     ) -> Response<CustomerBalanceTransaction> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.post_form(&format!("/customers/{}/balance_transactions", customer_id), &params)
+        client.post_form_with_options(
+            &format!("/customers/{}/balance_transactions", customer_id),
+            &params,
+            options,
+        )
     }
 
     /// Retrieve a customer balance transaction.
@@ -118,9 +162,27 @@ impl Customer {
         id: &CustomerBalanceTransactionId,
         expand: &[&str],
     ) -> Response<CustomerBalanceTransaction> {
-        client.get_query(
+        Self::retrieve_balance_transaction_with_options(
+            client,
+            customer_id,
+            id,
+            expand,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::retrieve_balance_transaction`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_balance_transaction_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        id: &CustomerBalanceTransactionId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<CustomerBalanceTransaction> {
+        client.get_query_with_options(
             &format!("/customers/{}/balance_transactions/{}", customer_id, id),
             Expand { expand },
+            options,
         )
     }
 
@@ -132,9 +194,29 @@ impl Customer {
         customer_id: &CustomerId,
         id: &CustomerBalanceTransactionId,
         params: UpdateCustomerBalanceTransaction<'_>,
+    ) -> Response<CustomerBalanceTransaction> {
+        Self::update_balance_transaction_with_options(
+            client,
+            customer_id,
+            id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::update_balance_transaction`], but with [`RequestOptions`] for this request only.
+    pub fn update_balance_transaction_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        id: &CustomerBalanceTransactionId,
+        params: UpdateCustomerBalanceTransaction<'_>,
+        options: RequestOptions,
     ) -> Response<CustomerBalanceTransaction> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client
-            .post_form(&format!("/customers/{}/balance_transactions/{}", customer_id, id), &params)
+        client.post_form_with_options(
+            &format!("/customers/{}/balance_transactions/{}", customer_id, id),
+            &params,
+            options,
+        )
     }
 }
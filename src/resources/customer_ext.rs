@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{BankAccountId, CardId, CustomerId, PaymentSourceId};
-use crate::params::{Deleted, Expand, List, SearchList};
+use crate::params::{Deleted, Expand, List, PaginableSearch, SearchList};
 use crate::resources::{
     BankAccount, Customer, PaymentMethod, PaymentSource, PaymentSourceParams, Source,
 };
@@ -71,7 +71,7 @@ pub struct CustomerSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -81,6 +81,12 @@ impl<'a> CustomerSearchParams<'a> {
     }
 }
 
+impl<'a> PaginableSearch for CustomerSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
+
 impl Customer {
     /// Attaches a source to a customer, does not change default Source for the Customer
     ///
@@ -89,13 +95,27 @@ impl Customer {
         client: &Client,
         customer_id: &CustomerId,
         source: PaymentSourceParams,
+    ) -> Response<PaymentSource> {
+        Self::attach_source_with_options(client, customer_id, source, RequestOptions::default())
+    }
+
+    /// Like [`Customer::attach_source`], but with [`RequestOptions`] for this request only.
+    pub fn attach_source_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        source: PaymentSourceParams,
+        options: RequestOptions,
     ) -> Response<PaymentSource> {
         #[derive(Serialize)]
         struct AttachSource {
             source: PaymentSourceParams,
         }
         let params = AttachSource { source };
-        client.post_form(&format!("/customers/{}/sources", customer_id), params)
+        client.post_form_with_options(
+            &format!("/customers/{}/sources", customer_id),
+            params,
+            options,
+        )
     }
 
     /// Detaches a source from a customer
@@ -106,7 +126,20 @@ impl Customer {
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
     ) -> Response<DetachedSource> {
-        client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
+        Self::detach_source_with_options(client, customer_id, source_id, RequestOptions::default())
+    }
+
+    /// Like [`Customer::detach_source`], but with [`RequestOptions`] for this request only.
+    pub fn detach_source_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        source_id: &PaymentSourceId,
+        options: RequestOptions,
+    ) -> Response<DetachedSource> {
+        client.delete_with_options(
+            &format!("/customers/{}/sources/{}", customer_id, source_id),
+            options,
+        )
     }
 
     /// Retrieves a Card, BankAccount, or Source for a Customer
@@ -115,7 +148,23 @@ impl Customer {
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
     ) -> Response<PaymentSource> {
-        client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
+        Self::retrieve_source_with_options(
+            client,
+            customer_id,
+            source_id,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::retrieve_source`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_source_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        source_id: &PaymentSourceId,
+        options: RequestOptions,
+    ) -> Response<PaymentSource> {
+        client
+            .get_with_options(&format!("/customers/{}/sources/{}", customer_id, source_id), options)
     }
 
     /// Verifies a Bank Account for a Customer.
@@ -127,9 +176,27 @@ impl Customer {
         bank_account_id: &BankAccountId,
         params: VerifyBankAccount<'_>,
     ) -> Response<BankAccount> {
-        client.post_form(
+        Self::verify_bank_account_with_options(
+            client,
+            customer_id,
+            bank_account_id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::verify_bank_account`], but with [`RequestOptions`] for this request only.
+    pub fn verify_bank_account_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        bank_account_id: &BankAccountId,
+        params: VerifyBankAccount<'_>,
+        options: RequestOptions,
+    ) -> Response<BankAccount> {
+        client.post_form_with_options(
             &format!("/customers/{}/sources/{}/verify", customer_id, bank_account_id),
             params,
+            options,
         )
     }
 
@@ -140,16 +207,44 @@ impl Customer {
         client: &Client,
         customer_id: &CustomerId,
         params: CustomerPaymentMethodRetrieval<'_>,
+    ) -> Response<List<PaymentMethod>> {
+        Self::retrieve_payment_methods_with_options(
+            client,
+            customer_id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`Customer::retrieve_payment_methods`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_payment_methods_with_options(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: CustomerPaymentMethodRetrieval<'_>,
+        options: RequestOptions,
     ) -> Response<List<PaymentMethod>> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.get_query(&format!("/customers/{}/payment_methods", customer_id), &params)
+        client.get_query_with_options(
+            &format!("/customers/{}/payment_methods", customer_id),
+            &params,
+            options,
+        )
     }
 
     /// Searches for a customer.
     ///
     /// For more details see <https://stripe.com/docs/api/customers/search>.
     pub fn search(client: &Client, params: CustomerSearchParams) -> Response<SearchList<Customer>> {
-        client.get_query("/customers/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Customer::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: CustomerSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Customer>> {
+        client.get_query_with_options("/customers/search", params, options)
     }
 }
 
@@ -0,0 +1,458 @@
+use std::future::Future;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::stream::BoxStream;
+use futures_util::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{config::sleep, Client};
+use crate::error::{EventPollerError, StripeError};
+use crate::ids::EventId;
+use crate::params::{List, RangeBounds, RangeQuery, Timestamp};
+use crate::resources::{Event, EventType, ListEvents};
+
+/// How long stripe keeps events for.
+const RETENTION: Timestamp = 30 * 24 * 60 * 60;
+
+/// The last event processed by an [`EventPoller`].
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EventCursor {
+    /// The id of the event.
+    pub id: EventId,
+    /// The time at which the event was created.
+    pub created: Timestamp,
+}
+
+impl From<&Event> for EventCursor {
+    fn from(event: &Event) -> Self {
+        Self { id: event.id.clone(), created: event.created }
+    }
+}
+
+/// Persists the position of an [`EventPoller`], so that it resumes where it stopped.
+///
+/// The methods are async, so that checkpoints stored in a database or a file don't block
+/// the executor polling the events.
+pub trait EventCheckpoint: Send + Sync {
+    /// Load the last event which was processed, if any.
+    fn load(&self) -> impl Future<Output = io::Result<Option<EventCursor>>> + Send;
+
+    /// Save the last event which was processed.
+    fn save(&self, cursor: &EventCursor) -> impl Future<Output = io::Result<()>> + Send;
+}
+
+impl<C: EventCheckpoint + ?Sized> EventCheckpoint for Arc<C> {
+    fn load(&self) -> impl Future<Output = io::Result<Option<EventCursor>>> + Send {
+        (**self).load()
+    }
+
+    fn save(&self, cursor: &EventCursor) -> impl Future<Output = io::Result<()>> + Send {
+        (**self).save(cursor)
+    }
+}
+
+/// Keeps the position in memory, for pollers which don't need to resume after a restart.
+impl EventCheckpoint for Mutex<Option<EventCursor>> {
+    async fn load(&self) -> io::Result<Option<EventCursor>> {
+        Ok(self.lock().unwrap_or_else(|err| err.into_inner()).clone())
+    }
+
+    async fn save(&self, cursor: &EventCursor) -> io::Result<()> {
+        *self.lock().unwrap_or_else(|err| err.into_inner()) = Some(cursor.clone());
+        Ok(())
+    }
+}
+
+/// Polls the list of events, as an alternative to receiving them using webhooks.
+///
+/// Events are yielded oldest first, starting after the event saved in the checkpoint, and
+/// are fetched one page at a time. The checkpoint is saved when the next event is requested
+/// from the stream, once the previous one has been processed, so events are delivered at
+/// least once.
+///
+/// ```no_run
+/// use std::sync::Mutex;
+/// use std::time::Duration;
+///
+/// use futures_util::TryStreamExt;
+/// use stripe::{Client, Event, EventPoller, EventRouter, EventType};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("sk_test_123");
+/// let router = EventRouter::<std::io::Error>::new()
+///     .fallback(|event: Event| async move {
+///         println!("received {}", event.id);
+///         Ok(())
+///     });
+///
+/// let mut events = EventPoller::new(&client, Mutex::new(None))
+///     .types([EventType::CustomerCreated, EventType::CustomerUpdated])
+///     .interval(Duration::from_secs(30))
+///     .stream();
+///
+/// while let Some(event) = events.try_next().await? {
+///     router.dispatch(event).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct EventPoller<C> {
+    client: Client,
+    checkpoint: C,
+    types: Vec<String>,
+    start: Timestamp,
+    interval: Duration,
+}
+
+impl<C: EventCheckpoint + 'static> EventPoller<C> {
+    /// Create a poller for all types of events which, without a saved position, starts
+    /// with the events created from now on.
+    pub fn new(client: &Client, checkpoint: C) -> Self {
+        Self {
+            client: client.clone(),
+            checkpoint,
+            types: Vec::new(),
+            start: now(),
+            interval: Duration::from_secs(10),
+        }
+    }
+
+    /// Only poll events of the given types.
+    pub fn types(mut self, types: impl IntoIterator<Item = EventType>) -> Self {
+        self.types = types
+            .into_iter()
+            .filter_map(|type_| match serde_json::to_value(type_) {
+                Ok(serde_json::Value::String(type_)) => Some(type_),
+                _ => None,
+            })
+            .collect();
+        self
+    }
+
+    /// Set the time from which events are polled when no position was saved. Defaults to
+    /// the time the poller was created.
+    ///
+    /// As events are listed newest first, the oldest page of events since that time is found
+    /// by halving the window of the first request until its events fit in a single page,
+    /// which takes a few requests at most.
+    pub fn start(mut self, created: Timestamp) -> Self {
+        self.start = created;
+        self
+    }
+
+    /// Set how long to wait before polling again after all events were yielded, or after
+    /// an error. Defaults to 10 seconds.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Poll for events forever, yielding them oldest first.
+    ///
+    /// Errors don't end the stream: polling is retried after the interval. If the saved
+    /// position is older than the 30 days for which stripe keeps events, an
+    /// [`EventPollerError::Expired`] error is yielded once, and polling continues with
+    /// the oldest events available.
+    pub fn stream(self) -> impl Stream<Item = Result<Event, EventPollerError>> + Unpin {
+        let state = PollState {
+            poller: self,
+            events: None,
+            uncommitted: None,
+            polled: false,
+            expired: false,
+        };
+        Box::pin(futures_util::stream::unfold(state, |mut state| async move {
+            let item = state.next().await;
+            Some((item, state))
+        }))
+    }
+
+    /// Stream the events created after the cursor, or since the given time without a
+    /// cursor, oldest first.
+    async fn fetch(
+        &self,
+        since: Timestamp,
+        cursor: Option<&EventCursor>,
+    ) -> Result<EventStream, StripeError> {
+        let params = ListEvents {
+            limit: Some(100),
+            types: (!self.types.is_empty()).then(|| self.types.clone()),
+            ..ListEvents::new()
+        };
+
+        if let Some(cursor) = cursor {
+            let params = ListEvents { ending_before: Some(cursor.id.clone()), ..params };
+            let page = Event::list(&self.client, &params).await?;
+            return Ok(page.paginate_reverse(params).stream(&self.client).boxed());
+        }
+
+        let params = ListEvents { created: Some(RangeQuery::gte(since)), ..params };
+        let page = self.oldest_page(&params, since).await?;
+        Ok(page.paginate_reverse(params).stream(&self.client).boxed())
+    }
+
+    /// Find the page of the oldest events created since the given time.
+    ///
+    /// Events are listed newest first, so rather than walking every page, the window of the
+    /// request is halved while it has more than a page of events, and grown back while it
+    /// has none.
+    async fn oldest_page(
+        &self,
+        params: &ListEvents<'static>,
+        since: Timestamp,
+    ) -> Result<List<Event>, StripeError> {
+        let list = |end: Option<Timestamp>| {
+            let created = RangeBounds { gte: Some(since), lt: end, ..Default::default() };
+            let params =
+                ListEvents { created: Some(RangeQuery::Bounds(created)), ..params.clone() };
+            Event::list(&self.client, &params)
+        };
+
+        // windows ending at `empty` have no events, and windows ending at `full` more than a page
+        let (mut empty, mut full) = (since, now() + 1);
+        let mut end = None;
+        let mut page = list(end).await?;
+        loop {
+            if page.has_more {
+                full = end.unwrap_or(full);
+            } else if page.data.is_empty() && end.is_some() {
+                empty = end.unwrap_or(empty);
+            } else {
+                break;
+            }
+
+            if full - empty <= 1 {
+                // more than a page of events were created in the same second, walk them
+                end = Some(full);
+                page = list(end).await?;
+                while page.has_more {
+                    page = page.paginate(params.clone()).next(&self.client).await?.page;
+                }
+                break;
+            }
+            end = Some(empty + (full - empty) / 2);
+            page = list(end).await?;
+        }
+
+        // the events after the window are fetched by the reverse paginator
+        page.has_more = end.is_some();
+        Ok(page)
+    }
+}
+
+/// The events of a poll, oldest first.
+type EventStream = BoxStream<'static, Result<Event, StripeError>>;
+
+struct PollState<C> {
+    poller: EventPoller<C>,
+    events: Option<EventStream>,
+    uncommitted: Option<EventCursor>,
+    polled: bool,
+    expired: bool,
+}
+
+impl<C: EventCheckpoint + 'static> PollState<C> {
+    async fn next(&mut self) -> Result<Event, EventPollerError> {
+        loop {
+            // the previous event was processed, since the next one was requested
+            if let Some(cursor) = self.uncommitted.take() {
+                if let Err(err) = self.poller.checkpoint.save(&cursor).await {
+                    self.uncommitted = Some(cursor);
+                    return Err(err.into());
+                }
+            }
+
+            if let Some(events) = &mut self.events {
+                match events.next().await {
+                    Some(Ok(event)) => {
+                        self.uncommitted = Some(EventCursor::from(&event));
+                        return Ok(event);
+                    }
+                    // the events are polled again from the checkpoint
+                    Some(Err(err)) => {
+                        self.events = None;
+                        return Err(err.into());
+                    }
+                    None => self.events = None,
+                }
+            }
+
+            if self.polled {
+                sleep(self.poller.interval).await;
+            }
+            self.polled = true;
+
+            let cursor = self.poller.checkpoint.load().await?;
+            let oldest = now() - RETENTION;
+            let since = cursor.as_ref().map_or(self.poller.start, |cursor| cursor.created);
+            if since < oldest && cursor.is_some() && !self.expired {
+                self.expired = true;
+                self.polled = false;
+                return Err(EventPollerError::Expired(since));
+            }
+
+            // N.B. stripe doesn't keep the events of an expired cursor
+            let cursor = cursor.filter(|_| since >= oldest);
+            self.events = Some(self.poller.fetch(since.max(oldest), cursor.as_ref()).await?);
+        }
+    }
+}
+
+fn now() -> Timestamp {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_secs() as Timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures_util::{StreamExt, TryStreamExt};
+    use httpmock::prelude::*;
+
+    use super::{now, EventCheckpoint, EventCursor, EventPoller};
+    use crate::{Client, Customer, EventObject, EventPollerError, EventType, TestEvent};
+
+    fn event(id: &str, created: i64) -> serde_json::Value {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let event = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .id(id.parse().unwrap())
+            .created(created)
+            .into_event();
+        serde_json::to_value(event).unwrap()
+    }
+
+    fn list(data: Vec<serde_json::Value>, has_more: bool) -> serde_json::Value {
+        serde_json::json!({ "object": "list", "url": "/v1/events", "has_more": has_more, "data": data })
+    }
+
+    #[tokio::test]
+    async fn poll_events() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+        let created = now() - 60;
+
+        // events are listed newest first
+        let first_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/events")
+                .query_param("ending_before", "evt_1")
+                .query_param("types[0]", "customer.created");
+            then.status(200).json_body(list(
+                vec![event("evt_3", created + 2), event("evt_2", created + 1)],
+                true,
+            ));
+        });
+        let next_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/events")
+                .query_param("ending_before", "evt_3")
+                .query_param("types[0]", "customer.created");
+            then.status(200).json_body(list(vec![event("evt_4", created + 3)], false));
+        });
+
+        let cursor = |id: &str, created| EventCursor { id: id.parse().unwrap(), created };
+        let checkpoint = Arc::new(Mutex::new(Some(cursor("evt_1", created))));
+        let mut events = EventPoller::new(&client, checkpoint.clone())
+            .types([EventType::CustomerCreated])
+            .interval(Duration::from_secs(1))
+            .stream();
+
+        let first = events.try_next().await.unwrap().unwrap();
+        assert_eq!(first.id.as_str(), "evt_2");
+        first_page.assert_hits_async(1).await;
+        next_page.assert_hits_async(0).await;
+
+        let second = events.try_next().await.unwrap().unwrap();
+        let third = events.try_next().await.unwrap().unwrap();
+        assert_eq!(second.id.as_str(), "evt_3");
+        assert_eq!(third.id.as_str(), "evt_4");
+        next_page.assert_hits_async(1).await;
+        assert_eq!(checkpoint.load().await.unwrap(), Some(cursor("evt_3", created + 2)));
+
+        // the last event is saved once the next one is requested
+        let next = tokio::time::timeout(Duration::from_millis(100), events.try_next()).await;
+        assert!(next.is_err());
+        assert_eq!(checkpoint.load().await.unwrap(), Some(cursor("evt_4", created + 3)));
+    }
+
+    #[tokio::test]
+    async fn poll_events_since() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+        let created = now() - 60;
+
+        // without a checkpoint, the window is narrowed to find the oldest events
+        let newest = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/events")
+                .query_param("created[gte]", created.to_string())
+                .matches(|req| {
+                    let params = req.query_params.as_deref().unwrap_or_default();
+                    !params.iter().any(|(key, _)| key == "created[lt]" || key == "ending_before")
+                });
+            then.status(200).json_body(list(
+                vec![event("evt_4", created + 3), event("evt_3", created + 2)],
+                true,
+            ));
+        });
+        let oldest = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/events")
+                .query_param("created[gte]", created.to_string())
+                .query_param_exists("created[lt]");
+            then.status(200)
+                .json_body(list(vec![event("evt_2", created + 1), event("evt_1", created)], false));
+        });
+        let newer = server.mock(|when, then| {
+            when.method(GET).path("/v1/events").query_param("ending_before", "evt_2");
+            then.status(200).json_body(list(
+                vec![event("evt_4", created + 3), event("evt_3", created + 2)],
+                false,
+            ));
+        });
+
+        let events = EventPoller::new(&client, Mutex::new(None)).start(created).stream();
+        let events: Vec<_> = events.take(4).try_collect().await.unwrap();
+        let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["evt_1", "evt_2", "evt_3", "evt_4"]);
+
+        newest.assert_hits_async(1).await;
+        oldest.assert_hits_async(1).await;
+        newer.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn expired_checkpoint() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+        let created = now() - 31 * 24 * 60 * 60;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/v1/events");
+            then.status(200).json_body(list(vec![event("evt_2", now() - 60)], false));
+        });
+
+        let checkpoint = Mutex::new(Some(EventCursor { id: "evt_1".parse().unwrap(), created }));
+        let mut events = EventPoller::new(&client, checkpoint).stream();
+
+        let res = events.try_next().await;
+        assert!(matches!(res, Err(EventPollerError::Expired(t)) if t == created));
+
+        // polling continues with the oldest events available
+        let event = events.try_next().await.unwrap().unwrap();
+        assert_eq!(event.id.as_str(), "evt_2");
+    }
+
+    #[tokio::test]
+    async fn memory_checkpoint() {
+        let checkpoint = Mutex::new(None);
+        assert_eq!(checkpoint.load().await.unwrap(), None);
+
+        let cursor = EventCursor { id: "evt_123".parse().unwrap(), created: 10 };
+        checkpoint.save(&cursor).await.unwrap();
+        assert_eq!(checkpoint.load().await.unwrap(), Some(cursor));
+    }
+}
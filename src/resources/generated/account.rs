@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::AccountId;
 use crate::params::{
-    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable,
+    Timestamp,
 };
 use crate::resources::{
     Address, Currency, DelayDays, ExternalAccount, File, Person, PersonVerificationParams, TaxId,
@@ -125,6 +126,15 @@ impl Account {
         client.get_query("/accounts", params)
     }
 
+    /// Like [`Account::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListAccounts<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Account>> {
+        client.get_query_with_options("/accounts", params, options)
+    }
+
     /// With [Connect](https://stripe.com/docs/connect), you can create Stripe accounts for your users.
     /// To do this, you’ll first need to [register your platform](https://dashboard.stripe.com/account/applications/settings).
     ///
@@ -137,11 +147,31 @@ impl Account {
         client.post_form("/accounts", &params)
     }
 
+    /// Like [`Account::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateAccount<'_>,
+        options: RequestOptions,
+    ) -> Response<Account> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/accounts", &params, options)
+    }
+
     /// Retrieves the details of an account.
     pub fn retrieve(client: &Client, id: &AccountId, expand: &[&str]) -> Response<Account> {
         client.get_query(&format!("/accounts/{}", id), Expand { expand })
     }
 
+    /// Like [`Account::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &AccountId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Account> {
+        client.get_query_with_options(&format!("/accounts/{}", id), Expand { expand }, options)
+    }
+
     /// Updates a [connected account](https://stripe.com/docs/connect/accounts) by setting the values of the parameters passed.
     ///
     /// Any parameters not provided are left unchanged.  For Custom accounts, you can update any information on the account.
@@ -153,6 +183,17 @@ impl Account {
         client.post_form(&format!("/accounts/{}", id), &params)
     }
 
+    /// Like [`Account::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &AccountId,
+        params: UpdateAccount<'_>,
+        options: RequestOptions,
+    ) -> Response<Account> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/accounts/{}", id), &params, options)
+    }
+
     /// With [Connect](https://stripe.com/docs/connect), you can delete accounts you manage.
     ///
     /// Accounts created using test-mode keys can be deleted at any time.
@@ -162,6 +203,15 @@ impl Account {
     pub fn delete(client: &Client, id: &AccountId) -> Response<Deleted<AccountId>> {
         client.delete(&format!("/accounts/{}", id))
     }
+
+    /// Like [`Account::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &AccountId,
+        options: RequestOptions,
+    ) -> Response<Deleted<AccountId>> {
+        client.delete_with_options(&format!("/accounts/{}", id), options)
+    }
 }
 
 impl Object for Account {
@@ -1059,6 +1109,11 @@ impl Paginable for ListAccounts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListAccounts<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Account::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateAccount<'a> {
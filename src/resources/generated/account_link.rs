@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::AccountId;
 use crate::params::{Expand, Object, Timestamp};
 
@@ -31,6 +31,16 @@ impl AccountLink {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/account_links", &params)
     }
+
+    /// Like [`AccountLink::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateAccountLink<'_>,
+        options: RequestOptions,
+    ) -> Response<AccountLink> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/account_links", &params, options)
+    }
 }
 
 impl Object for AccountLink {
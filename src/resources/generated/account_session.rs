@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{AccountId};
 use crate::params::{Expand, Object, Timestamp};
 use serde::{Deserialize, Serialize};
@@ -39,6 +39,12 @@ impl AccountSession {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/account_sessions", &params)
     }
+
+    /// Like [`AccountSession::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(client: &Client, params: CreateAccountSession<'_>, options: RequestOptions) -> Response<AccountSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/account_sessions", &params, options)
+    }
 }
 
 impl Object for AccountSession {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ApplicationFeeId, ChargeId};
-use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, Application, ApplicationFeeRefund, BalanceTransaction, Charge, Currency,
 };
@@ -73,6 +75,15 @@ impl ApplicationFee {
         client.get_query("/application_fees", params)
     }
 
+    /// Like [`ApplicationFee::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListApplicationFees<'_>,
+        options: RequestOptions,
+    ) -> Response<List<ApplicationFee>> {
+        client.get_query_with_options("/application_fees", params, options)
+    }
+
     /// Retrieves the details of an application fee that your account has collected.
     ///
     /// The same information is returned when refunding the application fee.
@@ -83,6 +94,20 @@ impl ApplicationFee {
     ) -> Response<ApplicationFee> {
         client.get_query(&format!("/application_fees/{}", id), Expand { expand })
     }
+
+    /// Like [`ApplicationFee::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &ApplicationFeeId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<ApplicationFee> {
+        client.get_query_with_options(
+            &format!("/application_fees/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
 }
 
 impl Object for ApplicationFee {
@@ -148,3 +173,8 @@ impl Paginable for ListApplicationFees<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListApplicationFees<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{BalanceTransactionId, PayoutId, SourceId};
-use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     BalanceTransactionSourceUnion, BalanceTransactionStatus, Currency, FeeType,
 };
@@ -91,6 +93,15 @@ impl BalanceTransaction {
         client.get_query("/balance_transactions", params)
     }
 
+    /// Like [`BalanceTransaction::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListBalanceTransactions<'_>,
+        options: RequestOptions,
+    ) -> Response<List<BalanceTransaction>> {
+        client.get_query_with_options("/balance_transactions", params, options)
+    }
+
     /// Retrieves the balance transaction with the given ID.
     ///
     /// Note that this endpoint previously used the path `/v1/balance/history/:id`.
@@ -101,6 +112,20 @@ impl BalanceTransaction {
     ) -> Response<BalanceTransaction> {
         client.get_query(&format!("/balance_transactions/{}", id), Expand { expand })
     }
+
+    /// Like [`BalanceTransaction::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &BalanceTransactionId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<BalanceTransaction> {
+        client.get_query_with_options(
+            &format!("/balance_transactions/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
 }
 
 impl Object for BalanceTransaction {
@@ -210,6 +235,11 @@ impl Paginable for ListBalanceTransactions<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListBalanceTransactions<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `BalanceTransaction`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{BillingPortalSessionId, CustomerId};
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::BillingPortalConfiguration;
@@ -62,6 +62,16 @@ impl BillingPortalSession {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/billing_portal/sessions", &params)
     }
+
+    /// Like [`BillingPortalSession::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateBillingPortalSession<'_>,
+        options: RequestOptions,
+    ) -> Response<BillingPortalSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/billing_portal/sessions", &params, options)
+    }
 }
 
 impl Object for BillingPortalSession {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ChargeId, CustomerId, PaymentIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, Address, Application, ApplicationFee, BalanceTransaction, BillingDetails,
     ChargeSourceParams, Currency, Customer, Invoice, Mandate, PaymentIntent, PaymentMethod,
@@ -216,6 +218,15 @@ impl Charge {
         client.get_query("/charges", params)
     }
 
+    /// Like [`Charge::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListCharges<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Charge>> {
+        client.get_query_with_options("/charges", params, options)
+    }
+
     /// This method is no longer recommended—use the [Payment Intents API](https://stripe.com/docs/api/payment_intents)
     /// to initiate a new payment instead.
     ///
@@ -225,6 +236,16 @@ impl Charge {
         client.post_form("/charges", &params)
     }
 
+    /// Like [`Charge::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateCharge<'_>,
+        options: RequestOptions,
+    ) -> Response<Charge> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/charges", &params, options)
+    }
+
     /// Retrieves the details of a charge that has previously been created.
     ///
     /// Supply the unique charge ID that was returned from your previous request, and Stripe will return the corresponding charge information.
@@ -233,6 +254,16 @@ impl Charge {
         client.get_query(&format!("/charges/{}", id), Expand { expand })
     }
 
+    /// Like [`Charge::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &ChargeId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Charge> {
+        client.get_query_with_options(&format!("/charges/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified charge by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
@@ -240,6 +271,17 @@ impl Charge {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/charges/{}", id), &params)
     }
+
+    /// Like [`Charge::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &ChargeId,
+        params: UpdateCharge<'_>,
+        options: RequestOptions,
+    ) -> Response<Charge> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/charges/{}", id), &params, options)
+    }
 }
 
 impl Object for Charge {
@@ -1770,6 +1812,11 @@ impl Paginable for ListCharges<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListCharges<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Charge::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCharge<'a> {
@@ -4,13 +4,14 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{
     CheckoutSessionId, CustomerId, PaymentIntentId, PaymentLinkId, PaymentMethodConfigurationId,
     SubscriptionId,
 };
 use crate::params::{
-    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{
     Address, CheckoutSessionItem, ConnectAccountReference, Currency, Customer, Discount, Invoice,
@@ -238,11 +239,30 @@ impl CheckoutSession {
         client.get_query("/checkout/sessions", params)
     }
 
+    /// Like [`CheckoutSession::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListCheckoutSessions<'_>,
+        options: RequestOptions,
+    ) -> Response<List<CheckoutSession>> {
+        client.get_query_with_options("/checkout/sessions", params, options)
+    }
+
     /// Creates a Session object.
     pub fn create(client: &Client, params: CreateCheckoutSession<'_>) -> Response<CheckoutSession> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/checkout/sessions", &params)
     }
+
+    /// Like [`CheckoutSession::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateCheckoutSession<'_>,
+        options: RequestOptions,
+    ) -> Response<CheckoutSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/checkout/sessions", &params, options)
+    }
 }
 
 impl Object for CheckoutSession {
@@ -1511,6 +1531,11 @@ impl Paginable for ListCheckoutSessions<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListCheckoutSessions<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateCheckoutSessionAfterExpiration {
     /// Configure a Checkout Session that can be used to recover an expired session.
@@ -2,9 +2,9 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CountrySpecId};
-use crate::params::{Expand, List, Object, Paginable};
+use crate::params::{Expand, List, Object, Paginable, ReversePaginable};
 use crate::resources::{Currency};
 use serde::{Deserialize, Serialize};
 
@@ -49,10 +49,21 @@ pub fn list(client: &Client, params: &ListCountrySpecs<'_>) -> Response<List<Cou
 }
 
 
+/// Like [`CountrySpec::list`], but with [`RequestOptions`] for this request only.
+pub fn list_with_options(client: &Client, params: &ListCountrySpecs<'_>, options: RequestOptions) -> Response<List<CountrySpec>> {
+    client.get_query_with_options("/country_specs", params, options)
+}
+
+
     /// Returns a Country Spec for a given Country code.
     pub fn retrieve(client: &Client, id: &CountrySpecId, expand: &[&str]) -> Response<CountrySpec> {
         client.get_query(&format!("/country_specs/{}", id), Expand { expand })
     }
+
+    /// Like [`CountrySpec::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(client: &Client, id: &CountrySpecId, expand: &[&str], options: RequestOptions) -> Response<CountrySpec> {
+        client.get_query_with_options(&format!("/country_specs/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for CountrySpec {
@@ -126,4 +137,10 @@ impl Paginable for ListCountrySpecs<'_> {
     type O = CountrySpec;
     fn set_last(&mut self, item: Self::O) {
                 self.starting_after = Some(item.id());
-            }}
\ No newline at end of file
+            }
+}
+impl ReversePaginable for ListCountrySpecs<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
\ No newline at end of file
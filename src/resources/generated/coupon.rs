@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::CouponId;
 use crate::params::{
-    CurrencyMap, Deleted, Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    CurrencyMap, Deleted, Expand, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable,
+    Timestamp,
 };
 use crate::resources::Currency;
 
@@ -101,6 +102,15 @@ impl Coupon {
         client.get_query("/coupons", params)
     }
 
+    /// Like [`Coupon::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListCoupons<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Coupon>> {
+        client.get_query_with_options("/coupons", params, options)
+    }
+
     /// You can create coupons easily via the [coupon management](https://dashboard.stripe.com/coupons) page of the Stripe dashboard.
     ///
     /// Coupon creation is also accessible via the API if you need to create coupons on the fly.  A coupon has either a `percent_off` or an `amount_off` and `currency`.
@@ -111,11 +121,31 @@ impl Coupon {
         client.post_form("/coupons", &params)
     }
 
+    /// Like [`Coupon::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateCoupon<'_>,
+        options: RequestOptions,
+    ) -> Response<Coupon> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/coupons", &params, options)
+    }
+
     /// Retrieves the coupon with the given ID.
     pub fn retrieve(client: &Client, id: &CouponId, expand: &[&str]) -> Response<Coupon> {
         client.get_query(&format!("/coupons/{}", id), Expand { expand })
     }
 
+    /// Like [`Coupon::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &CouponId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Coupon> {
+        client.get_query_with_options(&format!("/coupons/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the metadata of a coupon.
     ///
     /// Other coupon details (currency, duration, amount_off) are, by design, not editable.
@@ -124,6 +154,17 @@ impl Coupon {
         client.post_form(&format!("/coupons/{}", id), &params)
     }
 
+    /// Like [`Coupon::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &CouponId,
+        params: UpdateCoupon<'_>,
+        options: RequestOptions,
+    ) -> Response<Coupon> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/coupons/{}", id), &params, options)
+    }
+
     /// You can delete coupons via the [coupon management](https://dashboard.stripe.com/coupons) page of the Stripe dashboard.
     ///
     /// However, deleting a coupon does not affect any customers who have already applied the coupon; it means that new customers can’t redeem the coupon.
@@ -131,6 +172,15 @@ impl Coupon {
     pub fn delete(client: &Client, id: &CouponId) -> Response<Deleted<CouponId>> {
         client.delete(&format!("/coupons/{}", id))
     }
+
+    /// Like [`Coupon::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &CouponId,
+        options: RequestOptions,
+    ) -> Response<Deleted<CouponId>> {
+        client.delete_with_options(&format!("/coupons/{}", id), options)
+    }
 }
 
 impl Object for Coupon {
@@ -298,6 +348,11 @@ impl Paginable for ListCoupons<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListCoupons<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Coupon::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCoupon<'a> {
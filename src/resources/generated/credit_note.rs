@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CreditNoteId, CustomerId, InvoiceId, RefundId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     CreditNoteLineItem, Currency, Customer, CustomerBalanceTransaction, Discount, Invoice,
     InvoicesShippingCost, Refund, TaxRate,
@@ -126,6 +128,15 @@ impl CreditNote {
         client.get_query("/credit_notes", params)
     }
 
+    /// Like [`CreditNote::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListCreditNotes<'_>,
+        options: RequestOptions,
+    ) -> Response<List<CreditNote>> {
+        client.get_query_with_options("/credit_notes", params, options)
+    }
+
     /// Issue a credit note to adjust the amount of a finalized invoice.
     ///
     /// For a `status=open` invoice, a credit note reduces its `amount_due`.
@@ -137,11 +148,31 @@ impl CreditNote {
         client.post_form("/credit_notes", &params)
     }
 
+    /// Like [`CreditNote::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateCreditNote<'_>,
+        options: RequestOptions,
+    ) -> Response<CreditNote> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/credit_notes", &params, options)
+    }
+
     /// Retrieves the credit note object with the given identifier.
     pub fn retrieve(client: &Client, id: &CreditNoteId, expand: &[&str]) -> Response<CreditNote> {
         client.get_query(&format!("/credit_notes/{}", id), Expand { expand })
     }
 
+    /// Like [`CreditNote::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &CreditNoteId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<CreditNote> {
+        client.get_query_with_options(&format!("/credit_notes/{}", id), Expand { expand }, options)
+    }
+
     /// Updates an existing credit note.
     pub fn update(
         client: &Client,
@@ -151,6 +182,17 @@ impl CreditNote {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/credit_notes/{}", id), &params)
     }
+
+    /// Like [`CreditNote::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &CreditNoteId,
+        params: UpdateCreditNote<'_>,
+        options: RequestOptions,
+    ) -> Response<CreditNote> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/credit_notes/{}", id), &params, options)
+    }
 }
 
 impl Object for CreditNote {
@@ -330,6 +372,11 @@ impl Paginable for ListCreditNotes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListCreditNotes<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `CreditNote::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCreditNote<'a> {
@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CouponId, CustomerId, PaymentMethodId, PaymentSourceId, PromotionCodeId};
 use crate::params::{
-    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable,
+    Timestamp,
 };
 use crate::resources::{
     Address, CashBalance, Currency, Discount, InvoiceSettingRenderingOptions, PaymentMethod,
@@ -168,17 +169,46 @@ impl Customer {
         client.get_query("/customers", params)
     }
 
+    /// Like [`Customer::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListCustomers<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Customer>> {
+        client.get_query_with_options("/customers", params, options)
+    }
+
     /// Creates a new customer object.
     pub fn create(client: &Client, params: CreateCustomer<'_>) -> Response<Customer> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/customers", &params)
     }
 
+    /// Like [`Customer::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateCustomer<'_>,
+        options: RequestOptions,
+    ) -> Response<Customer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/customers", &params, options)
+    }
+
     /// Retrieves a Customer object.
     pub fn retrieve(client: &Client, id: &CustomerId, expand: &[&str]) -> Response<Customer> {
         client.get_query(&format!("/customers/{}", id), Expand { expand })
     }
 
+    /// Like [`Customer::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &CustomerId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Customer> {
+        client.get_query_with_options(&format!("/customers/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified customer by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
@@ -195,6 +225,17 @@ impl Customer {
         client.post_form(&format!("/customers/{}", id), &params)
     }
 
+    /// Like [`Customer::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &CustomerId,
+        params: UpdateCustomer<'_>,
+        options: RequestOptions,
+    ) -> Response<Customer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/customers/{}", id), &params, options)
+    }
+
     /// Permanently deletes a customer.
     ///
     /// It cannot be undone.
@@ -202,6 +243,15 @@ impl Customer {
     pub fn delete(client: &Client, id: &CustomerId) -> Response<Deleted<CustomerId>> {
         client.delete(&format!("/customers/{}", id))
     }
+
+    /// Like [`Customer::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &CustomerId,
+        options: RequestOptions,
+    ) -> Response<Deleted<CustomerId>> {
+        client.delete_with_options(&format!("/customers/{}", id), options)
+    }
 }
 
 impl Object for Customer {
@@ -468,6 +518,11 @@ impl Paginable for ListCustomers<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListCustomers<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Customer::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCustomer<'a> {
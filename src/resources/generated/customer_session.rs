@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId};
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::{Customer};
@@ -46,6 +46,12 @@ impl CustomerSession {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/customer_sessions", &params)
     }
+
+    /// Like [`CustomerSession::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(client: &Client, params: CreateCustomerSession<'_>, options: RequestOptions) -> Response<CustomerSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/customer_sessions", &params, options)
+    }
 }
 
 impl Object for CustomerSession {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ChargeId, DisputeId, PaymentIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{BalanceTransaction, Charge, Currency, File, PaymentIntent};
 
 /// The resource representing a Stripe "Dispute".
@@ -83,10 +85,29 @@ impl Dispute {
         client.get_query("/disputes", params)
     }
 
+    /// Like [`Dispute::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListDisputes<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Dispute>> {
+        client.get_query_with_options("/disputes", params, options)
+    }
+
     /// Retrieves the dispute with the given ID.
     pub fn retrieve(client: &Client, id: &DisputeId, expand: &[&str]) -> Response<Dispute> {
         client.get_query(&format!("/disputes/{}", id), Expand { expand })
     }
+
+    /// Like [`Dispute::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &DisputeId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Dispute> {
+        client.get_query_with_options(&format!("/disputes/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Dispute {
@@ -303,6 +324,11 @@ impl Paginable for ListDisputes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListDisputes<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `DisputePaymentMethodDetails`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
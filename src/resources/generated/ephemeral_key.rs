@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, EphemeralKeyId, IssuingCardId};
 use crate::params::{Deleted, Expand, Object, Timestamp};
 
@@ -41,10 +41,29 @@ impl EphemeralKey {
         client.post_form("/ephemeral_keys", &params)
     }
 
+    /// Like [`EphemeralKey::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateEphemeralKey<'_>,
+        options: RequestOptions,
+    ) -> Response<EphemeralKey> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/ephemeral_keys", &params, options)
+    }
+
     /// Invalidates a short-lived API key for a given resource.
     pub fn delete(client: &Client, id: &EphemeralKeyId) -> Response<Deleted<EphemeralKeyId>> {
         client.delete(&format!("/ephemeral_keys/{}", id))
     }
+
+    /// Like [`EphemeralKey::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &EphemeralKeyId,
+        options: RequestOptions,
+    ) -> Response<Deleted<EphemeralKeyId>> {
+        client.delete_with_options(&format!("/ephemeral_keys/{}", id), options)
+    }
 }
 
 impl Object for EphemeralKey {
@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::EventId;
-use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp};
 use crate::resources::{EventType, NotificationEventData};
 
 /// The resource representing a Stripe "NotificationEvent".
@@ -55,12 +55,31 @@ impl Event {
         client.get_query("/events", params)
     }
 
+    /// Like [`Event::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListEvents<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Event>> {
+        client.get_query_with_options("/events", params, options)
+    }
+
     /// Retrieves the details of an event.
     ///
     /// Supply the unique identifier of the event, which you might have received in a webhook.
     pub fn retrieve(client: &Client, id: &EventId, expand: &[&str]) -> Response<Event> {
         client.get_query(&format!("/events/{}", id), Expand { expand })
     }
+
+    /// Like [`Event::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &EventId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Event> {
+        client.get_query_with_options(&format!("/events/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Event {
@@ -158,3 +177,8 @@ impl Paginable for ListEvents<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListEvents<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
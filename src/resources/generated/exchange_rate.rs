@@ -2,9 +2,9 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ExchangeRateId};
-use crate::params::{Expand, List, Object, Paginable};
+use crate::params::{Expand, List, Object, Paginable, ReversePaginable};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "ExchangeRate".
@@ -29,10 +29,21 @@ pub fn list(client: &Client, params: &ListExchangeRates<'_>) -> Response<List<Ex
 }
 
 
+/// Like [`ExchangeRate::list`], but with [`RequestOptions`] for this request only.
+pub fn list_with_options(client: &Client, params: &ListExchangeRates<'_>, options: RequestOptions) -> Response<List<ExchangeRate>> {
+    client.get_query_with_options("/exchange_rates", params, options)
+}
+
+
     /// Retrieves the exchange rates from the given currency to every supported currency.
     pub fn retrieve(client: &Client, id: &ExchangeRateId, expand: &[&str]) -> Response<ExchangeRate> {
         client.get_query(&format!("/exchange_rates/{}", id), Expand { expand })
     }
+
+    /// Like [`ExchangeRate::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(client: &Client, id: &ExchangeRateId, expand: &[&str], options: RequestOptions) -> Response<ExchangeRate> {
+        client.get_query_with_options(&format!("/exchange_rates/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for ExchangeRate {
@@ -88,4 +99,10 @@ impl Paginable for ListExchangeRates<'_> {
     type O = ExchangeRate;
     fn set_last(&mut self, item: Self::O) {
                 self.starting_after = Some(item.id());
-            }}
\ No newline at end of file
+            }
+}
+impl ReversePaginable for ListExchangeRates<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
\ No newline at end of file
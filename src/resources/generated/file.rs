@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::FileId;
-use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp};
 use crate::resources::FileLink;
 
 /// The resource representing a Stripe "File".
@@ -57,6 +57,15 @@ impl File {
         client.get_query("/files", params)
     }
 
+    /// Like [`File::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListFiles<'_>,
+        options: RequestOptions,
+    ) -> Response<List<File>> {
+        client.get_query_with_options("/files", params, options)
+    }
+
     /// Retrieves the details of an existing file object.
     ///
     /// After you supply a unique file ID, Stripe returns the corresponding file object.
@@ -64,6 +73,16 @@ impl File {
     pub fn retrieve(client: &Client, id: &FileId, expand: &[&str]) -> Response<File> {
         client.get_query(&format!("/files/{}", id), Expand { expand })
     }
+
+    /// Like [`File::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &FileId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<File> {
+        client.get_query_with_options(&format!("/files/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for File {
@@ -131,6 +150,11 @@ impl Paginable for ListFiles<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListFiles<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `ListFiles`'s `purpose` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{FileId, FileLinkId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{File, Scheduled};
 
 /// The resource representing a Stripe "FileLink".
@@ -49,17 +51,46 @@ impl FileLink {
         client.get_query("/file_links", params)
     }
 
+    /// Like [`FileLink::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListFileLinks<'_>,
+        options: RequestOptions,
+    ) -> Response<List<FileLink>> {
+        client.get_query_with_options("/file_links", params, options)
+    }
+
     /// Creates a new file link object.
     pub fn create(client: &Client, params: CreateFileLink<'_>) -> Response<FileLink> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/file_links", &params)
     }
 
+    /// Like [`FileLink::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateFileLink<'_>,
+        options: RequestOptions,
+    ) -> Response<FileLink> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/file_links", &params, options)
+    }
+
     /// Retrieves the file link with the given ID.
     pub fn retrieve(client: &Client, id: &FileLinkId, expand: &[&str]) -> Response<FileLink> {
         client.get_query(&format!("/file_links/{}", id), Expand { expand })
     }
 
+    /// Like [`FileLink::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &FileLinkId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<FileLink> {
+        client.get_query_with_options(&format!("/file_links/{}", id), Expand { expand }, options)
+    }
+
     /// Updates an existing file link object.
     ///
     /// Expired links can no longer be updated.
@@ -71,6 +102,17 @@ impl FileLink {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/file_links/{}", id), &params)
     }
+
+    /// Like [`FileLink::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &FileLinkId,
+        params: UpdateFileLink<'_>,
+        options: RequestOptions,
+    ) -> Response<FileLink> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/file_links/{}", id), &params, options)
+    }
 }
 
 impl Object for FileLink {
@@ -179,6 +221,11 @@ impl Paginable for ListFileLinks<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListFileLinks<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `FileLink::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateFileLink<'a> {
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{FinancialConnectionsSessionId};
 use crate::params::{Expand, List, Object};
 use crate::resources::{BankConnectionsResourceAccountholder, FinancialConnectionsAccount};
@@ -53,6 +53,12 @@ impl FinancialConnectionsSession {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/financial_connections/sessions", &params)
     }
+
+    /// Like [`FinancialConnectionsSession::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(client: &Client, params: CreateFinancialConnectionsSession<'_>, options: RequestOptions) -> Response<FinancialConnectionsSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/financial_connections/sessions", &params, options)
+    }
 }
 
 impl Object for FinancialConnectionsSession {
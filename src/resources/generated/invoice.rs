@@ -4,11 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
 use crate::params::{
     CurrencyMap, Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
-    Timestamp,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{
     Account, Address, ApiErrors, Application, Charge, ConnectAccountReference, Currency, Customer,
@@ -476,6 +476,15 @@ impl Invoice {
         client.get_query("/invoices", params)
     }
 
+    /// Like [`Invoice::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListInvoices<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Invoice>> {
+        client.get_query_with_options("/invoices", params, options)
+    }
+
     /// This endpoint creates a draft invoice for a given customer.
     ///
     /// The invoice remains a draft until you [finalize](https://stripe.com/docs/api#finalize_invoice) the invoice, which allows you to [pay](https://stripe.com/docs/api#pay_invoice) or [send](https://stripe.com/docs/api#send_invoice) the invoice to your customers.
@@ -484,11 +493,31 @@ impl Invoice {
         client.post_form("/invoices", &params)
     }
 
+    /// Like [`Invoice::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateInvoice<'_>,
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/invoices", &params, options)
+    }
+
     /// Retrieves the invoice with the given ID.
     pub fn retrieve(client: &Client, id: &InvoiceId, expand: &[&str]) -> Response<Invoice> {
         client.get_query(&format!("/invoices/{}", id), Expand { expand })
     }
 
+    /// Like [`Invoice::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &InvoiceId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        client.get_query_with_options(&format!("/invoices/{}", id), Expand { expand }, options)
+    }
+
     /// Permanently deletes a one-off invoice draft.
     ///
     /// This cannot be undone.
@@ -496,6 +525,15 @@ impl Invoice {
     pub fn delete(client: &Client, id: &InvoiceId) -> Response<Deleted<InvoiceId>> {
         client.delete(&format!("/invoices/{}", id))
     }
+
+    /// Like [`Invoice::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &InvoiceId,
+        options: RequestOptions,
+    ) -> Response<Deleted<InvoiceId>> {
+        client.delete_with_options(&format!("/invoices/{}", id), options)
+    }
 }
 
 impl Object for Invoice {
@@ -1010,6 +1048,11 @@ impl Paginable for ListInvoices<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListInvoices<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateInvoiceAutomaticTax {
     /// Whether Stripe automatically computes tax on this invoice.
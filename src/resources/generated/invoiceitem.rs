@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, InvoiceId, InvoiceItemId, PriceId, SubscriptionId};
 use crate::params::{
-    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable,
+    Timestamp,
 };
 use crate::resources::{
     Currency, Customer, Discount, Invoice, Period, Plan, Price, Subscription, TaxRate,
@@ -137,6 +138,15 @@ impl InvoiceItem {
         client.get_query("/invoiceitems", params)
     }
 
+    /// Like [`InvoiceItem::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListInvoiceItems<'_>,
+        options: RequestOptions,
+    ) -> Response<List<InvoiceItem>> {
+        client.get_query_with_options("/invoiceitems", params, options)
+    }
+
     /// Creates an item to be added to a draft invoice (up to 250 items per invoice).
     ///
     /// If no invoice is specified, the item will be on the next invoice created for the customer specified.
@@ -145,11 +155,31 @@ impl InvoiceItem {
         client.post_form("/invoiceitems", &params)
     }
 
+    /// Like [`InvoiceItem::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateInvoiceItem<'_>,
+        options: RequestOptions,
+    ) -> Response<InvoiceItem> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/invoiceitems", &params, options)
+    }
+
     /// Retrieves the invoice item with the given ID.
     pub fn retrieve(client: &Client, id: &InvoiceItemId, expand: &[&str]) -> Response<InvoiceItem> {
         client.get_query(&format!("/invoiceitems/{}", id), Expand { expand })
     }
 
+    /// Like [`InvoiceItem::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &InvoiceItemId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<InvoiceItem> {
+        client.get_query_with_options(&format!("/invoiceitems/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the amount or description of an invoice item on an upcoming invoice.
     ///
     /// Updating an invoice item is only possible before the invoice it’s attached to is closed.
@@ -162,12 +192,32 @@ impl InvoiceItem {
         client.post_form(&format!("/invoiceitems/{}", id), &params)
     }
 
+    /// Like [`InvoiceItem::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &InvoiceItemId,
+        params: UpdateInvoiceItem<'_>,
+        options: RequestOptions,
+    ) -> Response<InvoiceItem> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/invoiceitems/{}", id), &params, options)
+    }
+
     /// Deletes an invoice item, removing it from an invoice.
     ///
     /// Deleting invoice items is only possible when they’re not attached to invoices, or if it’s attached to a draft invoice.
     pub fn delete(client: &Client, id: &InvoiceItemId) -> Response<Deleted<InvoiceItemId>> {
         client.delete(&format!("/invoiceitems/{}", id))
     }
+
+    /// Like [`InvoiceItem::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &InvoiceItemId,
+        options: RequestOptions,
+    ) -> Response<Deleted<InvoiceItemId>> {
+        client.delete_with_options(&format!("/invoiceitems/{}", id), options)
+    }
 }
 
 impl Object for InvoiceItem {
@@ -393,6 +443,11 @@ impl Paginable for ListInvoiceItems<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListInvoiceItems<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `InvoiceItem::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateInvoiceItem<'a> {
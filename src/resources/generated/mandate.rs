@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::MandateId;
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::{Currency, PaymentMethod};
@@ -50,6 +50,16 @@ impl Mandate {
     pub fn retrieve(client: &Client, id: &MandateId, expand: &[&str]) -> Response<Mandate> {
         client.get_query(&format!("/mandates/{}", id), Expand { expand })
     }
+
+    /// Like [`Mandate::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &MandateId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Mandate> {
+        client.get_query_with_options(&format!("/mandates/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Mandate {
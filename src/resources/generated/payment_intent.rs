@@ -4,11 +4,13 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{
     CustomerId, MandateId, PaymentIntentId, PaymentMethodConfigurationId, PaymentMethodId,
 };
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, ApiErrors, Application, Charge, Currency, Customer, Invoice,
     LinkedAccountOptionsUsBankAccount, PaymentIntentNextActionCashappHandleRedirectOrDisplayQrCode,
@@ -201,6 +203,15 @@ impl PaymentIntent {
         client.get_query("/payment_intents", params)
     }
 
+    /// Like [`PaymentIntent::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPaymentIntents<'_>,
+        options: RequestOptions,
+    ) -> Response<List<PaymentIntent>> {
+        client.get_query_with_options("/payment_intents", params, options)
+    }
+
     /// Creates a PaymentIntent object.
     ///
     /// After the PaymentIntent is created, attach a payment method and [confirm](https://stripe.com/docs/api/payment_intents/confirm)
@@ -213,6 +224,16 @@ impl PaymentIntent {
         client.post_form("/payment_intents", &params)
     }
 
+    /// Like [`PaymentIntent::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreatePaymentIntent<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payment_intents", &params, options)
+    }
+
     /// Retrieves the details of a PaymentIntent that has previously been created.
     ///
     /// You can retrieve a PaymentIntent client-side using a publishable key when the `client_secret` is in the query string.
@@ -226,6 +247,20 @@ impl PaymentIntent {
         client.get_query(&format!("/payment_intents/{}", id), Expand { expand })
     }
 
+    /// Like [`PaymentIntent::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PaymentIntentId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        client.get_query_with_options(
+            &format!("/payment_intents/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates properties on a PaymentIntent object without confirming.
     ///
     /// Depending on which properties you update, you might need to confirm the
@@ -241,6 +276,17 @@ impl PaymentIntent {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payment_intents/{}", id), &params)
     }
+
+    /// Like [`PaymentIntent::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: UpdatePaymentIntent<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payment_intents/{}", id), &params, options)
+    }
 }
 
 impl Object for PaymentIntent {
@@ -1862,6 +1908,11 @@ impl Paginable for ListPaymentIntents<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPaymentIntents<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PaymentIntent::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePaymentIntent<'a> {
@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::PaymentLinkId;
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, ReversePaginable};
 use crate::resources::{
     Account, Application, CheckoutSessionItem, ConnectAccountReference, Currency,
     InvoiceSettingRenderingOptions, ShippingRate, SubscriptionsTrialsResourceTrialSettings, TaxId,
@@ -130,17 +130,46 @@ impl PaymentLink {
         client.get_query("/payment_links", params)
     }
 
+    /// Like [`PaymentLink::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPaymentLinks<'_>,
+        options: RequestOptions,
+    ) -> Response<List<PaymentLink>> {
+        client.get_query_with_options("/payment_links", params, options)
+    }
+
     /// Creates a payment link.
     pub fn create(client: &Client, params: CreatePaymentLink<'_>) -> Response<PaymentLink> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/payment_links", &params)
     }
 
+    /// Like [`PaymentLink::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreatePaymentLink<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentLink> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payment_links", &params, options)
+    }
+
     /// Retrieve a payment link.
     pub fn retrieve(client: &Client, id: &PaymentLinkId, expand: &[&str]) -> Response<PaymentLink> {
         client.get_query(&format!("/payment_links/{}", id), Expand { expand })
     }
 
+    /// Like [`PaymentLink::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PaymentLinkId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<PaymentLink> {
+        client.get_query_with_options(&format!("/payment_links/{}", id), Expand { expand }, options)
+    }
+
     /// Updates a payment link.
     pub fn update(
         client: &Client,
@@ -150,6 +179,17 @@ impl PaymentLink {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payment_links/{}", id), &params)
     }
+
+    /// Like [`PaymentLink::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PaymentLinkId,
+        params: UpdatePaymentLink<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentLink> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payment_links/{}", id), &params, options)
+    }
 }
 
 impl Object for PaymentLink {
@@ -708,6 +748,11 @@ impl Paginable for ListPaymentLinks<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPaymentLinks<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PaymentLink::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePaymentLink<'a> {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, PaymentMethodId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Address, BillingDetails, Charge, Customer, PaymentMethodCardPresentNetworks, RadarRadarOptions,
     SetupAttempt,
@@ -164,6 +166,15 @@ impl PaymentMethod {
         client.get_query("/payment_methods", params)
     }
 
+    /// Like [`PaymentMethod::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPaymentMethods<'_>,
+        options: RequestOptions,
+    ) -> Response<List<PaymentMethod>> {
+        client.get_query_with_options("/payment_methods", params, options)
+    }
+
     /// Creates a PaymentMethod object.
     ///
     /// Read the [Stripe.js reference](https://stripe.com/docs/stripe-js/reference#stripe-create-payment-method) to learn how to create PaymentMethods via Stripe.js.  Instead of creating a PaymentMethod directly, we recommend using the [PaymentIntents](https://stripe.com/docs/payments/accept-a-payment) API to accept a payment immediately or the [SetupIntent](https://stripe.com/docs/payments/save-and-reuse) API to collect payment method details ahead of a future payment.
@@ -172,6 +183,16 @@ impl PaymentMethod {
         client.post_form("/payment_methods", &params)
     }
 
+    /// Like [`PaymentMethod::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreatePaymentMethod<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentMethod> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payment_methods", &params, options)
+    }
+
     /// Retrieves a PaymentMethod object attached to the StripeAccount.
     ///
     /// To retrieve a payment method attached to a Customer, you should use [Retrieve a Customer’s PaymentMethods](https://stripe.com/docs/api/payment_methods/customer).
@@ -183,6 +204,20 @@ impl PaymentMethod {
         client.get_query(&format!("/payment_methods/{}", id), Expand { expand })
     }
 
+    /// Like [`PaymentMethod::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PaymentMethodId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<PaymentMethod> {
+        client.get_query_with_options(
+            &format!("/payment_methods/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates a PaymentMethod object.
     ///
     /// A PaymentMethod must be attached a customer to be updated.
@@ -194,6 +229,17 @@ impl PaymentMethod {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payment_methods/{}", id), &params)
     }
+
+    /// Like [`PaymentMethod::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PaymentMethodId,
+        params: UpdatePaymentMethod<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentMethod> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payment_methods/{}", id), &params, options)
+    }
 }
 
 impl Object for PaymentMethod {
@@ -1115,6 +1161,11 @@ impl Paginable for ListPaymentMethods<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPaymentMethods<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PaymentMethod::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePaymentMethod<'a> {
@@ -2,9 +2,9 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{PaymentMethodConfigurationId};
-use crate::params::{Expand, List, Object, Paginable};
+use crate::params::{Expand, List, Object, Paginable, ReversePaginable};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "PaymentMethodConfigResourcePaymentMethodConfiguration".
@@ -144,22 +144,45 @@ pub fn list(client: &Client, params: &ListPaymentMethodConfigurations<'_>) -> Re
 }
 
 
+/// Like [`PaymentMethodConfiguration::list`], but with [`RequestOptions`] for this request only.
+pub fn list_with_options(client: &Client, params: &ListPaymentMethodConfigurations<'_>, options: RequestOptions) -> Response<List<PaymentMethodConfiguration>> {
+    client.get_query_with_options("/payment_method_configurations", params, options)
+}
+
+
     /// Creates a payment method configuration.
     pub fn create(client: &Client, params: CreatePaymentMethodConfiguration<'_>) -> Response<PaymentMethodConfiguration> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/payment_method_configurations", &params)
     }
 
+    /// Like [`PaymentMethodConfiguration::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(client: &Client, params: CreatePaymentMethodConfiguration<'_>, options: RequestOptions) -> Response<PaymentMethodConfiguration> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payment_method_configurations", &params, options)
+    }
+
     /// Retrieve payment method configuration.
     pub fn retrieve(client: &Client, id: &PaymentMethodConfigurationId, expand: &[&str]) -> Response<PaymentMethodConfiguration> {
         client.get_query(&format!("/payment_method_configurations/{}", id), Expand { expand })
     }
 
+    /// Like [`PaymentMethodConfiguration::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(client: &Client, id: &PaymentMethodConfigurationId, expand: &[&str], options: RequestOptions) -> Response<PaymentMethodConfiguration> {
+        client.get_query_with_options(&format!("/payment_method_configurations/{}", id), Expand { expand }, options)
+    }
+
     /// Update payment method configuration.
     pub fn update(client: &Client, id: &PaymentMethodConfigurationId, params: UpdatePaymentMethodConfiguration<'_>) -> Response<PaymentMethodConfiguration> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payment_method_configurations/{}", id), &params)
     }
+
+    /// Like [`PaymentMethodConfiguration::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(client: &Client, id: &PaymentMethodConfigurationId, params: UpdatePaymentMethodConfiguration<'_>, options: RequestOptions) -> Response<PaymentMethodConfiguration> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payment_method_configurations/{}", id), &params, options)
+    }
 }
 
 impl Object for PaymentMethodConfiguration {
@@ -515,7 +538,13 @@ impl Paginable for ListPaymentMethodConfigurations<'_> {
     type O = PaymentMethodConfiguration;
     fn set_last(&mut self, item: Self::O) {
                 self.starting_after = Some(item.id());
-            }}
+            }
+}
+impl ReversePaginable for ListPaymentMethodConfigurations<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PaymentMethodConfiguration::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePaymentMethodConfiguration<'a> {
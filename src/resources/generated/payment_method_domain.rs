@@ -2,9 +2,9 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{PaymentMethodDomainId};
-use crate::params::{Expand, List, Object, Paginable, Timestamp};
+use crate::params::{Expand, List, Object, Paginable, ReversePaginable, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "PaymentMethodDomainResourcePaymentMethodDomain".
@@ -48,22 +48,45 @@ pub fn list(client: &Client, params: &ListPaymentMethodDomains<'_>) -> Response<
 }
 
 
+/// Like [`PaymentMethodDomain::list`], but with [`RequestOptions`] for this request only.
+pub fn list_with_options(client: &Client, params: &ListPaymentMethodDomains<'_>, options: RequestOptions) -> Response<List<PaymentMethodDomain>> {
+    client.get_query_with_options("/payment_method_domains", params, options)
+}
+
+
     /// Creates a payment method domain.
     pub fn create(client: &Client, params: CreatePaymentMethodDomain<'_>) -> Response<PaymentMethodDomain> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/payment_method_domains", &params)
     }
 
+    /// Like [`PaymentMethodDomain::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(client: &Client, params: CreatePaymentMethodDomain<'_>, options: RequestOptions) -> Response<PaymentMethodDomain> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payment_method_domains", &params, options)
+    }
+
     /// Retrieves the details of an existing payment method domain.
     pub fn retrieve(client: &Client, id: &PaymentMethodDomainId, expand: &[&str]) -> Response<PaymentMethodDomain> {
         client.get_query(&format!("/payment_method_domains/{}", id), Expand { expand })
     }
 
+    /// Like [`PaymentMethodDomain::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(client: &Client, id: &PaymentMethodDomainId, expand: &[&str], options: RequestOptions) -> Response<PaymentMethodDomain> {
+        client.get_query_with_options(&format!("/payment_method_domains/{}", id), Expand { expand }, options)
+    }
+
     /// Updates an existing payment method domain.
     pub fn update(client: &Client, id: &PaymentMethodDomainId, params: UpdatePaymentMethodDomain<'_>) -> Response<PaymentMethodDomain> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payment_method_domains/{}", id), &params)
     }
+
+    /// Like [`PaymentMethodDomain::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(client: &Client, id: &PaymentMethodDomainId, params: UpdatePaymentMethodDomain<'_>, options: RequestOptions) -> Response<PaymentMethodDomain> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payment_method_domains/{}", id), &params, options)
+    }
 }
 
 impl Object for PaymentMethodDomain {
@@ -176,7 +199,13 @@ impl Paginable for ListPaymentMethodDomains<'_> {
     type O = PaymentMethodDomain;
     fn set_last(&mut self, item: Self::O) {
                 self.starting_after = Some(item.id());
-            }}
+            }
+}
+impl ReversePaginable for ListPaymentMethodDomains<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PaymentMethodDomain::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePaymentMethodDomain<'a> {
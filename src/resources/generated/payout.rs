@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::PayoutId;
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{BalanceTransaction, Currency, PayoutDestinationUnion};
 
 /// The resource representing a Stripe "Payout".
@@ -109,6 +111,15 @@ impl Payout {
         client.get_query("/payouts", params)
     }
 
+    /// Like [`Payout::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPayouts<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Payout>> {
+        client.get_query_with_options("/payouts", params, options)
+    }
+
     /// To send funds to your own bank account, create a new payout object.
     ///
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must cover the payout amount.
@@ -119,6 +130,16 @@ impl Payout {
         client.post_form("/payouts", &params)
     }
 
+    /// Like [`Payout::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreatePayout<'_>,
+        options: RequestOptions,
+    ) -> Response<Payout> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/payouts", &params, options)
+    }
+
     /// Retrieves the details of an existing payout.
     ///
     /// Supply the unique payout ID from either a payout creation request or the payout list.
@@ -127,6 +148,16 @@ impl Payout {
         client.get_query(&format!("/payouts/{}", id), Expand { expand })
     }
 
+    /// Like [`Payout::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PayoutId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Payout> {
+        client.get_query_with_options(&format!("/payouts/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified payout by setting the values of the parameters you pass.
     ///
     /// We don’t change parameters that you don’t provide.
@@ -135,6 +166,17 @@ impl Payout {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/payouts/{}", id), &params)
     }
+
+    /// Like [`Payout::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PayoutId,
+        params: UpdatePayout<'_>,
+        options: RequestOptions,
+    ) -> Response<Payout> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/payouts/{}", id), &params, options)
+    }
 }
 
 impl Object for Payout {
@@ -284,6 +326,11 @@ impl Paginable for ListPayouts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPayouts<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Payout::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePayout<'a> {
@@ -4,11 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::PlanId;
 use crate::params::{
     Deleted, Expand, Expandable, IdOrCreate, List, Metadata, Object, Paginable, RangeQuery,
-    Timestamp,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{CreateProduct, Currency, Product};
 
@@ -137,11 +137,30 @@ impl Plan {
         client.get_query("/plans", params)
     }
 
+    /// Like [`Plan::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPlans<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Plan>> {
+        client.get_query_with_options("/plans", params, options)
+    }
+
     /// Retrieves the plan with the given ID.
     pub fn retrieve(client: &Client, id: &PlanId, expand: &[&str]) -> Response<Plan> {
         client.get_query(&format!("/plans/{}", id), Expand { expand })
     }
 
+    /// Like [`Plan::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PlanId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Plan> {
+        client.get_query_with_options(&format!("/plans/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified plan by setting the values of the parameters passed.
     ///
     /// Any parameters not provided are left unchanged.
@@ -151,12 +170,32 @@ impl Plan {
         client.post_form(&format!("/plans/{}", id), &params)
     }
 
+    /// Like [`Plan::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PlanId,
+        params: UpdatePlan<'_>,
+        options: RequestOptions,
+    ) -> Response<Plan> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/plans/{}", id), &params, options)
+    }
+
     /// Deleting plans means new subscribers can’t be added.
     ///
     /// Existing subscribers aren’t affected.
     pub fn delete(client: &Client, id: &PlanId) -> Response<Deleted<PlanId>> {
         client.delete(&format!("/plans/{}", id))
     }
+
+    /// Like [`Plan::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &PlanId,
+        options: RequestOptions,
+    ) -> Response<Deleted<PlanId>> {
+        client.delete_with_options(&format!("/plans/{}", id), options)
+    }
 }
 
 impl Object for Plan {
@@ -257,6 +296,11 @@ impl Paginable for ListPlans<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPlans<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Plan::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePlan<'a> {
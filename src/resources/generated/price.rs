@@ -4,11 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::PriceId;
 use crate::params::{
     CurrencyMap, Expand, Expandable, IdOrCreate, List, Metadata, Object, Paginable, RangeQuery,
-    Timestamp,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{CreateProduct, Currency, CustomUnitAmount, Product, UpTo};
 
@@ -140,6 +140,15 @@ impl Price {
         client.get_query("/prices", params)
     }
 
+    /// Like [`Price::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPrices<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Price>> {
+        client.get_query_with_options("/prices", params, options)
+    }
+
     /// Creates a new price for an existing product.
     ///
     /// The price can be recurring or one-time.
@@ -148,11 +157,31 @@ impl Price {
         client.post_form("/prices", &params)
     }
 
+    /// Like [`Price::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreatePrice<'_>,
+        options: RequestOptions,
+    ) -> Response<Price> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/prices", &params, options)
+    }
+
     /// Retrieves the price with the given ID.
     pub fn retrieve(client: &Client, id: &PriceId, expand: &[&str]) -> Response<Price> {
         client.get_query(&format!("/prices/{}", id), Expand { expand })
     }
 
+    /// Like [`Price::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PriceId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Price> {
+        client.get_query_with_options(&format!("/prices/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified price by setting the values of the parameters passed.
     ///
     /// Any parameters not provided are left unchanged.
@@ -160,6 +189,17 @@ impl Price {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/prices/{}", id), &params)
     }
+
+    /// Like [`Price::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PriceId,
+        params: UpdatePrice<'_>,
+        options: RequestOptions,
+    ) -> Response<Price> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/prices/{}", id), &params, options)
+    }
 }
 
 impl Object for Price {
@@ -476,6 +516,11 @@ impl Paginable for ListPrices<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPrices<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Price::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePrice<'a> {
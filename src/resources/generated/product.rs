@@ -4,11 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ProductId, TaxCodeId};
 use crate::params::{
     CurrencyMap, Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
-    Timestamp,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{Currency, Price, TaxCode, UpTo};
 
@@ -118,12 +118,31 @@ impl Product {
         client.get_query("/products", params)
     }
 
+    /// Like [`Product::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListProducts<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Product>> {
+        client.get_query_with_options("/products", params, options)
+    }
+
     /// Creates a new product object.
     pub fn create(client: &Client, params: CreateProduct<'_>) -> Response<Product> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/products", &params)
     }
 
+    /// Like [`Product::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateProduct<'_>,
+        options: RequestOptions,
+    ) -> Response<Product> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/products", &params, options)
+    }
+
     /// Retrieves the details of an existing product.
     ///
     /// Supply the unique product ID from either a product creation request or the product list, and Stripe will return the corresponding product information.
@@ -131,6 +150,16 @@ impl Product {
         client.get_query(&format!("/products/{}", id), Expand { expand })
     }
 
+    /// Like [`Product::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &ProductId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Product> {
+        client.get_query_with_options(&format!("/products/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specific product by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
@@ -139,6 +168,17 @@ impl Product {
         client.post_form(&format!("/products/{}", id), &params)
     }
 
+    /// Like [`Product::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &ProductId,
+        params: UpdateProduct<'_>,
+        options: RequestOptions,
+    ) -> Response<Product> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/products/{}", id), &params, options)
+    }
+
     /// Delete a product.
     ///
     /// Deleting a product is only possible if it has no prices associated with it.
@@ -146,6 +186,15 @@ impl Product {
     pub fn delete(client: &Client, id: &ProductId) -> Response<Deleted<ProductId>> {
         client.delete(&format!("/products/{}", id))
     }
+
+    /// Like [`Product::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &ProductId,
+        options: RequestOptions,
+    ) -> Response<Deleted<ProductId>> {
+        client.delete_with_options(&format!("/products/{}", id), options)
+    }
 }
 
 impl Object for Product {
@@ -374,6 +423,11 @@ impl Paginable for ListProducts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListProducts<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Product::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateProduct<'a> {
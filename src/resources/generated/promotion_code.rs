@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CouponId, CustomerId, PromotionCodeId};
 use crate::params::{
-    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{Coupon, Currency, Customer};
 
@@ -65,6 +66,15 @@ impl PromotionCode {
         client.get_query("/promotion_codes", params)
     }
 
+    /// Like [`PromotionCode::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListPromotionCodes<'_>,
+        options: RequestOptions,
+    ) -> Response<List<PromotionCode>> {
+        client.get_query_with_options("/promotion_codes", params, options)
+    }
+
     /// Retrieves the promotion code with the given ID.
     ///
     /// In order to retrieve a promotion code by the customer-facing `code` use [list](https://stripe.com/docs/api/promotion_codes/list) with the desired `code`.
@@ -76,6 +86,20 @@ impl PromotionCode {
         client.get_query(&format!("/promotion_codes/{}", id), Expand { expand })
     }
 
+    /// Like [`PromotionCode::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &PromotionCodeId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<PromotionCode> {
+        client.get_query_with_options(
+            &format!("/promotion_codes/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates the specified promotion code by setting the values of the parameters passed.
     ///
     /// Most fields are, by design, not editable.
@@ -87,6 +111,17 @@ impl PromotionCode {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/promotion_codes/{}", id), &params)
     }
+
+    /// Like [`PromotionCode::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &PromotionCodeId,
+        params: UpdatePromotionCode<'_>,
+        options: RequestOptions,
+    ) -> Response<PromotionCode> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/promotion_codes/{}", id), &params, options)
+    }
 }
 
 impl Object for PromotionCode {
@@ -194,6 +229,11 @@ impl Paginable for ListPromotionCodes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListPromotionCodes<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `PromotionCode::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePromotionCode<'a> {
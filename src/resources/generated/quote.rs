@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, QuoteId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, Application, CheckoutSessionItem, ConnectAccountReference, Currency, Customer,
     Discount, Invoice, QuotesResourceTotalDetails, Subscription, SubscriptionSchedule, TaxRate,
@@ -149,10 +151,29 @@ impl Quote {
         client.get_query("/quotes", params)
     }
 
+    /// Like [`Quote::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListQuotes<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Quote>> {
+        client.get_query_with_options("/quotes", params, options)
+    }
+
     /// Retrieves the quote with the given ID.
     pub fn retrieve(client: &Client, id: &QuoteId, expand: &[&str]) -> Response<Quote> {
         client.get_query(&format!("/quotes/{}", id), Expand { expand })
     }
+
+    /// Like [`Quote::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &QuoteId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Quote> {
+        client.get_query_with_options(&format!("/quotes/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Quote {
@@ -369,6 +390,11 @@ impl Paginable for ListQuotes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListQuotes<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `Quote`'s `collection_method` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ChargeId, CustomerId, PaymentIntentId, RefundId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{BalanceTransaction, Charge, Currency, PaymentIntent, TransferReversal};
 
 /// The resource representing a Stripe "Refund".
@@ -101,6 +103,15 @@ impl Refund {
         client.get_query("/refunds", params)
     }
 
+    /// Like [`Refund::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListRefunds<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Refund>> {
+        client.get_query_with_options("/refunds", params, options)
+    }
+
     /// When you create a new refund, you must specify a Charge or a PaymentIntent object on which to create it.
     ///
     /// Creating a new refund will refund a charge that has previously been created but not yet refunded.
@@ -117,11 +128,31 @@ impl Refund {
         client.post_form("/refunds", &params)
     }
 
+    /// Like [`Refund::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateRefund<'_>,
+        options: RequestOptions,
+    ) -> Response<Refund> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/refunds", &params, options)
+    }
+
     /// Retrieves the details of an existing refund.
     pub fn retrieve(client: &Client, id: &RefundId, expand: &[&str]) -> Response<Refund> {
         client.get_query(&format!("/refunds/{}", id), Expand { expand })
     }
 
+    /// Like [`Refund::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &RefundId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Refund> {
+        client.get_query_with_options(&format!("/refunds/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the refund that you specify by setting the values of the passed parameters.
     ///
     /// Any parameters that you don’t provide remain unchanged.  This request only accepts `metadata` as an argument.
@@ -129,6 +160,17 @@ impl Refund {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/refunds/{}", id), &params)
     }
+
+    /// Like [`Refund::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &RefundId,
+        params: UpdateRefund<'_>,
+        options: RequestOptions,
+    ) -> Response<Refund> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/refunds/{}", id), &params, options)
+    }
 }
 
 impl Object for Refund {
@@ -442,6 +484,11 @@ impl Paginable for ListRefunds<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListRefunds<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Refund::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateRefund<'a> {
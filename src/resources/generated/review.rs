@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::ReviewId;
-use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{Charge, PaymentIntent, ReviewReason};
 
 /// The resource representing a Stripe "RadarReview".
@@ -73,10 +75,29 @@ impl Review {
         client.get_query("/reviews", params)
     }
 
+    /// Like [`Review::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListReviews<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Review>> {
+        client.get_query_with_options("/reviews", params, options)
+    }
+
     /// Retrieves a `Review` object.
     pub fn retrieve(client: &Client, id: &ReviewId, expand: &[&str]) -> Response<Review> {
         client.get_query(&format!("/reviews/{}", id), Expand { expand })
     }
+
+    /// Like [`Review::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &ReviewId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Review> {
+        client.get_query_with_options(&format!("/reviews/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Review {
@@ -170,6 +191,11 @@ impl Paginable for ListReviews<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListReviews<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `Review`'s `closed_reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
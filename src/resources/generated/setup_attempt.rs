@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{SetupAttemptId, SetupIntentId};
-use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, ApiErrors, Application, Customer, Mandate, PaymentMethod,
     PaymentMethodDetailsCardWalletApplePay, PaymentMethodDetailsCardWalletGooglePay, SetupIntent,
@@ -76,6 +78,15 @@ impl SetupAttempt {
     pub fn list(client: &Client, params: &ListSetupAttempts<'_>) -> Response<List<SetupAttempt>> {
         client.get_query("/setup_attempts", params)
     }
+
+    /// Like [`SetupAttempt::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSetupAttempts<'_>,
+        options: RequestOptions,
+    ) -> Response<List<SetupAttempt>> {
+        client.get_query_with_options("/setup_attempts", params, options)
+    }
 }
 
 impl Object for SetupAttempt {
@@ -443,6 +454,11 @@ impl Paginable for ListSetupAttempts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSetupAttempts<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `SetupAttempt`'s `flow_directions` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
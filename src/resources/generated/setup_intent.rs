@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, PaymentMethodConfigurationId, PaymentMethodId, SetupIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, ApiErrors, Application, Currency, Customer, LinkedAccountOptionsUsBankAccount,
     Mandate, PaymentIntentNextActionCashappHandleRedirectOrDisplayQrCode, PaymentMethod,
@@ -130,6 +132,15 @@ impl SetupIntent {
         client.get_query("/setup_intents", params)
     }
 
+    /// Like [`SetupIntent::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSetupIntents<'_>,
+        options: RequestOptions,
+    ) -> Response<List<SetupIntent>> {
+        client.get_query_with_options("/setup_intents", params, options)
+    }
+
     /// Creates a SetupIntent object.
     ///
     /// After you create the SetupIntent, attach a payment method and [confirm](https://stripe.com/docs/api/setup_intents/confirm)
@@ -139,6 +150,16 @@ impl SetupIntent {
         client.post_form("/setup_intents", &params)
     }
 
+    /// Like [`SetupIntent::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateSetupIntent<'_>,
+        options: RequestOptions,
+    ) -> Response<SetupIntent> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/setup_intents", &params, options)
+    }
+
     /// Retrieves the details of a SetupIntent that has previously been created.
     ///
     /// Client-side retrieval using a publishable key is allowed when the `client_secret` is provided in the query string.
@@ -148,6 +169,16 @@ impl SetupIntent {
         client.get_query(&format!("/setup_intents/{}", id), Expand { expand })
     }
 
+    /// Like [`SetupIntent::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &SetupIntentId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<SetupIntent> {
+        client.get_query_with_options(&format!("/setup_intents/{}", id), Expand { expand }, options)
+    }
+
     /// Updates a SetupIntent object.
     pub fn update(
         client: &Client,
@@ -157,6 +188,17 @@ impl SetupIntent {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/setup_intents/{}", id), &params)
     }
+
+    /// Like [`SetupIntent::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &SetupIntentId,
+        params: UpdateSetupIntent<'_>,
+        options: RequestOptions,
+    ) -> Response<SetupIntent> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/setup_intents/{}", id), &params, options)
+    }
 }
 
 impl Object for SetupIntent {
@@ -595,6 +637,11 @@ impl Paginable for ListSetupIntents<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSetupIntents<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `SetupIntent::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSetupIntent<'a> {
@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ShippingRateId, TaxCodeId};
 use crate::params::{
-    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
+    ReversePaginable, Timestamp,
 };
 use crate::resources::{Currency, TaxCode};
 
@@ -73,12 +74,31 @@ impl ShippingRate {
         client.get_query("/shipping_rates", params)
     }
 
+    /// Like [`ShippingRate::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListShippingRates<'_>,
+        options: RequestOptions,
+    ) -> Response<List<ShippingRate>> {
+        client.get_query_with_options("/shipping_rates", params, options)
+    }
+
     /// Creates a new shipping rate object.
     pub fn create(client: &Client, params: CreateShippingRate<'_>) -> Response<ShippingRate> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/shipping_rates", &params)
     }
 
+    /// Like [`ShippingRate::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateShippingRate<'_>,
+        options: RequestOptions,
+    ) -> Response<ShippingRate> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/shipping_rates", &params, options)
+    }
+
     /// Returns the shipping rate object with the given ID.
     pub fn retrieve(
         client: &Client,
@@ -88,6 +108,20 @@ impl ShippingRate {
         client.get_query(&format!("/shipping_rates/{}", id), Expand { expand })
     }
 
+    /// Like [`ShippingRate::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &ShippingRateId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<ShippingRate> {
+        client.get_query_with_options(
+            &format!("/shipping_rates/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates an existing shipping rate object.
     pub fn update(
         client: &Client,
@@ -97,6 +131,17 @@ impl ShippingRate {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/shipping_rates/{}", id), &params)
     }
+
+    /// Like [`ShippingRate::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &ShippingRateId,
+        params: UpdateShippingRate<'_>,
+        options: RequestOptions,
+    ) -> Response<ShippingRate> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/shipping_rates/{}", id), &params, options)
+    }
 }
 
 impl Object for ShippingRate {
@@ -287,6 +332,11 @@ impl Paginable for ListShippingRates<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListShippingRates<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `ShippingRate::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateShippingRate<'a> {
@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, SourceId, TokenId};
-use crate::params::{Expand, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{Expand, List, Metadata, Object, Paginable, ReversePaginable, Timestamp};
 use crate::resources::{
     Address, BillingDetails, Currency, Shipping, SourceRedirectFlowFailureReason,
     SourceRedirectFlowStatus, SourceStatus, SourceUsage,
@@ -165,12 +165,31 @@ impl Source {
         client.get_query("/sources/{source}/source_transactions", params)
     }
 
+    /// Like [`Source::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSources<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Source>> {
+        client.get_query_with_options("/sources/{source}/source_transactions", params, options)
+    }
+
     /// Creates a new source object.
     pub fn create(client: &Client, params: CreateSource<'_>) -> Response<Source> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/sources", &params)
     }
 
+    /// Like [`Source::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateSource<'_>,
+        options: RequestOptions,
+    ) -> Response<Source> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/sources", &params, options)
+    }
+
     /// Retrieves an existing source object.
     ///
     /// Supply the unique source ID from a source creation request and Stripe will return the corresponding up-to-date source object information.
@@ -178,6 +197,16 @@ impl Source {
         client.get_query(&format!("/sources/{}", id), Expand { expand })
     }
 
+    /// Like [`Source::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &SourceId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Source> {
+        client.get_query_with_options(&format!("/sources/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified source by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.  This request accepts the `metadata` and `owner` as arguments.
@@ -187,6 +216,17 @@ impl Source {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/sources/{}", id), &params)
     }
+
+    /// Like [`Source::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &SourceId,
+        params: UpdateSource<'_>,
+        options: RequestOptions,
+    ) -> Response<Source> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/sources/{}", id), &params, options)
+    }
 }
 
 impl Object for Source {
@@ -1081,6 +1121,11 @@ impl Paginable for ListSources<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSources<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Source::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSource<'a> {
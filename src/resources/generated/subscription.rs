@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CouponId, CustomerId, PlanId, PriceId, PromotionCodeId, SubscriptionId};
 use crate::params::{
-    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
+    Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable,
+    Timestamp,
 };
 use crate::resources::{
     Account, Application, CollectionMethod, ConnectAccountReference, Currency, Customer, Discount,
@@ -218,6 +219,15 @@ impl Subscription {
         client.get_query("/subscriptions", params)
     }
 
+    /// Like [`Subscription::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSubscriptions<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Subscription>> {
+        client.get_query_with_options("/subscriptions", params, options)
+    }
+
     /// Creates a new subscription on an existing customer.
     ///
     /// Each customer can have up to 500 active or scheduled subscriptions.  When you create a subscription with `collection_method=charge_automatically`, the first invoice is finalized as part of the request. The `payment_behavior` parameter determines the exact behavior of the initial payment.  To start subscriptions where the first invoice always begins in a `draft` status, use [subscription schedules](https://stripe.com/docs/billing/subscriptions/subscription-schedules#managing) instead. Schedules provide the flexibility to model more complex billing configurations that change over time.
@@ -226,6 +236,16 @@ impl Subscription {
         client.post_form("/subscriptions", &params)
     }
 
+    /// Like [`Subscription::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateSubscription<'_>,
+        options: RequestOptions,
+    ) -> Response<Subscription> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/subscriptions", &params, options)
+    }
+
     /// Retrieves the subscription with the given ID.
     pub fn retrieve(
         client: &Client,
@@ -235,6 +255,16 @@ impl Subscription {
         client.get_query(&format!("/subscriptions/{}", id), Expand { expand })
     }
 
+    /// Like [`Subscription::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &SubscriptionId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Subscription> {
+        client.get_query_with_options(&format!("/subscriptions/{}", id), Expand { expand }, options)
+    }
+
     /// Updates an existing subscription to match the specified parameters.
     /// When changing prices or quantities, we optionally prorate the price we charge next month to make up for any price changes.
     /// To preview how the proration is calculated, use the [upcoming invoice](https://stripe.com/docs/api/invoices/upcoming) endpoint.
@@ -260,6 +290,17 @@ impl Subscription {
         client.post_form(&format!("/subscriptions/{}", id), &params)
     }
 
+    /// Like [`Subscription::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &SubscriptionId,
+        params: UpdateSubscription<'_>,
+        options: RequestOptions,
+    ) -> Response<Subscription> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/subscriptions/{}", id), &params, options)
+    }
+
     /// Cancels a customer’s subscription immediately.
     ///
     /// The customer will not be charged again for the subscription.  Note, however, that any pending invoice items that you’ve created will still be charged for at the end of the period, unless manually [deleted](https://stripe.com/docs/api#delete_invoiceitem).
@@ -271,6 +312,15 @@ impl Subscription {
     pub fn delete(client: &Client, id: &SubscriptionId) -> Response<Deleted<SubscriptionId>> {
         client.delete(&format!("/subscriptions/{}", id))
     }
+
+    /// Like [`Subscription::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &SubscriptionId,
+        options: RequestOptions,
+    ) -> Response<Deleted<SubscriptionId>> {
+        client.delete_with_options(&format!("/subscriptions/{}", id), options)
+    }
 }
 
 impl Object for Subscription {
@@ -804,6 +854,11 @@ impl Paginable for ListSubscriptions<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSubscriptions<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Subscription::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSubscription<'a> {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{PlanId, PriceId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Deleted, Expand, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{
+    Deleted, Expand, List, Metadata, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{Currency, Plan, Price, SubscriptionItemBillingThresholds, TaxRate};
 
 /// The resource representing a Stripe "SubscriptionItem".
@@ -67,6 +69,15 @@ impl SubscriptionItem {
         client.get_query("/subscription_items", params)
     }
 
+    /// Like [`SubscriptionItem::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSubscriptionItems<'_>,
+        options: RequestOptions,
+    ) -> Response<List<SubscriptionItem>> {
+        client.get_query_with_options("/subscription_items", params, options)
+    }
+
     /// Adds a new item to an existing subscription.
     ///
     /// No existing items will be changed or replaced.
@@ -78,6 +89,16 @@ impl SubscriptionItem {
         client.post_form("/subscription_items", &params)
     }
 
+    /// Like [`SubscriptionItem::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateSubscriptionItem<'_>,
+        options: RequestOptions,
+    ) -> Response<SubscriptionItem> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/subscription_items", &params, options)
+    }
+
     /// Retrieves the subscription item with the given ID.
     pub fn retrieve(
         client: &Client,
@@ -87,6 +108,20 @@ impl SubscriptionItem {
         client.get_query(&format!("/subscription_items/{}", id), Expand { expand })
     }
 
+    /// Like [`SubscriptionItem::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &SubscriptionItemId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<SubscriptionItem> {
+        client.get_query_with_options(
+            &format!("/subscription_items/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates the plan or quantity of an item on a current subscription.
     pub fn update(
         client: &Client,
@@ -97,6 +132,17 @@ impl SubscriptionItem {
         client.post_form(&format!("/subscription_items/{}", id), &params)
     }
 
+    /// Like [`SubscriptionItem::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &SubscriptionItemId,
+        params: UpdateSubscriptionItem<'_>,
+        options: RequestOptions,
+    ) -> Response<SubscriptionItem> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/subscription_items/{}", id), &params, options)
+    }
+
     /// Deletes an item from the subscription.
     ///
     /// Removing a subscription item from a subscription will not cancel the subscription.
@@ -106,6 +152,15 @@ impl SubscriptionItem {
     ) -> Response<Deleted<SubscriptionItemId>> {
         client.delete(&format!("/subscription_items/{}", id))
     }
+
+    /// Like [`SubscriptionItem::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &SubscriptionItemId,
+        options: RequestOptions,
+    ) -> Response<Deleted<SubscriptionItemId>> {
+        client.delete_with_options(&format!("/subscription_items/{}", id), options)
+    }
 }
 
 impl Object for SubscriptionItem {
@@ -260,6 +315,11 @@ impl Paginable for ListSubscriptionItems<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSubscriptionItems<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `SubscriptionItem::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSubscriptionItem<'a> {
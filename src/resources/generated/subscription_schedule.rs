@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, SubscriptionScheduleId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{
     Account, Application, CollectionMethod, ConnectAccountReference, Coupon, Currency, Customer,
     PaymentMethod, Plan, Price, Scheduled, Subscription, SubscriptionBillingThresholds,
@@ -96,6 +98,15 @@ impl SubscriptionSchedule {
         client.get_query("/subscription_schedules", params)
     }
 
+    /// Like [`SubscriptionSchedule::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListSubscriptionSchedules<'_>,
+        options: RequestOptions,
+    ) -> Response<List<SubscriptionSchedule>> {
+        client.get_query_with_options("/subscription_schedules", params, options)
+    }
+
     /// Creates a new subscription schedule object.
     ///
     /// Each customer can have up to 500 active or scheduled subscriptions.
@@ -107,6 +118,16 @@ impl SubscriptionSchedule {
         client.post_form("/subscription_schedules", &params)
     }
 
+    /// Like [`SubscriptionSchedule::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateSubscriptionSchedule<'_>,
+        options: RequestOptions,
+    ) -> Response<SubscriptionSchedule> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/subscription_schedules", &params, options)
+    }
+
     /// Retrieves the details of an existing subscription schedule.
     ///
     /// You only need to supply the unique subscription schedule identifier that was returned upon subscription schedule creation.
@@ -118,6 +139,20 @@ impl SubscriptionSchedule {
         client.get_query(&format!("/subscription_schedules/{}", id), Expand { expand })
     }
 
+    /// Like [`SubscriptionSchedule::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<SubscriptionSchedule> {
+        client.get_query_with_options(
+            &format!("/subscription_schedules/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates an existing subscription schedule.
     pub fn update(
         client: &Client,
@@ -127,6 +162,17 @@ impl SubscriptionSchedule {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/subscription_schedules/{}", id), &params)
     }
+
+    /// Like [`SubscriptionSchedule::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        params: UpdateSubscriptionSchedule<'_>,
+        options: RequestOptions,
+    ) -> Response<SubscriptionSchedule> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/subscription_schedules/{}", id), &params, options)
+    }
 }
 
 impl Object for SubscriptionSchedule {
@@ -510,6 +556,11 @@ impl Paginable for ListSubscriptionSchedules<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListSubscriptionSchedules<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `SubscriptionSchedule::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSubscriptionSchedule<'a> {
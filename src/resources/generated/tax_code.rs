@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TaxCodeId;
-use crate::params::{Expand, List, Object, Paginable};
+use crate::params::{Expand, List, Object, Paginable, ReversePaginable};
 
 /// The resource representing a Stripe "TaxProductResourceTaxCode".
 ///
@@ -29,12 +29,31 @@ impl TaxCode {
         client.get_query("/tax_codes", params)
     }
 
+    /// Like [`TaxCode::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTaxCodes<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TaxCode>> {
+        client.get_query_with_options("/tax_codes", params, options)
+    }
+
     /// Retrieves the details of an existing tax code.
     ///
     /// Supply the unique tax code ID and Stripe will return the corresponding tax code information.
     pub fn retrieve(client: &Client, id: &TaxCodeId, expand: &[&str]) -> Response<TaxCode> {
         client.get_query(&format!("/tax_codes/{}", id), Expand { expand })
     }
+
+    /// Like [`TaxCode::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TaxCodeId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<TaxCode> {
+        client.get_query_with_options(&format!("/tax_codes/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for TaxCode {
@@ -91,3 +110,8 @@ impl Paginable for ListTaxCodes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTaxCodes<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
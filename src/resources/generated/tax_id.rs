@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TaxIdId;
-use crate::params::{Deleted, Expand, Expandable, List, Object, Paginable, Timestamp};
+use crate::params::{
+    Deleted, Expand, Expandable, List, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{Account, Application, Customer};
 
 /// The resource representing a Stripe "tax_id".
@@ -65,21 +67,59 @@ impl TaxId {
         client.get_query("/tax_ids", params)
     }
 
+    /// Like [`TaxId::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTaxIds<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TaxId>> {
+        client.get_query_with_options("/tax_ids", params, options)
+    }
+
     /// Creates a new account or customer `tax_id` object.
     pub fn create(client: &Client, params: CreateTaxId<'_>) -> Response<TaxId> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/tax_ids", &params)
     }
 
+    /// Like [`TaxId::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTaxId<'_>,
+        options: RequestOptions,
+    ) -> Response<TaxId> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/tax_ids", &params, options)
+    }
+
     /// Retrieves an account or customer `tax_id` object.
     pub fn retrieve(client: &Client, id: &TaxIdId, expand: &[&str]) -> Response<TaxId> {
         client.get_query(&format!("/tax_ids/{}", id), Expand { expand })
     }
 
+    /// Like [`TaxId::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TaxIdId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<TaxId> {
+        client.get_query_with_options(&format!("/tax_ids/{}", id), Expand { expand }, options)
+    }
+
     /// Deletes an existing account or customer `tax_id` object.
     pub fn delete(client: &Client, id: &TaxIdId) -> Response<Deleted<TaxIdId>> {
         client.delete(&format!("/tax_ids/{}", id))
     }
+
+    /// Like [`TaxId::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &TaxIdId,
+        options: RequestOptions,
+    ) -> Response<Deleted<TaxIdId>> {
+        client.delete_with_options(&format!("/tax_ids/{}", id), options)
+    }
 }
 
 impl Object for TaxId {
@@ -201,6 +241,11 @@ impl Paginable for ListTaxIds<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTaxIds<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateTaxIdOwner {
     /// Account the tax ID belongs to.
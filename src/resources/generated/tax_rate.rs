@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TaxRateId;
-use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 
 /// The resource representing a Stripe "TaxRate".
 ///
@@ -86,22 +88,62 @@ impl TaxRate {
         client.get_query("/tax_rates", params)
     }
 
+    /// Like [`TaxRate::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTaxRates<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TaxRate>> {
+        client.get_query_with_options("/tax_rates", params, options)
+    }
+
     /// Creates a new tax rate.
     pub fn create(client: &Client, params: CreateTaxRate<'_>) -> Response<TaxRate> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/tax_rates", &params)
     }
 
+    /// Like [`TaxRate::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTaxRate<'_>,
+        options: RequestOptions,
+    ) -> Response<TaxRate> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/tax_rates", &params, options)
+    }
+
     /// Retrieves a tax rate with the given ID.
     pub fn retrieve(client: &Client, id: &TaxRateId, expand: &[&str]) -> Response<TaxRate> {
         client.get_query(&format!("/tax_rates/{}", id), Expand { expand })
     }
 
+    /// Like [`TaxRate::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TaxRateId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<TaxRate> {
+        client.get_query_with_options(&format!("/tax_rates/{}", id), Expand { expand }, options)
+    }
+
     /// Updates an existing tax rate.
     pub fn update(client: &Client, id: &TaxRateId, params: UpdateTaxRate<'_>) -> Response<TaxRate> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/tax_rates/{}", id), &params)
     }
+
+    /// Like [`TaxRate::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &TaxRateId,
+        params: UpdateTaxRate<'_>,
+        options: RequestOptions,
+    ) -> Response<TaxRate> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/tax_rates/{}", id), &params, options)
+    }
 }
 
 impl Object for TaxRate {
@@ -249,6 +291,11 @@ impl Paginable for ListTaxRates<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTaxRates<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `TaxRate::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateTaxRate<'a> {
@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TerminalConfigurationId;
-use crate::params::{Expand, Expandable, List, Object, Paginable};
+use crate::params::{Expand, Expandable, List, Object, Paginable, ReversePaginable};
 use crate::resources::File;
 
 /// The resource representing a Stripe "TerminalConfigurationConfiguration".
@@ -51,6 +51,15 @@ impl TerminalConfiguration {
         client.get_query("/terminal/configurations", params)
     }
 
+    /// Like [`TerminalConfiguration::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTerminalConfigurations<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TerminalConfiguration>> {
+        client.get_query_with_options("/terminal/configurations", params, options)
+    }
+
     /// Creates a new `Configuration` object.
     pub fn create(
         client: &Client,
@@ -59,6 +68,16 @@ impl TerminalConfiguration {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/terminal/configurations", &params)
     }
+
+    /// Like [`TerminalConfiguration::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTerminalConfiguration<'_>,
+        options: RequestOptions,
+    ) -> Response<TerminalConfiguration> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/terminal/configurations", &params, options)
+    }
 }
 
 impl Object for TerminalConfiguration {
@@ -231,6 +250,11 @@ impl Paginable for ListTerminalConfigurations<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTerminalConfigurations<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateTerminalConfigurationBbposWiseposE {
     /// A File ID representing an image you would like displayed on the reader.
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::params::{Expand, Object};
 
 /// The resource representing a Stripe "TerminalConnectionToken".
@@ -34,6 +34,16 @@ impl TerminalConnectionToken {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/terminal/connection_tokens", &params)
     }
+
+    /// Like [`TerminalConnectionToken::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTerminalConnectionToken<'_>,
+        options: RequestOptions,
+    ) -> Response<TerminalConnectionToken> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/terminal/connection_tokens", &params, options)
+    }
 }
 
 impl Object for TerminalConnectionToken {
@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TerminalLocationId;
-use crate::params::{Expand, List, Metadata, Object, Paginable};
+use crate::params::{Expand, List, Metadata, Object, Paginable, ReversePaginable};
 use crate::resources::Address;
 
 /// The resource representing a Stripe "TerminalLocationLocation".
@@ -52,6 +52,15 @@ impl TerminalLocation {
         client.get_query("/terminal/locations", params)
     }
 
+    /// Like [`TerminalLocation::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTerminalLocations<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TerminalLocation>> {
+        client.get_query_with_options("/terminal/locations", params, options)
+    }
+
     /// Creates a new `Location` object.
     /// For further details, including which address fields are required in each country, see the [Manage locations](https://stripe.com/docs/terminal/fleet/locations) guide.
     pub fn create(
@@ -61,6 +70,16 @@ impl TerminalLocation {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/terminal/locations", &params)
     }
+
+    /// Like [`TerminalLocation::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTerminalLocation<'_>,
+        options: RequestOptions,
+    ) -> Response<TerminalLocation> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/terminal/locations", &params, options)
+    }
 }
 
 impl Object for TerminalLocation {
@@ -155,6 +174,11 @@ impl Paginable for ListTerminalLocations<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTerminalLocations<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateTerminalLocationAddress {
     /// City, district, suburb, town, or village.
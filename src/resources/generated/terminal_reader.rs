@@ -4,9 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TerminalReaderId;
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, ReversePaginable};
 use crate::resources::{Charge, Currency, PaymentIntent, Refund, SetupIntent, TerminalLocation};
 
 /// The resource representing a Stripe "TerminalReaderReader".
@@ -73,11 +73,30 @@ impl TerminalReader {
         client.get_query("/terminal/readers", params)
     }
 
+    /// Like [`TerminalReader::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTerminalReaders<'_>,
+        options: RequestOptions,
+    ) -> Response<List<TerminalReader>> {
+        client.get_query_with_options("/terminal/readers", params, options)
+    }
+
     /// Creates a new `Reader` object.
     pub fn create(client: &Client, params: CreateTerminalReader<'_>) -> Response<TerminalReader> {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form("/terminal/readers", &params)
     }
+
+    /// Like [`TerminalReader::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTerminalReader<'_>,
+        options: RequestOptions,
+    ) -> Response<TerminalReader> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/terminal/readers", &params, options)
+    }
 }
 
 impl Object for TerminalReader {
@@ -358,6 +377,11 @@ impl Paginable for ListTerminalReaders<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTerminalReaders<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// An enum representing the possible values of an `TerminalReader`'s `device_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, TokenId};
 use crate::params::{Expand, Metadata, Object, Timestamp};
 use crate::resources::{Address, BankAccount, Card, CompanyParams, PersonParams, TokenType};
@@ -53,10 +53,30 @@ impl Token {
         client.post_form("/tokens", &params)
     }
 
+    /// Like [`Token::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateToken<'_>,
+        options: RequestOptions,
+    ) -> Response<Token> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/tokens", &params, options)
+    }
+
     /// Retrieves the token with the given ID.
     pub fn retrieve(client: &Client, id: &TokenId, expand: &[&str]) -> Response<Token> {
         client.get_query(&format!("/tokens/{}", id), Expand { expand })
     }
+
+    /// Like [`Token::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TokenId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Token> {
+        client.get_query_with_options(&format!("/tokens/{}", id), Expand { expand }, options)
+    }
 }
 
 impl Object for Token {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::TopupId;
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{BalanceTransaction, Currency, Source};
 
 /// The resource representing a Stripe "Topup".
@@ -84,6 +86,15 @@ impl Topup {
         client.get_query("/topups", params)
     }
 
+    /// Like [`Topup::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTopups<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Topup>> {
+        client.get_query_with_options("/topups", params, options)
+    }
+
     /// Retrieves the details of a top-up that has previously been created.
     ///
     /// Supply the unique top-up ID that was returned from your previous request, and Stripe will return the corresponding top-up information.
@@ -91,6 +102,16 @@ impl Topup {
         client.get_query(&format!("/topups/{}", id), Expand { expand })
     }
 
+    /// Like [`Topup::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TopupId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Topup> {
+        client.get_query_with_options(&format!("/topups/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the metadata of a top-up.
     ///
     /// Other top-up details are not editable by design.
@@ -98,6 +119,17 @@ impl Topup {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/topups/{}", id), &params)
     }
+
+    /// Like [`Topup::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &TopupId,
+        params: UpdateTopup<'_>,
+        options: RequestOptions,
+    ) -> Response<Topup> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/topups/{}", id), &params, options)
+    }
 }
 
 impl Object for Topup {
@@ -173,6 +205,11 @@ impl Paginable for ListTopups<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTopups<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Topup::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateTopup<'a> {
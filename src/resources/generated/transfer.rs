@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{ChargeId, TransferId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, ReversePaginable, Timestamp,
+};
 use crate::resources::{Account, BalanceTransaction, Charge, Currency, TransferReversal};
 
 /// The resource representing a Stripe "Transfer".
@@ -87,6 +89,15 @@ impl Transfer {
         client.get_query("/transfers", params)
     }
 
+    /// Like [`Transfer::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTransfers<'_>,
+        options: RequestOptions,
+    ) -> Response<List<Transfer>> {
+        client.get_query_with_options("/transfers", params, options)
+    }
+
     /// To send funds from your Stripe account to a connected account, you create a new transfer object.
     ///
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.
@@ -95,6 +106,16 @@ impl Transfer {
         client.post_form("/transfers", &params)
     }
 
+    /// Like [`Transfer::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateTransfer<'_>,
+        options: RequestOptions,
+    ) -> Response<Transfer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/transfers", &params, options)
+    }
+
     /// Retrieves the details of an existing transfer.
     ///
     /// Supply the unique transfer ID from either a transfer creation request or the transfer list, and Stripe will return the corresponding transfer information.
@@ -102,6 +123,16 @@ impl Transfer {
         client.get_query(&format!("/transfers/{}", id), Expand { expand })
     }
 
+    /// Like [`Transfer::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TransferId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<Transfer> {
+        client.get_query_with_options(&format!("/transfers/{}", id), Expand { expand }, options)
+    }
+
     /// Updates the specified transfer by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.  This request accepts only metadata as an argument.
@@ -113,6 +144,17 @@ impl Transfer {
         #[allow(clippy::needless_borrows_for_generic_args)]
         client.post_form(&format!("/transfers/{}", id), &params)
     }
+
+    /// Like [`Transfer::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &TransferId,
+        params: UpdateTransfer<'_>,
+        options: RequestOptions,
+    ) -> Response<Transfer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/transfers/{}", id), &params, options)
+    }
 }
 
 impl Object for Transfer {
@@ -253,6 +295,11 @@ impl Paginable for ListTransfers<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListTransfers<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `Transfer::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateTransfer<'a> {
@@ -4,9 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::WebhookEndpointId;
-use crate::params::{Deleted, Expand, List, Metadata, Object, Paginable, Timestamp};
+use crate::params::{
+    Deleted, Expand, List, Metadata, Object, Paginable, ReversePaginable, Timestamp,
+};
 use crate::resources::{ApiVersion, WebhookEndpointStatus};
 
 /// The resource representing a Stripe "NotificationWebhookEndpoint".
@@ -81,6 +83,15 @@ impl WebhookEndpoint {
         client.get_query("/webhook_endpoints", params)
     }
 
+    /// Like [`WebhookEndpoint::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListWebhookEndpoints<'_>,
+        options: RequestOptions,
+    ) -> Response<List<WebhookEndpoint>> {
+        client.get_query_with_options("/webhook_endpoints", params, options)
+    }
+
     /// A webhook endpoint must have a `url` and a list of `enabled_events`.
     ///
     /// You may optionally specify the Boolean `connect` parameter.
@@ -91,6 +102,16 @@ impl WebhookEndpoint {
         client.post_form("/webhook_endpoints", &params)
     }
 
+    /// Like [`WebhookEndpoint::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateWebhookEndpoint<'_>,
+        options: RequestOptions,
+    ) -> Response<WebhookEndpoint> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options("/webhook_endpoints", &params, options)
+    }
+
     /// Retrieves the webhook endpoint with the given ID.
     pub fn retrieve(
         client: &Client,
@@ -100,6 +121,20 @@ impl WebhookEndpoint {
         client.get_query(&format!("/webhook_endpoints/{}", id), Expand { expand })
     }
 
+    /// Like [`WebhookEndpoint::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &WebhookEndpointId,
+        expand: &[&str],
+        options: RequestOptions,
+    ) -> Response<WebhookEndpoint> {
+        client.get_query_with_options(
+            &format!("/webhook_endpoints/{}", id),
+            Expand { expand },
+            options,
+        )
+    }
+
     /// Updates the webhook endpoint.
     ///
     /// You may edit the `url`, the list of `enabled_events`, and the status of your endpoint.
@@ -112,10 +147,30 @@ impl WebhookEndpoint {
         client.post_form(&format!("/webhook_endpoints/{}", id), &params)
     }
 
+    /// Like [`WebhookEndpoint::update`], but with [`RequestOptions`] for this request only.
+    pub fn update_with_options(
+        client: &Client,
+        id: &WebhookEndpointId,
+        params: UpdateWebhookEndpoint<'_>,
+        options: RequestOptions,
+    ) -> Response<WebhookEndpoint> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form_with_options(&format!("/webhook_endpoints/{}", id), &params, options)
+    }
+
     /// You can also delete webhook endpoints via the [webhook endpoint management](https://dashboard.stripe.com/account/webhooks) page of the Stripe dashboard.
     pub fn delete(client: &Client, id: &WebhookEndpointId) -> Response<Deleted<WebhookEndpointId>> {
         client.delete(&format!("/webhook_endpoints/{}", id))
     }
+
+    /// Like [`WebhookEndpoint::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &WebhookEndpointId,
+        options: RequestOptions,
+    ) -> Response<Deleted<WebhookEndpointId>> {
+        client.delete_with_options(&format!("/webhook_endpoints/{}", id), options)
+    }
 }
 
 impl Object for WebhookEndpoint {
@@ -224,6 +279,11 @@ impl Paginable for ListWebhookEndpoints<'_> {
         self.starting_after = Some(item.id());
     }
 }
+impl ReversePaginable for ListWebhookEndpoints<'_> {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id());
+    }
+}
 /// The parameters for `WebhookEndpoint::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateWebhookEndpoint<'a> {
@@ -1,8 +1,8 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Metadata, SearchList, Timestamp};
+use crate::params::{Metadata, PaginableSearch, SearchList, Timestamp};
 use crate::resources::{CollectionMethod, Invoice};
 
 #[deprecated(since = "0.12.0")]
@@ -13,7 +13,16 @@ impl Invoice {
     ///
     /// For more details see <https://stripe.com/docs/api#upcoming_invoice>.
     pub fn upcoming(client: &Client, params: RetrieveUpcomingInvoice) -> Response<Invoice> {
-        client.get_query("/invoices/upcoming", &params)
+        Self::upcoming_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Invoice::upcoming`], but with [`RequestOptions`] for this request only.
+    pub fn upcoming_with_options(
+        client: &Client,
+        params: RetrieveUpcomingInvoice,
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        client.get_query_with_options("/invoices/upcoming", &params, options)
     }
 
     /// Finalizes an invoice.
@@ -24,28 +33,69 @@ impl Invoice {
         invoice_id: &InvoiceId,
         params: FinalizeInvoiceParams,
     ) -> Response<Invoice> {
-        client.post_form(&format!("/invoices/{}/finalize", invoice_id), params)
+        Self::finalize_with_options(client, invoice_id, params, RequestOptions::default())
+    }
+
+    /// Like [`Invoice::finalize`], but with [`RequestOptions`] for this request only.
+    pub fn finalize_with_options(
+        client: &Client,
+        invoice_id: &InvoiceId,
+        params: FinalizeInvoiceParams,
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        client.post_form_with_options(
+            &format!("/invoices/{}/finalize", invoice_id),
+            params,
+            options,
+        )
     }
 
     /// Pays an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api#pay_invoice.>.
     pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
-        client.post(&format!("/invoices/{}/pay", invoice_id))
+        Self::pay_with_options(client, invoice_id, RequestOptions::default())
+    }
+
+    /// Like [`Invoice::pay`], but with [`RequestOptions`] for this request only.
+    pub fn pay_with_options(
+        client: &Client,
+        invoice_id: &InvoiceId,
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        client.post_with_options(&format!("/invoices/{}/pay", invoice_id), options)
     }
 
     /// Searches for an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api/invoices/search>.
     pub fn search(client: &Client, params: InvoiceSearchParams) -> Response<SearchList<Invoice>> {
-        client.get_query("/invoices/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Invoice::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: InvoiceSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Invoice>> {
+        client.get_query_with_options("/invoices/search", params, options)
     }
 
     /// Voids an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api/invoices/void>.
     pub fn void(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
-        client.post(&format!("/invoices/{}/void", invoice_id))
+        Self::void_with_options(client, invoice_id, RequestOptions::default())
+    }
+
+    /// Like [`Invoice::void`], but with [`RequestOptions`] for this request only.
+    pub fn void_with_options(
+        client: &Client,
+        invoice_id: &InvoiceId,
+        options: RequestOptions,
+    ) -> Response<Invoice> {
+        client.post_with_options(&format!("/invoices/{}/void", invoice_id), options)
     }
 }
 
@@ -103,7 +153,7 @@ pub struct InvoiceSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -113,6 +163,12 @@ impl<'a> InvoiceSearchParams<'a> {
     }
 }
 
+impl<'a> PaginableSearch for InvoiceSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct FinalizeInvoiceParams {
     #[serde(skip_serializing_if = "Option::is_none")]
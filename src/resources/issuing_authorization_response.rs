@@ -0,0 +1,236 @@
+use serde_json::{json, Value};
+
+use crate::error::IssuingAuthorizationError;
+use crate::generated::core::version::VERSION;
+use crate::params::Metadata;
+use crate::resources::IssuingAuthorization;
+#[cfg(feature = "events")]
+use crate::resources::{Event, EventType, FromEventObject};
+
+#[cfg(feature = "events")]
+impl IssuingAuthorization {
+    /// Extract the authorization from an `issuing_authorization.request` event.
+    ///
+    /// These events are sent synchronously, and must be answered with an
+    /// [`IssuingAuthorizationDecision`] within two seconds.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the event is of another type, or if its object
+    /// is not an authorization.
+    pub fn from_request_event(event: Event) -> Result<Self, IssuingAuthorizationError> {
+        if event.type_ != EventType::IssuingAuthorizationRequest {
+            return Err(IssuingAuthorizationError::UnexpectedEvent(event.type_));
+        }
+        Self::from_event_object(event.data.object).map_err(|object| {
+            IssuingAuthorizationError::UnexpectedObject(object.object().to_string())
+        })
+    }
+}
+
+/// The decision to approve or decline an authorization, sent as the response to an
+/// `issuing_authorization.request` webhook.
+///
+/// ```
+/// use stripe::{IssuingAuthorization, IssuingAuthorizationDecision};
+///
+/// # fn run(authorization: IssuingAuthorization) -> Result<(), stripe::IssuingAuthorizationError> {
+/// let decision = if authorization.amount > 10_000 {
+///     IssuingAuthorizationDecision::decline().metadata("reason", "over limit")
+/// } else {
+///     IssuingAuthorizationDecision::approve()
+/// };
+///
+/// let response = decision.respond(&authorization)?;
+/// // reply to the webhook with a `200 OK` status, these headers and this body
+/// let (headers, body) = (response.headers(), response.body);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IssuingAuthorizationDecision {
+    /// Whether the authorization is approved.
+    pub approved: bool,
+    /// The amount to hold, when approving a smaller amount than requested.
+    pub amount: Option<i64>,
+    /// The metadata to set on the authorization.
+    pub metadata: Metadata,
+}
+
+/// The response to an `issuing_authorization.request` webhook, to be sent with a
+/// `200 OK` status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuingAuthorizationResponse {
+    /// The JSON body of the response.
+    pub body: String,
+}
+
+impl IssuingAuthorizationDecision {
+    /// Approve the authorization.
+    pub fn approve() -> Self {
+        Self { approved: true, ..Default::default() }
+    }
+
+    /// Decline the authorization.
+    pub fn decline() -> Self {
+        Self { approved: false, ..Default::default() }
+    }
+
+    /// Set the amount to hold, in the currency of the pending request.
+    ///
+    /// This is only allowed for approved requests whose amount is controllable, and can't
+    /// exceed the amount of the pending request.
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set a metadata key on the authorization.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the response for the pending request of an authorization.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the authorization has no pending request, or if
+    /// an amount is set which the pending request doesn't allow.
+    pub fn respond(
+        &self,
+        authorization: &IssuingAuthorization,
+    ) -> Result<IssuingAuthorizationResponse, IssuingAuthorizationError> {
+        let pending = authorization
+            .pending_request
+            .as_ref()
+            .ok_or(IssuingAuthorizationError::NoPendingRequest)?;
+
+        let mut body = json!({ "approved": self.approved });
+        if let Some(amount) = self.amount {
+            if !self.approved || !pending.is_amount_controllable {
+                return Err(IssuingAuthorizationError::AmountNotControllable);
+            }
+            if amount <= 0 || amount > pending.amount {
+                return Err(IssuingAuthorizationError::InvalidAmount(amount));
+            }
+            body["amount"] = json!(amount);
+        }
+        if !self.metadata.is_empty() {
+            body["metadata"] = json!(self.metadata);
+        }
+
+        Ok(IssuingAuthorizationResponse { body: Value::to_string(&body) })
+    }
+}
+
+impl IssuingAuthorizationResponse {
+    /// The status code of the response.
+    pub const STATUS: u16 = 200;
+
+    /// The headers of the response.
+    pub fn headers(&self) -> [(&'static str, &'static str); 2] {
+        [("Content-Type", "application/json"), ("Stripe-Version", VERSION.as_str())]
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<IssuingAuthorizationResponse> for http::Response<String> {
+    fn from(response: IssuingAuthorizationResponse) -> Self {
+        let mut builder = http::Response::builder().status(IssuingAuthorizationResponse::STATUS);
+        for (name, value) in response.headers() {
+            builder = builder.header(name, value);
+        }
+        builder.body(response.body).expect("the response is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{IssuingAuthorizationDecision, VERSION};
+    use crate::error::IssuingAuthorizationError;
+    use crate::{IssuingAuthorization, IssuingAuthorizationPendingRequest};
+
+    fn authorization(is_amount_controllable: bool) -> IssuingAuthorization {
+        IssuingAuthorization {
+            id: "iauth_123".parse().unwrap(),
+            pending_request: Some(IssuingAuthorizationPendingRequest {
+                amount: 5000,
+                is_amount_controllable,
+                merchant_amount: 5000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn respond() {
+        let authorization = authorization(true);
+
+        let response = IssuingAuthorizationDecision::approve()
+            .amount(2000)
+            .metadata("order", "123")
+            .respond(&authorization)
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "approved": true, "amount": 2000, "metadata": { "order": "123" } })
+        );
+        assert_eq!(response.headers()[1], ("Stripe-Version", VERSION.as_str()));
+
+        let response = IssuingAuthorizationDecision::decline().respond(&authorization).unwrap();
+        assert_eq!(response.body, r#"{"approved":false}"#);
+
+        let res = IssuingAuthorizationDecision::approve().amount(0).respond(&authorization);
+        assert!(matches!(res, Err(IssuingAuthorizationError::InvalidAmount(0))));
+
+        let res = IssuingAuthorizationDecision::approve().amount(5001).respond(&authorization);
+        assert!(matches!(res, Err(IssuingAuthorizationError::InvalidAmount(5001))));
+
+        let response =
+            IssuingAuthorizationDecision::approve().amount(5000).respond(&authorization).unwrap();
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body, json!({ "approved": true, "amount": 5000 }));
+    }
+
+    #[test]
+    fn amount_not_controllable() {
+        let res =
+            IssuingAuthorizationDecision::approve().amount(2000).respond(&authorization(false));
+        assert!(matches!(res, Err(IssuingAuthorizationError::AmountNotControllable)));
+
+        let mut authorization = authorization(true);
+        authorization.pending_request = None;
+        let res = IssuingAuthorizationDecision::approve().respond(&authorization);
+        assert!(matches!(res, Err(IssuingAuthorizationError::NoPendingRequest)));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn from_request_event() {
+        use crate::{EventObject, EventType, TestEvent};
+
+        let event = TestEvent::new(
+            EventType::IssuingAuthorizationRequest,
+            EventObject::IssuingAuthorization(authorization(true)),
+        )
+        .into_event();
+        let authorization = IssuingAuthorization::from_request_event(event).unwrap();
+        assert_eq!(authorization.id.as_str(), "iauth_123");
+
+        let event = TestEvent::new(
+            EventType::IssuingAuthorizationCreated,
+            EventObject::IssuingAuthorization(authorization),
+        )
+        .into_event();
+        let res = IssuingAuthorization::from_request_event(event);
+        assert!(matches!(
+            res,
+            Err(IssuingAuthorizationError::UnexpectedEvent(EventType::IssuingAuthorizationCreated))
+        ));
+    }
+}
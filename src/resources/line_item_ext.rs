@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, InvoiceId};
 use crate::resources::{Currency, InvoiceLineItem};
 
@@ -9,7 +9,16 @@ impl InvoiceLineItem {
     ///
     /// For more details see <https://stripe.com/docs/api#invoice_line_item_object>.
     pub fn create(client: &Client, params: CreateInvoiceLineItem<'_>) -> Response<InvoiceLineItem> {
-        client.post_form("/invoiceitems", &params)
+        Self::create_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`InvoiceLineItem::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: CreateInvoiceLineItem<'_>,
+        options: RequestOptions,
+    ) -> Response<InvoiceLineItem> {
+        client.post_form_with_options("/invoiceitems", &params, options)
     }
 }
 
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::params::Expand;
 use crate::resources::LoginLink;
 use crate::AccountId;
@@ -18,10 +18,24 @@ pub struct CreateLoginLink<'a> {
 
 impl LoginLink {
     pub fn create(client: &Client, id: &AccountId, redirect_url: &str) -> Response<Self> {
+        Self::create_with_options(client, id, redirect_url, RequestOptions::default())
+    }
+
+    /// Like [`LoginLink::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        id: &AccountId,
+        redirect_url: &str,
+        options: RequestOptions,
+    ) -> Response<Self> {
         let create_login_link =
             CreateLoginLink { expand: &[], redirect_url: Some(redirect_url.to_string()) };
 
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.post_form(&format!("/accounts/{}/login_links", id), &create_login_link)
+        client.post_form_with_options(
+            &format!("/accounts/{}/login_links", id),
+            &create_login_link,
+            options,
+        )
     }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
-use crate::params::{Expandable, Metadata, SearchList};
+use crate::client::{Client, RequestOptions, Response};
+use crate::params::{Expandable, Metadata, PaginableSearch, SearchList};
 use crate::resources::{Currency, PaymentSource, Shipping};
 use crate::{PaymentIntent, PaymentIntentCancellationReason};
 
@@ -14,7 +14,21 @@ impl PaymentIntent {
         payment_intent_id: &str,
         params: PaymentIntentConfirmParams<'_>,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
+        Self::confirm_with_options(client, payment_intent_id, params, RequestOptions::default())
+    }
+
+    /// Like [`PaymentIntent::confirm`], but with [`RequestOptions`] for this request only.
+    pub fn confirm_with_options(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentConfirmParams<'_>,
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        client.post_form_with_options(
+            &format!("/payment_intents/{}/confirm", payment_intent_id),
+            params,
+            options,
+        )
     }
 
     /// Capture the funds of an existing uncaptured PaymentIntent where required_action="requires_capture".
@@ -25,7 +39,21 @@ impl PaymentIntent {
         payment_intent_id: &str,
         params: CapturePaymentIntent,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/capture", payment_intent_id), params)
+        Self::capture_with_options(client, payment_intent_id, params, RequestOptions::default())
+    }
+
+    /// Like [`PaymentIntent::capture`], but with [`RequestOptions`] for this request only.
+    pub fn capture_with_options(
+        client: &Client,
+        payment_intent_id: &str,
+        params: CapturePaymentIntent,
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        client.post_form_with_options(
+            &format!("/payment_intents/{}/capture", payment_intent_id),
+            params,
+            options,
+        )
     }
 
     /// A PaymentIntent object can be canceled when it is in one of these statuses: requires_source, requires_capture, requires_confirmation, requires_source_action.
@@ -36,7 +64,21 @@ impl PaymentIntent {
         payment_intent_id: &str,
         params: CancelPaymentIntent,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
+        Self::cancel_with_options(client, payment_intent_id, params, RequestOptions::default())
+    }
+
+    /// Like [`PaymentIntent::cancel`], but with [`RequestOptions`] for this request only.
+    pub fn cancel_with_options(
+        client: &Client,
+        payment_intent_id: &str,
+        params: CancelPaymentIntent,
+        options: RequestOptions,
+    ) -> Response<PaymentIntent> {
+        client.post_form_with_options(
+            &format!("/payment_intents/{}/cancel", payment_intent_id),
+            params,
+            options,
+        )
     }
 
     /// Searches for a payment intent.
@@ -46,7 +88,16 @@ impl PaymentIntent {
         client: &Client,
         params: PaymentIntentSearchParams,
     ) -> Response<SearchList<PaymentIntent>> {
-        client.get_query("/payment_intents/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`PaymentIntent::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: PaymentIntentSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<PaymentIntent>> {
+        client.get_query_with_options("/payment_intents/search", params, options)
     }
 }
 /// The resource representing a Stripe PaymentError object.
@@ -222,7 +273,7 @@ pub struct PaymentIntentSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -231,3 +282,9 @@ impl<'a> PaymentIntentSearchParams<'a> {
         PaymentIntentSearchParams { query: String::new(), limit: None, page: None, expand: &[] }
     }
 }
+
+impl<'a> PaginableSearch for PaymentIntentSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
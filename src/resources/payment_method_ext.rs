@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{CustomerId, PaymentMethodId};
 use crate::resources::PaymentMethod;
 
@@ -21,13 +21,36 @@ impl PaymentMethod {
         payment_method_id: &PaymentMethodId,
         params: AttachPaymentMethod,
     ) -> Response<PaymentMethod> {
-        client.post_form(&format!("/payment_methods/{}/attach", payment_method_id), params)
+        Self::attach_with_options(client, payment_method_id, params, RequestOptions::default())
+    }
+
+    /// Like [`PaymentMethod::attach`], but with [`RequestOptions`] for this request only.
+    pub fn attach_with_options(
+        client: &Client,
+        payment_method_id: &PaymentMethodId,
+        params: AttachPaymentMethod,
+        options: RequestOptions,
+    ) -> Response<PaymentMethod> {
+        client.post_form_with_options(
+            &format!("/payment_methods/{}/attach", payment_method_id),
+            params,
+            options,
+        )
     }
 
     /// Detach a PaymentMethod from a Customer
     ///
     /// For more details see <https://stripe.com/docs/api/payment_methods/detach>.
     pub fn detach(client: &Client, payment_method_id: &PaymentMethodId) -> Response<PaymentMethod> {
-        client.post(&format!("/payment_methods/{}/detach", payment_method_id))
+        Self::detach_with_options(client, payment_method_id, RequestOptions::default())
+    }
+
+    /// Like [`PaymentMethod::detach`], but with [`RequestOptions`] for this request only.
+    pub fn detach_with_options(
+        client: &Client,
+        payment_method_id: &PaymentMethodId,
+        options: RequestOptions,
+    ) -> Response<PaymentMethod> {
+        client.post_with_options(&format!("/payment_methods/{}/detach", payment_method_id), options)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::{PayoutDestinationId, PayoutId};
 use crate::params::Object;
 use crate::resources::{BankAccount, Card, Payout};
@@ -10,7 +10,16 @@ impl Payout {
     ///
     /// For more details see <https://stripe.com/docs/api/payouts/cancel>.
     pub fn cancel(client: &Client, id: &PayoutId) -> Response<Payout> {
-        client.post(&format!("/payouts/{}/cancel", id))
+        Self::cancel_with_options(client, id, RequestOptions::default())
+    }
+
+    /// Like [`Payout::cancel`], but with [`RequestOptions`] for this request only.
+    pub fn cancel_with_options(
+        client: &Client,
+        id: &PayoutId,
+        options: RequestOptions,
+    ) -> Response<Payout> {
+        client.post_with_options(&format!("/payouts/{}/cancel", id), options)
     }
 }
 
@@ -1,7 +1,7 @@
 use serde::Serialize;
 
-use crate::params::SearchList;
-use crate::{Client, Price, Response};
+use crate::params::{PaginableSearch, SearchList};
+use crate::{Client, Price, RequestOptions, Response};
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PriceSearchParams<'a> {
@@ -9,7 +9,7 @@ pub struct PriceSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -19,11 +19,26 @@ impl<'a> PriceSearchParams<'a> {
     }
 }
 
+impl<'a> PaginableSearch for PriceSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
+
 impl Price {
     /// Searches for a price.
     ///
     /// For more details see <https://stripe.com/docs/api/prices/search>.
     pub fn search(client: &Client, params: PriceSearchParams) -> Response<SearchList<Price>> {
-        client.get_query("/prices/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Price::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: PriceSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Price>> {
+        client.get_query_with_options("/prices/search", params, options)
     }
 }
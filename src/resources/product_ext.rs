@@ -1,7 +1,7 @@
 use serde::Serialize;
 
-use crate::params::SearchList;
-use crate::{Client, Product, Response};
+use crate::params::{PaginableSearch, SearchList};
+use crate::{Client, Product, RequestOptions, Response};
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct ProductSearchParams<'a> {
@@ -9,7 +9,7 @@ pub struct ProductSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -19,11 +19,26 @@ impl<'a> ProductSearchParams<'a> {
     }
 }
 
+impl<'a> PaginableSearch for ProductSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
+
 impl Product {
     /// Searches for a product.
     ///
     /// For more details see <https://stripe.com/docs/api/products/search>.
     pub fn search(client: &Client, params: ProductSearchParams) -> Response<SearchList<Product>> {
-        client.get_query("/products/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Product::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: ProductSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Product>> {
+        client.get_query_with_options("/products/search", params, options)
     }
 }
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::resources::{Currency, PromotionCode};
 
 #[derive(Copy, Clone, Debug, serde::Serialize)]
@@ -102,6 +102,15 @@ impl<'a> CreatePromotionCode<'a> {
     ///
     /// You can optionally restrict the code to a specific customer, redemption limit, and expiration date.
     pub fn send(&self, client: &Client) -> Response<PromotionCode> {
-        client.post_form("/promotion_codes", self)
+        self.send_with_options(client, RequestOptions::default())
+    }
+
+    /// Like [`CreatePromotionCode::send`], but with [`RequestOptions`] for this request only.
+    pub fn send_with_options(
+        &self,
+        client: &Client,
+        options: RequestOptions,
+    ) -> Response<PromotionCode> {
+        client.post_form_with_options("/promotion_codes", self, options)
     }
 }
@@ -0,0 +1,513 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Not;
+
+#[cfg(feature = "products")]
+use crate::resources::PriceType;
+use crate::resources::{ChargeStatus, Currency, PaymentIntentStatus};
+#[cfg(feature = "billing")]
+use crate::resources::{InvoiceStatus, SubscriptionStatus};
+
+/// A field of the resource `R` which can be searched with values of type `V`.
+///
+/// Fields are obtained from the search query builder of their resource, such as
+/// [`CustomerSearch`], which only exposes the fields supported by the search api.
+///
+/// For more details see <https://stripe.com/docs/search#search-query-language>.
+pub struct SearchField<R, V> {
+    name: String,
+    _marker: PhantomData<fn() -> (R, V)>,
+}
+
+/// A single condition of a search query, such as `email:'jane@example.com'`.
+///
+/// Clauses are negated with the `!` operator, and combined into a query with
+/// [`SearchClause::and`] or [`SearchClause::or`].
+pub struct SearchClause<R> {
+    negated: bool,
+    condition: String,
+    _marker: PhantomData<fn() -> R>,
+}
+
+/// A search query matching the objects which satisfy all of its clauses.
+///
+/// The search api doesn't allow `AND` and `OR` to be mixed in a query, so this
+/// query can only be extended with more `AND` clauses.
+pub struct SearchAll<R> {
+    clauses: Vec<SearchClause<R>>,
+}
+
+/// A search query matching the objects which satisfy any of its clauses.
+///
+/// The search api doesn't allow `AND` and `OR` to be mixed in a query, so this
+/// query can only be extended with more `OR` clauses.
+pub struct SearchAny<R> {
+    clauses: Vec<SearchClause<R>>,
+}
+
+impl<R, V> SearchField<R, V> {
+    fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), _marker: PhantomData }
+    }
+
+    fn clause(&self, operator: &str, value: impl fmt::Display) -> SearchClause<R> {
+        SearchClause {
+            negated: false,
+            condition: format!("{}{}{}", self.name, operator, value),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Match the objects where this field has no value.
+    pub fn is_null(&self) -> SearchClause<R> {
+        self.clause(":", "null")
+    }
+}
+
+impl<R> SearchField<R, String> {
+    /// Match the objects where this field is equal to the value.
+    pub fn eq(&self, value: impl AsRef<str>) -> SearchClause<R> {
+        self.clause(":", quote(value.as_ref()))
+    }
+
+    /// Match the objects where this field contains the value, which must be
+    /// at least 3 characters long.
+    pub fn contains(&self, value: impl AsRef<str>) -> SearchClause<R> {
+        self.clause("~", quote(value.as_ref()))
+    }
+}
+
+impl<R> SearchField<R, i64> {
+    /// Match the objects where this field is equal to the value.
+    pub fn eq(&self, value: i64) -> SearchClause<R> {
+        self.clause(":", value)
+    }
+
+    /// Match the objects where this field is greater than the value.
+    pub fn gt(&self, value: i64) -> SearchClause<R> {
+        self.clause(">", value)
+    }
+
+    /// Match the objects where this field is greater than or equal to the value.
+    pub fn gte(&self, value: i64) -> SearchClause<R> {
+        self.clause(">=", value)
+    }
+
+    /// Match the objects where this field is less than the value.
+    pub fn lt(&self, value: i64) -> SearchClause<R> {
+        self.clause("<", value)
+    }
+
+    /// Match the objects where this field is less than or equal to the value.
+    pub fn lte(&self, value: i64) -> SearchClause<R> {
+        self.clause("<=", value)
+    }
+}
+
+impl<R> SearchField<R, bool> {
+    /// Match the objects where this field is equal to the value.
+    pub fn eq(&self, value: bool) -> SearchClause<R> {
+        self.clause(":", quote(&value.to_string()))
+    }
+}
+
+macro_rules! enum_fields {
+    ($($(#[$attr:meta])* $enum:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<R> SearchField<R, $enum> {
+                /// Match the objects where this field is equal to the value.
+                pub fn eq(&self, value: $enum) -> SearchClause<R> {
+                    self.clause(":", quote(&value.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+enum_fields!(
+    ChargeStatus,
+    Currency,
+    PaymentIntentStatus,
+    #[cfg(feature = "billing")]
+    InvoiceStatus,
+    #[cfg(feature = "billing")]
+    SubscriptionStatus,
+    #[cfg(feature = "products")]
+    PriceType,
+);
+
+impl<R> SearchClause<R> {
+    /// Combine this clause with another, matching the objects which satisfy both.
+    pub fn and(self, clause: SearchClause<R>) -> SearchAll<R> {
+        SearchAll { clauses: vec![self, clause] }
+    }
+
+    /// Combine this clause with another, matching the objects which satisfy either.
+    pub fn or(self, clause: SearchClause<R>) -> SearchAny<R> {
+        SearchAny { clauses: vec![self, clause] }
+    }
+}
+
+impl<R> Not for SearchClause<R> {
+    type Output = Self;
+
+    fn not(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+}
+
+impl<R> SearchAll<R> {
+    /// Add a clause which the objects must also satisfy.
+    pub fn and(mut self, clause: SearchClause<R>) -> Self {
+        self.clauses.push(clause);
+        self
+    }
+}
+
+impl<R> SearchAny<R> {
+    /// Add a clause which the objects may satisfy instead.
+    pub fn or(mut self, clause: SearchClause<R>) -> Self {
+        self.clauses.push(clause);
+        self
+    }
+}
+
+impl<R> FromIterator<SearchClause<R>> for SearchAll<R> {
+    fn from_iter<I: IntoIterator<Item = SearchClause<R>>>(iter: I) -> Self {
+        Self { clauses: iter.into_iter().collect() }
+    }
+}
+
+impl<R> FromIterator<SearchClause<R>> for SearchAny<R> {
+    fn from_iter<I: IntoIterator<Item = SearchClause<R>>>(iter: I) -> Self {
+        Self { clauses: iter.into_iter().collect() }
+    }
+}
+
+impl<R> fmt::Display for SearchClause<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negated {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.condition)
+    }
+}
+
+impl<R> fmt::Display for SearchAll<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        join(f, &self.clauses, " AND ")
+    }
+}
+
+impl<R> fmt::Display for SearchAny<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        join(f, &self.clauses, " OR ")
+    }
+}
+
+impl<R, V> fmt::Debug for SearchField<R, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SearchField").field(&self.name).finish()
+    }
+}
+
+impl<R> fmt::Debug for SearchClause<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SearchClause").field(&self.to_string()).finish()
+    }
+}
+
+impl<R> fmt::Debug for SearchAll<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SearchAll").field(&self.to_string()).finish()
+    }
+}
+
+impl<R> fmt::Debug for SearchAny<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SearchAny").field(&self.to_string()).finish()
+    }
+}
+
+impl<R> From<SearchClause<R>> for String {
+    fn from(clause: SearchClause<R>) -> Self {
+        clause.to_string()
+    }
+}
+
+impl<R> From<SearchAll<R>> for String {
+    fn from(query: SearchAll<R>) -> Self {
+        query.to_string()
+    }
+}
+
+impl<R> From<SearchAny<R>> for String {
+    fn from(query: SearchAny<R>) -> Self {
+        query.to_string()
+    }
+}
+
+fn join<R>(
+    f: &mut fmt::Formatter<'_>,
+    clauses: &[SearchClause<R>],
+    separator: &str,
+) -> fmt::Result {
+    for (i, clause) in clauses.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{}", clause)?;
+    }
+    Ok(())
+}
+
+/// Quote a string value, escaping the quotes and backslashes it contains.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        if c == '\'' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+macro_rules! search_fields {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $($(#[$field_attr:meta])* $field:ident: $type:ty = $path:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name;
+
+        impl $name {
+            /// A key of the metadata.
+            pub fn metadata(key: &str) -> SearchField<$name, String> {
+                SearchField::new(format!("metadata[{}]", quote(key)))
+            }
+
+            $(
+                $(#[$field_attr])*
+                pub fn $field() -> SearchField<$name, $type> {
+                    SearchField::new($path)
+                }
+            )*
+        }
+    };
+}
+
+search_fields! {
+    /// The fields which can be used to search customers.
+    ///
+    /// ```
+    /// use stripe::{CustomerSearch, CustomerSearchParams};
+    ///
+    /// let query = CustomerSearch::email()
+    ///     .eq("jane@example.com")
+    ///     .and(CustomerSearch::metadata("order_id").eq("123"));
+    /// assert_eq!(query.to_string(), "email:'jane@example.com' AND metadata['order_id']:'123'");
+    ///
+    /// let params = CustomerSearchParams { query: query.into(), ..Default::default() };
+    /// ```
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-customers>.
+    CustomerSearch {
+        /// The time at which the customer was created.
+        created: i64 = "created",
+        /// The email of the customer.
+        email: String = "email",
+        /// The name of the customer.
+        name: String = "name",
+        /// The phone number of the customer.
+        phone: String = "phone",
+    }
+}
+
+search_fields! {
+    /// The fields which can be used to search charges.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-charges>.
+    ChargeSearch {
+        /// The amount of the charge.
+        amount: i64 = "amount",
+        /// The postal code of the billing address.
+        billing_postal_code: String = "billing_details.address.postal_code",
+        /// The time at which the charge was created.
+        created: i64 = "created",
+        /// The currency of the charge.
+        currency: Currency = "currency",
+        /// The id of the customer of the charge.
+        customer: String = "customer",
+        /// Whether the charge was disputed.
+        disputed: bool = "disputed",
+        /// The brand of the card.
+        card_brand: String = "payment_method_details.card.brand",
+        /// The expiration month of the card.
+        card_exp_month: i64 = "payment_method_details.card.exp_month",
+        /// The expiration year of the card.
+        card_exp_year: i64 = "payment_method_details.card.exp_year",
+        /// The fingerprint of the card.
+        card_fingerprint: String = "payment_method_details.card.fingerprint",
+        /// The last four digits of the card.
+        card_last4: String = "payment_method_details.card.last4",
+        /// Whether the charge was refunded.
+        refunded: bool = "refunded",
+        /// The status of the charge.
+        status: ChargeStatus = "status",
+    }
+}
+
+search_fields! {
+    /// The fields which can be used to search payment intents.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-payment-intents>.
+    PaymentIntentSearch {
+        /// The amount of the payment intent.
+        amount: i64 = "amount",
+        /// The time at which the payment intent was created.
+        created: i64 = "created",
+        /// The currency of the payment intent.
+        currency: Currency = "currency",
+        /// The id of the customer of the payment intent.
+        customer: String = "customer",
+        /// The status of the payment intent.
+        status: PaymentIntentStatus = "status",
+    }
+}
+
+#[cfg(feature = "billing")]
+search_fields! {
+    /// The fields which can be used to search invoices.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-invoices>.
+    InvoiceSearch {
+        /// The time at which the invoice was created.
+        created: i64 = "created",
+        /// The currency of the invoice.
+        currency: Currency = "currency",
+        /// The id of the customer of the invoice.
+        customer: String = "customer",
+        /// The code of the error which occurred when the invoice was last finalized.
+        last_finalization_error_code: String = "last_finalization_error_code",
+        /// The type of the error which occurred when the invoice was last finalized.
+        last_finalization_error_type: String = "last_finalization_error_type",
+        /// The number of the invoice.
+        number: String = "number",
+        /// The receipt number of the invoice.
+        receipt_number: String = "receipt_number",
+        /// The status of the invoice.
+        status: InvoiceStatus = "status",
+        /// The id of the subscription of the invoice.
+        subscription: String = "subscription",
+        /// The total of the invoice.
+        total: i64 = "total",
+    }
+}
+
+#[cfg(feature = "billing")]
+search_fields! {
+    /// The fields which can be used to search subscriptions.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-subscriptions>.
+    SubscriptionSearch {
+        /// The time at which the subscription was created.
+        created: i64 = "created",
+        /// The status of the subscription.
+        status: SubscriptionStatus = "status",
+    }
+}
+
+#[cfg(feature = "products")]
+search_fields! {
+    /// The fields which can be used to search prices.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-prices>.
+    PriceSearch {
+        /// Whether the price can be used for new purchases.
+        active: bool = "active",
+        /// The currency of the price.
+        currency: Currency = "currency",
+        /// The lookup key of the price.
+        lookup_key: String = "lookup_key",
+        /// The id of the product of the price.
+        product: String = "product",
+        /// The type of the price.
+        type_: PriceType = "type",
+    }
+}
+
+#[cfg(feature = "products")]
+search_fields! {
+    /// The fields which can be used to search products.
+    ///
+    /// For more details see <https://stripe.com/docs/search#query-fields-for-products>.
+    ProductSearch {
+        /// Whether the product can be purchased.
+        active: bool = "active",
+        /// The description of the product.
+        description: String = "description",
+        /// The name of the product.
+        name: String = "name",
+        /// Whether the product is a shipped good.
+        shippable: bool = "shippable",
+        /// The url of the product.
+        url: String = "url",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChargeSearch, CustomerSearch, PaymentIntentSearch, SearchAny};
+    use crate::{ChargeStatus, Currency};
+
+    #[test]
+    fn clauses() {
+        assert_eq!(
+            CustomerSearch::email().eq("jane@example.com").to_string(),
+            "email:'jane@example.com'"
+        );
+        assert_eq!(CustomerSearch::name().contains("Jane").to_string(), "name~'Jane'");
+        assert_eq!(CustomerSearch::phone().is_null().to_string(), "phone:null");
+        assert_eq!(ChargeSearch::amount().gt(1000).to_string(), "amount>1000");
+        assert_eq!(ChargeSearch::created().lte(1609459200).to_string(), "created<=1609459200");
+        assert_eq!(ChargeSearch::refunded().eq(true).to_string(), "refunded:'true'");
+        assert_eq!(
+            ChargeSearch::status().eq(ChargeStatus::Succeeded).to_string(),
+            "status:'succeeded'"
+        );
+        assert_eq!((!ChargeSearch::currency().eq(Currency::JPY)).to_string(), "-currency:'jpy'");
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(CustomerSearch::name().eq("O'Brien").to_string(), r"name:'O\'Brien'");
+        assert_eq!(CustomerSearch::name().eq(r"a\").to_string(), r"name:'a\\'");
+        assert_eq!(CustomerSearch::metadata("it's").eq("1").to_string(), r"metadata['it\'s']:'1'");
+    }
+
+    #[test]
+    fn queries() {
+        let query = PaymentIntentSearch::metadata("order_id")
+            .eq("123")
+            .and(PaymentIntentSearch::amount().gte(500))
+            .and(!PaymentIntentSearch::customer().is_null());
+        assert_eq!(
+            query.to_string(),
+            "metadata['order_id']:'123' AND amount>=500 AND -customer:null"
+        );
+
+        let query: SearchAny<_> = ["a@example.com", "b@example.com"]
+            .iter()
+            .map(|email| CustomerSearch::email().eq(email))
+            .collect();
+        assert_eq!(String::from(query), "email:'a@example.com' OR email:'b@example.com'");
+    }
+}
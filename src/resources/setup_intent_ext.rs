@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::params::Expand;
 use crate::resources::SetupIntent;
 use crate::{PaymentMethodId, SetupIntentCancellationReason, SetupIntentId};
@@ -71,18 +71,51 @@ impl SetupIntent {
         client: &Client,
         setup_id: &SetupIntentId,
         params: ConfirmSetupIntent,
+    ) -> Response<SetupIntent> {
+        Self::confirm_with_options(client, setup_id, params, RequestOptions::default())
+    }
+
+    /// Like [`SetupIntent::confirm`], but with [`RequestOptions`] for this request only.
+    pub fn confirm_with_options(
+        client: &Client,
+        setup_id: &SetupIntentId,
+        params: ConfirmSetupIntent,
+        options: RequestOptions,
     ) -> Response<SetupIntent> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.post_form(&format!("/setup_intents/{}/confirm", setup_id), &params)
+        client.post_form_with_options(
+            &format!("/setup_intents/{}/confirm", setup_id),
+            &params,
+            options,
+        )
     }
 
     pub fn verify_micro_deposits(
         client: &Client,
         setup_id: &SetupIntentId,
         params: VerifyMicrodeposits,
+    ) -> Response<SetupIntent> {
+        Self::verify_micro_deposits_with_options(
+            client,
+            setup_id,
+            params,
+            RequestOptions::default(),
+        )
+    }
+
+    /// Like [`SetupIntent::verify_micro_deposits`], but with [`RequestOptions`] for this request only.
+    pub fn verify_micro_deposits_with_options(
+        client: &Client,
+        setup_id: &SetupIntentId,
+        params: VerifyMicrodeposits,
+        options: RequestOptions,
     ) -> Response<SetupIntent> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.post_form(&format!("/setup_intents/{}/verify_microdeposits", setup_id), &params)
+        client.post_form_with_options(
+            &format!("/setup_intents/{}/verify_microdeposits", setup_id),
+            &params,
+            options,
+        )
     }
 
     /// A SetupIntent object can be canceled when it is in one of these statuses: requires_payment_method, requires_confirmation, or requires_action.
@@ -93,7 +126,21 @@ impl SetupIntent {
         setup_id: &SetupIntentId,
         params: CancelSetupIntent,
     ) -> Response<SetupIntent> {
-        client.post_form(&format!("/setup_intents/{}/cancel", setup_id), params)
+        Self::cancel_with_options(client, setup_id, params, RequestOptions::default())
+    }
+
+    /// Like [`SetupIntent::cancel`], but with [`RequestOptions`] for this request only.
+    pub fn cancel_with_options(
+        client: &Client,
+        setup_id: &SetupIntentId,
+        params: CancelSetupIntent,
+        options: RequestOptions,
+    ) -> Response<SetupIntent> {
+        client.post_form_with_options(
+            &format!("/setup_intents/{}/cancel", setup_id),
+            params,
+            options,
+        )
     }
 }
 
@@ -1,8 +1,8 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::ids::SubscriptionId;
-use crate::params::SearchList;
+use crate::params::{PaginableSearch, SearchList};
 use crate::resources::{CreateSubscriptionItems, Subscription};
 use crate::CancellationDetails;
 
@@ -28,7 +28,7 @@ pub struct SubscriptionSearchParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u64>,
+    pub page: Option<String>,
     pub expand: &'a [&'a str],
 }
 
@@ -38,6 +38,12 @@ impl<'a> SubscriptionSearchParams<'a> {
     }
 }
 
+impl<'a> PaginableSearch for SubscriptionSearchParams<'a> {
+    fn set_page(&mut self, page: String) {
+        self.page = Some(page);
+    }
+}
+
 impl Subscription {
     /// Cancels a subscription.
     ///
@@ -47,7 +53,21 @@ impl Subscription {
         subscription_id: &SubscriptionId,
         params: CancelSubscription,
     ) -> Response<Subscription> {
-        client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
+        Self::cancel_with_options(client, subscription_id, params, RequestOptions::default())
+    }
+
+    /// Like [`Subscription::cancel`], but with [`RequestOptions`] for this request only.
+    pub fn cancel_with_options(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+        params: CancelSubscription,
+        options: RequestOptions,
+    ) -> Response<Subscription> {
+        client.delete_query_with_options(
+            &format!("/subscriptions/{}", subscription_id),
+            params,
+            options,
+        )
     }
     /// Searches for a subscription.
     ///
@@ -56,7 +76,16 @@ impl Subscription {
         client: &Client,
         params: SubscriptionSearchParams,
     ) -> Response<SearchList<Subscription>> {
-        client.get_query("/subscriptions/search", params)
+        Self::search_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`Subscription::search`], but with [`RequestOptions`] for this request only.
+    pub fn search_with_options(
+        client: &Client,
+        params: SubscriptionSearchParams,
+        options: RequestOptions,
+    ) -> Response<SearchList<Subscription>> {
+        client.get_query_with_options("/subscriptions/search", params, options)
     }
 }
 
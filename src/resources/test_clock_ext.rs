@@ -3,7 +3,8 @@
 use serde::Serialize;
 
 use crate::{
-    params::Paginable, Client, List, Response, TestHelpersTestClock, TestHelpersTestClockId,
+    params::{Paginable, ReversePaginable},
+    Client, List, RequestOptions, Response, TestHelpersTestClock, TestHelpersTestClockId,
     Timestamp,
 };
 
@@ -73,12 +74,27 @@ impl Paginable for ListTestClocks {
     }
 }
 
+impl ReversePaginable for ListTestClocks {
+    fn set_first(&mut self, item: Self::O) {
+        self.ending_before = Some(item.id);
+    }
+}
+
 impl TestHelpersTestClock {
     /// Creates a new test clock that can be attached to new customers and quotes.
     ///
     /// For more details see <https://docs.stripe.com/api/test_clocks/create>
     pub fn create(client: &Client, params: &CreateTestClock<'_>) -> Response<TestHelpersTestClock> {
-        client.post_form("/test_helpers/test_clocks", params)
+        Self::create_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`TestHelpersTestClock::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        params: &CreateTestClock<'_>,
+        options: RequestOptions,
+    ) -> Response<TestHelpersTestClock> {
+        client.post_form_with_options("/test_helpers/test_clocks", params, options)
     }
 
     /// Retrieves a test clock.
@@ -88,21 +104,48 @@ impl TestHelpersTestClock {
         client: &Client,
         id: &TestHelpersTestClockId,
     ) -> Response<TestHelpersTestClock> {
-        client.get(&format!("/test_helpers/test_clocks/{}", id))
+        Self::retrieve_with_options(client, id, RequestOptions::default())
+    }
+
+    /// Like [`TestHelpersTestClock::retrieve`], but with [`RequestOptions`] for this request only.
+    pub fn retrieve_with_options(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        options: RequestOptions,
+    ) -> Response<TestHelpersTestClock> {
+        client.get_with_options(&format!("/test_helpers/test_clocks/{}", id), options)
     }
 
     /// Returns a list of your test clocks.
     ///
     /// For more details see <https://docs.stripe.com/api/test_clocks/list>
     pub fn list(client: &Client, params: &ListTestClocks) -> Response<List<TestHelpersTestClock>> {
-        client.get_query("/test_helpers/test_clocks", params)
+        Self::list_with_options(client, params, RequestOptions::default())
+    }
+
+    /// Like [`TestHelpersTestClock::list`], but with [`RequestOptions`] for this request only.
+    pub fn list_with_options(
+        client: &Client,
+        params: &ListTestClocks,
+        options: RequestOptions,
+    ) -> Response<List<TestHelpersTestClock>> {
+        client.get_query_with_options("/test_helpers/test_clocks", params, options)
     }
 
     /// Deletes a test clock.
     ///
     /// For more details see <https://docs.stripe.com/api/test_clocks/delete>
     pub fn delete(client: &Client, id: &TestHelpersTestClockId) -> Response<TestHelpersTestClock> {
-        client.delete(&format!("/test_helpers/test_clocks/{}", id))
+        Self::delete_with_options(client, id, RequestOptions::default())
+    }
+
+    /// Like [`TestHelpersTestClock::delete`], but with [`RequestOptions`] for this request only.
+    pub fn delete_with_options(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        options: RequestOptions,
+    ) -> Response<TestHelpersTestClock> {
+        client.delete_with_options(&format!("/test_helpers/test_clocks/{}", id), options)
     }
 
     /// Starts advancing a test clock to a specified time in the future. Advancement is done when status changes to `Ready`.
@@ -113,6 +156,20 @@ impl TestHelpersTestClock {
         test_clock_id: &TestHelpersTestClockId,
         params: &AdvanceTestClock,
     ) -> Response<TestHelpersTestClock> {
-        client.post_form(&format!("test_helpers/test_clocks/{}/advance", test_clock_id), params)
+        Self::advance_with_options(client, test_clock_id, params, RequestOptions::default())
+    }
+
+    /// Like [`TestHelpersTestClock::advance`], but with [`RequestOptions`] for this request only.
+    pub fn advance_with_options(
+        client: &Client,
+        test_clock_id: &TestHelpersTestClockId,
+        params: &AdvanceTestClock,
+        options: RequestOptions,
+    ) -> Response<TestHelpersTestClock> {
+        client.post_form_with_options(
+            &format!("test_helpers/test_clocks/{}/advance", test_clock_id),
+            params,
+            options,
+        )
     }
 }
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::{Client, RequestOptions, Response};
 use crate::params::Metadata;
 use crate::resources::TransferReversal;
 use crate::TransferId;
@@ -30,6 +30,20 @@ impl TransferReversal {
         transfer: &TransferId,
         params: CreateTransferReversal,
     ) -> Response<TransferReversal> {
-        client.post_form(&format!("/transfers/{}/reversals", transfer), params)
+        Self::create_with_options(client, transfer, params, RequestOptions::default())
+    }
+
+    /// Like [`TransferReversal::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        transfer: &TransferId,
+        params: CreateTransferReversal,
+        options: RequestOptions,
+    ) -> Response<TransferReversal> {
+        client.post_form_with_options(
+            &format!("/transfers/{}/reversals", transfer),
+            params,
+            options,
+        )
     }
 }
@@ -1,17 +1,28 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Client, Response, SubscriptionItemId, Timestamp, UsageRecord};
+use crate::{Client, RequestOptions, Response, SubscriptionItemId, Timestamp, UsageRecord};
 
 impl UsageRecord {
     pub fn create(
         client: &Client,
         subscription_item_id: &SubscriptionItemId,
         params: CreateUsageRecord,
+    ) -> Response<UsageRecord> {
+        Self::create_with_options(client, subscription_item_id, params, RequestOptions::default())
+    }
+
+    /// Like [`UsageRecord::create`], but with [`RequestOptions`] for this request only.
+    pub fn create_with_options(
+        client: &Client,
+        subscription_item_id: &SubscriptionItemId,
+        params: CreateUsageRecord,
+        options: RequestOptions,
     ) -> Response<UsageRecord> {
         #[allow(clippy::needless_borrows_for_generic_args)]
-        client.post_form(
+        client.post_form_with_options(
             &format!("/subscription_items/{}/usage_records", subscription_item_id),
             &params,
+            options,
         )
     }
 }
@@ -0,0 +1,182 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::error::WebhookError;
+use crate::resources::{
+    Charge, ChargeStatus, Dispute, DisputeStatus, Event, FromEventObject, PaymentIntent,
+    PaymentIntentStatus, SetupIntent, SetupIntentStatus,
+};
+#[cfg(feature = "billing")]
+use crate::resources::{Invoice, InvoiceStatus, Subscription, SubscriptionStatus};
+
+/// The changes made to an object by an `*.updated` event.
+///
+/// ```
+/// use stripe::{Event, Subscription, SubscriptionStatus};
+///
+/// # fn run(event: Event) -> Result<(), stripe::WebhookError> {
+/// if let Some(changes) = event.changed::<Subscription>()? {
+///     if changes.previous_status() == Some(SubscriptionStatus::Trialing) {
+///         println!("the trial of {} has ended", changes.previous.id);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Changes<T> {
+    /// The object as it was before the event.
+    pub previous: T,
+    /// The names of the top-level fields which changed.
+    pub fields: Vec<String>,
+}
+
+impl<T> Changes<T> {
+    /// Whether the given top-level field changed.
+    pub fn did_change(&self, field: &str) -> bool {
+        self.fields.iter().any(|changed| changed == field)
+    }
+}
+
+impl Event {
+    /// Whether the given top-level field of the object changed, for `*.updated` events.
+    pub fn did_change(&self, field: &str) -> bool {
+        self.data.previous_attributes.as_ref().is_some_and(|previous| previous.contains_key(field))
+    }
+
+    /// Get the object as it was before an `*.updated` event, by applying the previous
+    /// values of the attributes which changed to the current object.
+    ///
+    /// Returns `None` if the event has no previous attributes, or if its object isn't a `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the previous object can't be parsed as a `T`.
+    pub fn changed<T>(&self) -> Result<Option<Changes<T>>, WebhookError>
+    where
+        T: FromEventObject + DeserializeOwned,
+    {
+        let Some(previous_attributes) = &self.data.previous_attributes else {
+            return Ok(None);
+        };
+        if self.data.object.object() != T::OBJECT {
+            return Ok(None);
+        }
+
+        let mut previous = serde_json::to_value(&self.data.object)?;
+        if let Value::Object(fields) = &mut previous {
+            for (field, value) in previous_attributes {
+                apply(fields, field, value);
+            }
+        }
+
+        Ok(Some(Changes {
+            previous: serde_json::from_value(previous)?,
+            fields: previous_attributes.keys().cloned().collect(),
+        }))
+    }
+}
+
+/// Set the previous value of a field. Nested objects, such as `metadata`, only contain
+/// the keys which changed, with `null` for keys which didn't exist.
+fn apply(fields: &mut Map<String, Value>, field: &str, previous: &Value) {
+    match (fields.get_mut(field), previous) {
+        (Some(Value::Object(current)), Value::Object(previous)) => {
+            for (field, value) in previous {
+                apply(current, field, value);
+            }
+        }
+        (_, Value::Null) => {
+            fields.remove(field);
+        }
+        _ => {
+            fields.insert(field.to_string(), previous.clone());
+        }
+    }
+}
+
+macro_rules! previous_status {
+    ($($(#[$attr:meta])* $type:ident => $status:ident,)*) => {
+        $(
+            $(#[$attr])*
+            impl Changes<$type> {
+                /// The status of the object before the event, if it changed.
+                pub fn previous_status(&self) -> Option<$status> {
+                    self.did_change("status").then(|| self.previous.status.clone())
+                }
+            }
+        )*
+    };
+}
+
+previous_status! {
+    Charge => ChargeStatus,
+    Dispute => DisputeStatus,
+    PaymentIntent => PaymentIntentStatus,
+    SetupIntent => SetupIntentStatus,
+    #[cfg(feature = "billing")]
+    Subscription => SubscriptionStatus,
+}
+
+#[cfg(feature = "billing")]
+impl Changes<Invoice> {
+    /// The status of the invoice before the event, if it changed.
+    pub fn previous_status(&self) -> Option<InvoiceStatus> {
+        self.did_change("status").then_some(self.previous.status).flatten()
+    }
+}
+
+#[cfg(all(test, feature = "webhook-events"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::{Customer, EventObject, EventType, PaymentIntent, PaymentIntentStatus, TestEvent};
+
+    #[test]
+    fn changed() {
+        let intent = PaymentIntent {
+            id: "pi_123".parse().unwrap(),
+            amount: 2000,
+            status: PaymentIntentStatus::Succeeded,
+            metadata: HashMap::from([
+                ("order".to_string(), "123".to_string()),
+                ("note".to_string(), "gift".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let previous_attributes = HashMap::from([
+            ("amount".to_string(), json!(1000)),
+            ("status".to_string(), json!("processing")),
+            ("metadata".to_string(), json!({ "order": "122", "note": null })),
+        ]);
+        let event =
+            TestEvent::new(EventType::PaymentIntentSucceeded, EventObject::PaymentIntent(intent))
+                .previous_attributes(previous_attributes)
+                .into_event();
+
+        assert!(event.did_change("amount"));
+        assert!(!event.did_change("currency"));
+
+        let changes = event.changed::<PaymentIntent>().unwrap().unwrap();
+        assert_eq!(changes.previous.id.as_str(), "pi_123");
+        assert_eq!(changes.previous.amount, 1000);
+        assert_eq!(changes.previous_status(), Some(PaymentIntentStatus::Processing));
+        assert_eq!(changes.previous.metadata, HashMap::from([("order".into(), "122".into())]));
+        assert!(changes.did_change("metadata"));
+
+        // the object of the event is not a customer
+        assert!(event.changed::<Customer>().unwrap().is_none());
+    }
+
+    #[test]
+    fn unchanged() {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let event = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .into_event();
+
+        assert!(!event.did_change("email"));
+        assert!(event.changed::<Customer>().unwrap().is_none());
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::error::ConnectRouterError;
+use crate::ids::AccountId;
+use crate::resources::{Event, EventType};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type Handler<C, E> = Box<dyn Fn(C, Event) -> BoxFuture<Result<(), E>> + Send + Sync>;
+type Resolver<C, E> = Box<dyn Fn(AccountId) -> BoxFuture<Result<Option<C>, E>> + Send + Sync>;
+type DeauthorizedHandler<C, E> =
+    Box<dyn Fn(AccountId, Option<C>) -> BoxFuture<Result<(), E>> + Send + Sync>;
+
+/// Dispatches the events received by a Connect endpoint to the tenant of the connected
+/// account which originated them.
+///
+/// The context of a tenant is either registered for its account with
+/// [`ConnectRouter::account`], or looked up by a [`ConnectRouter::resolver`]. Events from
+/// accounts without a tenant, such as accounts which were disconnected, are ignored.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use stripe::{AccountId, ConnectRouter, Event, EventRouter, EventRouterError};
+///
+/// # async fn run(event: Event) -> Result<(), Box<dyn std::error::Error>> {
+/// type Tenant = Arc<EventRouter<std::io::Error>>;
+///
+/// let router = ConnectRouter::new(|tenant: Tenant, event: Event| async move {
+///         tenant.dispatch(event).await
+///     })
+///     .account("acct_123".parse()?, Arc::new(EventRouter::new()))
+///     .livemode(false)
+///     .on_deauthorized(|account: AccountId, _tenant: Option<Tenant>| async move {
+///         println!("{} disconnected from the platform", account);
+///         Ok::<_, EventRouterError<std::io::Error>>(())
+///     });
+///
+/// router.dispatch(event).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConnectRouter<C, E> {
+    handler: Handler<C, E>,
+    accounts: HashMap<AccountId, C>,
+    resolver: Option<Resolver<C, E>>,
+    deauthorized: Option<DeauthorizedHandler<C, E>>,
+    livemode: Option<bool>,
+}
+
+/// What [`ConnectRouter::dispatch`] did with an event.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ConnectDispatched {
+    /// The event was passed to the handler, with the context of its tenant.
+    Handled,
+    /// The event was an `account.application.deauthorized` event.
+    Deauthorized,
+    /// The account of the event has no tenant, so the event was ignored.
+    UnknownAccount,
+    /// The event didn't originate from a connected account, so it was ignored.
+    NoAccount,
+}
+
+impl<C, E> ConnectRouter<C, E>
+where
+    C: Clone + Send + Sync + 'static,
+    E: Send + 'static,
+{
+    /// Create a router passing the events of each tenant to the given handler.
+    pub fn new<F, Fut>(handler: F) -> Self
+    where
+        F: Fn(C, Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        Self {
+            handler: Box::new(move |tenant, event| Box::pin(handler(tenant, event))),
+            accounts: HashMap::new(),
+            resolver: None,
+            deauthorized: None,
+            livemode: None,
+        }
+    }
+
+    /// Register the context of the tenant of a connected account.
+    pub fn account(mut self, account: AccountId, tenant: C) -> Self {
+        self.accounts.insert(account, tenant);
+        self
+    }
+
+    /// Look up the context of the tenant of the accounts which weren't registered.
+    ///
+    /// The resolver returns `None` for accounts without a tenant, whose events are ignored.
+    pub fn resolver<F, Fut>(mut self, resolver: F) -> Self
+    where
+        F: Fn(AccountId) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Option<C>, E>> + Send + 'static,
+    {
+        self.resolver = Some(Box::new(move |account| Box::pin(resolver(account))));
+        self
+    }
+
+    /// Handle the `account.application.deauthorized` events, sent when an account
+    /// disconnects from the platform, instead of passing them to the handler.
+    ///
+    /// The hook receives the context of the tenant if it still has one, and is where the
+    /// account should be removed from the resolver. The tenant is looked up on a best effort
+    /// basis: if the resolver fails, the hook receives `None`. Without a hook, these events
+    /// are ignored.
+    pub fn on_deauthorized<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(AccountId, Option<C>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.deauthorized =
+            Some(Box::new(move |account, tenant| Box::pin(handler(account, tenant))));
+        self
+    }
+
+    /// Reject the events whose `livemode` differs from the given mode, which usually means
+    /// that a secret for the wrong mode was configured for the endpoint.
+    pub fn livemode(mut self, livemode: bool) -> Self {
+        self.livemode = Some(livemode);
+        self
+    }
+
+    /// Pass an event to the handler, with the context of the tenant of its account.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the event is not in the expected mode, if its
+    /// account is not a valid account id, or if the resolver or the handler fails. The
+    /// resolver and the handlers share the error type `E`, and their errors are told apart
+    /// by the variant of [`ConnectRouterError`].
+    pub async fn dispatch(&self, event: Event) -> Result<ConnectDispatched, ConnectRouterError<E>> {
+        if let Some(expected) = self.livemode.filter(|expected| *expected != event.livemode) {
+            return Err(ConnectRouterError::LivemodeMismatch { expected, found: event.livemode });
+        }
+
+        let Some(account) = event.account.as_deref() else {
+            return Ok(ConnectDispatched::NoAccount);
+        };
+        let account: AccountId = account.parse().map_err(|source| {
+            ConnectRouterError::InvalidAccount { account: account.to_string(), source }
+        })?;
+
+        // the tenant of a disconnected account may already be gone, so failing to resolve it
+        // doesn't prevent the hook from running
+        if event.type_ == EventType::AccountApplicationDeauthorized {
+            if let Some(handler) = &self.deauthorized {
+                let tenant = self.tenant(&account).await.ok().flatten();
+                handler(account, tenant).await.map_err(ConnectRouterError::Handler)?;
+            }
+            return Ok(ConnectDispatched::Deauthorized);
+        }
+
+        match self.tenant(&account).await? {
+            Some(tenant) => {
+                (self.handler)(tenant, event).await.map_err(ConnectRouterError::Handler)?;
+                Ok(ConnectDispatched::Handled)
+            }
+            None => Ok(ConnectDispatched::UnknownAccount),
+        }
+    }
+
+    async fn tenant(&self, account: &AccountId) -> Result<Option<C>, ConnectRouterError<E>> {
+        if let Some(tenant) = self.accounts.get(account) {
+            return Ok(Some(tenant.clone()));
+        }
+        match &self.resolver {
+            Some(resolver) => resolver(account.clone()).await.map_err(ConnectRouterError::Resolver),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<C, E> std::fmt::Debug for ConnectRouter<C, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectRouter")
+            .field("accounts", &self.accounts.keys().collect::<Vec<_>>())
+            .field("resolver", &self.resolver.is_some())
+            .field("deauthorized", &self.deauthorized.is_some())
+            .field("livemode", &self.livemode)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{ConnectDispatched, ConnectRouter};
+    use crate::{
+        AccountId, ConnectRouterError, Customer, Event, EventObject, EventType, TestEvent,
+    };
+
+    fn event(type_: EventType, account: Option<&str>) -> Event {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let mut event = TestEvent::new(type_, EventObject::Customer(customer));
+        if let Some(account) = account {
+            event = event.account(account);
+        }
+        event.into_event()
+    }
+
+    #[tokio::test]
+    async fn dispatch() {
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let deauthorized = Arc::new(Mutex::new(Vec::new()));
+
+        let router = ConnectRouter::new({
+            let handled = handled.clone();
+            move |tenant: &'static str, event: Event| {
+                handled.lock().unwrap().push((tenant, event.id));
+                async { Ok::<_, String>(()) }
+            }
+        })
+        .account("acct_1".parse().unwrap(), "one")
+        .resolver(|account: AccountId| async move {
+            match account.as_str() {
+                "acct_2" => Ok(Some("two")),
+                "acct_err" => Err("lookup failed".to_string()),
+                _ => Ok(None),
+            }
+        })
+        .on_deauthorized({
+            let deauthorized = deauthorized.clone();
+            move |account: AccountId, tenant| {
+                deauthorized.lock().unwrap().push((account, tenant));
+                async { Ok(()) }
+            }
+        })
+        .livemode(false);
+
+        let res = router.dispatch(event(EventType::CustomerCreated, Some("acct_1"))).await;
+        assert_eq!(res.unwrap(), ConnectDispatched::Handled);
+        let res = router.dispatch(event(EventType::CustomerCreated, Some("acct_2"))).await;
+        assert_eq!(res.unwrap(), ConnectDispatched::Handled);
+        let tenants: Vec<_> = handled.lock().unwrap().iter().map(|(tenant, _)| *tenant).collect();
+        assert_eq!(tenants, ["one", "two"]);
+
+        let res = router.dispatch(event(EventType::CustomerCreated, Some("acct_3"))).await;
+        assert_eq!(res.unwrap(), ConnectDispatched::UnknownAccount);
+        let res = router.dispatch(event(EventType::CustomerCreated, None)).await;
+        assert_eq!(res.unwrap(), ConnectDispatched::NoAccount);
+
+        let res = router.dispatch(event(EventType::CustomerCreated, Some("acct_err"))).await;
+        assert!(matches!(res, Err(ConnectRouterError::Resolver(err)) if err == "lookup failed"));
+
+        // a malformed account is not mistaken for a platform event
+        let res = router.dispatch(event(EventType::CustomerCreated, Some("cus_123"))).await;
+        assert!(matches!(
+            res,
+            Err(ConnectRouterError::InvalidAccount { ref account, .. }) if account == "cus_123"
+        ));
+
+        // the hook still runs when the tenant can't be resolved
+        let res = router
+            .dispatch(event(EventType::AccountApplicationDeauthorized, Some("acct_err")))
+            .await;
+        assert_eq!(res.unwrap(), ConnectDispatched::Deauthorized);
+        assert_eq!(*deauthorized.lock().unwrap(), [("acct_err".parse().unwrap(), None)]);
+        deauthorized.lock().unwrap().clear();
+
+        let res =
+            router.dispatch(event(EventType::AccountApplicationDeauthorized, Some("acct_2"))).await;
+        assert_eq!(res.unwrap(), ConnectDispatched::Deauthorized);
+        assert_eq!(*deauthorized.lock().unwrap(), [("acct_2".parse().unwrap(), Some("two"))]);
+        assert_eq!(handled.lock().unwrap().len(), 2);
+
+        let mut live = event(EventType::CustomerCreated, Some("acct_1"));
+        live.livemode = true;
+        let res = router.dispatch(live).await;
+        assert!(matches!(
+            res,
+            Err(ConnectRouterError::LivemodeMismatch { expected: false, found: true })
+        ));
+    }
+}
@@ -0,0 +1,748 @@
+use std::fmt;
+
+use crate::client::{config::and_then, Client, Response};
+use crate::error::StripeError;
+use crate::ids::WebhookEndpointId;
+use crate::params::Metadata;
+use crate::resources::{
+    ApiVersion, CreateWebhookEndpoint, EventFilter, ListWebhookEndpoints, UpdateWebhookEndpoint,
+    WebhookEndpoint, WebhookEndpointStatus,
+};
+
+/// The configuration an endpoint should have, identified by its url.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DesiredWebhookEndpoint {
+    /// The url of the endpoint.
+    pub url: String,
+    /// The events sent to the endpoint.
+    pub enabled_events: Vec<EventFilter>,
+    /// The api version of the events, or the default version of the account if `None`.
+    pub api_version: Option<ApiVersion>,
+    /// A description of the endpoint.
+    pub description: Option<String>,
+    /// The metadata of the endpoint.
+    pub metadata: Metadata,
+    /// Whether the endpoint receives the events of connected accounts.
+    ///
+    /// Stripe doesn't return this setting, so it is only used when creating the endpoint.
+    pub connect: bool,
+}
+
+impl DesiredWebhookEndpoint {
+    /// Describe an endpoint receiving the given events.
+    pub fn new(url: impl Into<String>, enabled_events: Vec<EventFilter>) -> Self {
+        Self { url: url.into(), enabled_events, ..Default::default() }
+    }
+
+    /// Set the api version of the events.
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Set the description of the endpoint.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set a metadata key of the endpoint.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set whether the endpoint receives the events of connected accounts.
+    pub fn connect(mut self, connect: bool) -> Self {
+        self.connect = connect;
+        self
+    }
+
+    fn create_params(&self) -> CreateWebhookEndpoint<'_> {
+        let mut params = CreateWebhookEndpoint::new(self.enabled_events.clone(), &self.url);
+        params.api_version = self.api_version;
+        params.connect = self.connect.then_some(true);
+        params.description = self.description.clone();
+        params.metadata = (!self.metadata.is_empty()).then(|| self.metadata.clone());
+        params
+    }
+}
+
+/// A change to the endpoints of an account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookEndpointChange {
+    /// Create an endpoint.
+    Create(DesiredWebhookEndpoint),
+    /// Update the fields of an endpoint which differ from the desired ones.
+    Update {
+        /// The id of the endpoint.
+        id: WebhookEndpointId,
+        /// The desired configuration of the endpoint.
+        desired: DesiredWebhookEndpoint,
+        /// The names of the fields which differ.
+        fields: Vec<&'static str>,
+        /// The metadata keys to remove.
+        removed_metadata: Vec<String>,
+    },
+    /// Create an endpoint, then delete the existing one, since the api version of an
+    /// endpoint can't be updated.
+    Replace {
+        /// The id of the existing endpoint.
+        id: WebhookEndpointId,
+        /// The desired configuration of the endpoint.
+        desired: DesiredWebhookEndpoint,
+    },
+    /// Delete an endpoint which isn't desired.
+    Delete {
+        /// The id of the endpoint.
+        id: WebhookEndpointId,
+        /// The url of the endpoint.
+        url: String,
+    },
+}
+
+/// The changes needed to make the endpoints of an account match the desired ones.
+///
+/// Its `Display` implementation lists the changes, for a dry run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebhookEndpointPlan {
+    /// The changes, in the order they are applied.
+    pub changes: Vec<WebhookEndpointChange>,
+}
+
+impl WebhookEndpointPlan {
+    /// Whether the endpoints already match the desired ones.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for WebhookEndpointPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for change in &self.changes {
+            match change {
+                WebhookEndpointChange::Create(desired) => writeln!(f, "+ create {}", desired.url)?,
+                WebhookEndpointChange::Update { id, desired, fields, .. } => {
+                    writeln!(f, "~ update {} ({}): {}", desired.url, id, fields.join(", "))?
+                }
+                WebhookEndpointChange::Replace { id, desired } => {
+                    writeln!(f, "-/+ replace {} ({}): api_version", desired.url, id)?
+                }
+                WebhookEndpointChange::Delete { id, url } => {
+                    writeln!(f, "- delete {} ({})", url, id)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An endpoint created when applying a plan, with its signing secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatedWebhookEndpoint {
+    /// The id of the endpoint.
+    pub id: WebhookEndpointId,
+    /// The url of the endpoint.
+    pub url: String,
+    /// The signing secret of the endpoint, which stripe only returns when it is created.
+    pub secret: Option<String>,
+}
+
+/// Makes the webhook endpoints of an account match a declarative configuration.
+///
+/// Endpoints are matched by url. Computing the plan and applying it are separate, so the
+/// plan can be reviewed first, and planning again after applying it gives an empty plan.
+///
+/// With the blocking runtime, [`WebhookEndpointReconciler::plan`] and
+/// [`WebhookEndpointReconciler::apply`] return their results directly instead of futures.
+///
+#[cfg_attr(feature = "async", doc = "```no_run")]
+#[cfg_attr(not(feature = "async"), doc = "```ignore")]
+/// use stripe::{Client, DesiredWebhookEndpoint, EventFilter, WebhookEndpointReconciler};
+///
+/// # async fn run() -> Result<(), stripe::StripeError> {
+/// let client = Client::new("sk_test_123");
+/// let reconciler = WebhookEndpointReconciler::new([DesiredWebhookEndpoint::new(
+///     "https://example.com/webhooks",
+///     vec![EventFilter::CustomerCreated, EventFilter::InvoicePaid],
+/// )
+/// .description("billing")])
+/// .prune(true);
+///
+/// let plan = reconciler.plan(&client).await?;
+/// print!("{}", plan);
+/// for created in reconciler.apply(&client, &plan).await? {
+///     println!("store the secret of {}: {:?}", created.url, created.secret);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WebhookEndpointReconciler {
+    desired: Vec<DesiredWebhookEndpoint>,
+    prune: bool,
+}
+
+impl WebhookEndpointReconciler {
+    /// Create a reconciler for the given endpoints.
+    pub fn new(desired: impl IntoIterator<Item = DesiredWebhookEndpoint>) -> Self {
+        Self { desired: desired.into_iter().collect(), prune: false }
+    }
+
+    /// Delete the endpoints which aren't desired, including duplicates of a desired
+    /// endpoint. Defaults to false.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Compute the changes needed to turn the existing endpoints into the desired ones.
+    pub fn diff(&self, existing: &[WebhookEndpoint]) -> WebhookEndpointPlan {
+        let mut existing: Vec<&WebhookEndpoint> =
+            existing.iter().filter(|endpoint| !endpoint.deleted).collect();
+        let mut changes = Vec::new();
+
+        for desired in &self.desired {
+            let position = existing
+                .iter()
+                .position(|endpoint| endpoint.url.as_deref() == Some(desired.url.as_str()));
+            let Some(endpoint) = position.map(|position| existing.remove(position)) else {
+                changes.push(WebhookEndpointChange::Create(desired.clone()));
+                continue;
+            };
+
+            if desired.api_version.is_some() && desired.api_version != endpoint.api_version {
+                changes.push(WebhookEndpointChange::Replace {
+                    id: endpoint.id.clone(),
+                    desired: desired.clone(),
+                });
+                continue;
+            }
+
+            let mut fields = Vec::new();
+            if event_names(&desired.enabled_events)
+                != event_names(endpoint.enabled_events.as_deref().unwrap_or_default())
+            {
+                fields.push("enabled_events");
+            }
+            if desired.description.as_deref().unwrap_or_default()
+                != endpoint.description.as_deref().unwrap_or_default()
+            {
+                fields.push("description");
+            }
+            let current = endpoint.metadata.clone().unwrap_or_default();
+            let mut removed_metadata: Vec<String> = current
+                .keys()
+                .filter(|key| !desired.metadata.contains_key(*key))
+                .cloned()
+                .collect();
+            removed_metadata.sort();
+            if current != desired.metadata {
+                fields.push("metadata");
+            }
+            if endpoint.status == Some(WebhookEndpointStatus::Disabled) {
+                fields.push("disabled");
+            }
+
+            if !fields.is_empty() {
+                changes.push(WebhookEndpointChange::Update {
+                    id: endpoint.id.clone(),
+                    desired: desired.clone(),
+                    fields,
+                    removed_metadata,
+                });
+            }
+        }
+
+        if self.prune {
+            changes.extend(existing.into_iter().map(|endpoint| WebhookEndpointChange::Delete {
+                id: endpoint.id.clone(),
+                url: endpoint.url.clone().unwrap_or_default(),
+            }));
+        }
+        WebhookEndpointPlan { changes }
+    }
+
+    /// List the endpoints of the account, and compute the changes needed to turn them
+    /// into the desired ones.
+    ///
+    /// An account has at most 16 endpoints, so they are listed in a single request.
+    #[cfg(feature = "async")]
+    pub async fn plan(&self, client: &Client) -> Result<WebhookEndpointPlan, StripeError> {
+        let endpoints = WebhookEndpoint::list(client, &list_params()).await?;
+        Ok(self.diff(&endpoints.data))
+    }
+
+    /// List the endpoints of the account, and compute the changes needed to turn them
+    /// into the desired ones.
+    ///
+    /// An account has at most 16 endpoints, so they are listed in a single request.
+    #[cfg(feature = "blocking")]
+    pub fn plan(&self, client: &Client) -> Result<WebhookEndpointPlan, StripeError> {
+        let endpoints = WebhookEndpoint::list(client, &list_params())?;
+        Ok(self.diff(&endpoints.data))
+    }
+
+    /// Apply the changes of a plan, returning the endpoints which were created.
+    ///
+    /// The endpoints are listed again first: endpoints which already exist with the desired
+    /// url and api version aren't created again, and endpoints which were already deleted
+    /// are skipped, so a plan can be applied again after it was partially or fully applied.
+    /// Updates only send the fields which differ in the plan, so other fields edited in the
+    /// meantime are kept. The secrets of the created endpoints are only returned by stripe
+    /// once, so they should be stored right away.
+    #[cfg(feature = "async")]
+    pub async fn apply(
+        &self,
+        client: &Client,
+        plan: &WebhookEndpointPlan,
+    ) -> Result<Vec<CreatedWebhookEndpoint>, StripeError> {
+        let existing = WebhookEndpoint::list(client, &list_params()).await?.data;
+        let mut created = Vec::new();
+        for request in plan.requests(&existing) {
+            created.extend(request.send(client).await?);
+        }
+        Ok(created)
+    }
+
+    /// Apply the changes of a plan, returning the endpoints which were created.
+    ///
+    /// The endpoints are listed again first: endpoints which already exist with the desired
+    /// url and api version aren't created again, and endpoints which were already deleted
+    /// are skipped, so a plan can be applied again after it was partially or fully applied.
+    /// Updates only send the fields which differ in the plan, so other fields edited in the
+    /// meantime are kept. The secrets of the created endpoints are only returned by stripe
+    /// once, so they should be stored right away.
+    #[cfg(feature = "blocking")]
+    pub fn apply(
+        &self,
+        client: &Client,
+        plan: &WebhookEndpointPlan,
+    ) -> Result<Vec<CreatedWebhookEndpoint>, StripeError> {
+        let existing = WebhookEndpoint::list(client, &list_params())?.data;
+        let mut created = Vec::new();
+        for request in plan.requests(&existing) {
+            created.extend(request.send(client)?);
+        }
+        Ok(created)
+    }
+}
+
+impl WebhookEndpointPlan {
+    /// The requests applying the changes of the plan to the existing endpoints, in order.
+    fn requests(&self, existing: &[WebhookEndpoint]) -> Vec<EndpointRequest<'_>> {
+        let mut created: Vec<&DesiredWebhookEndpoint> = Vec::new();
+        let mut requests = Vec::new();
+        for change in &self.changes {
+            match change {
+                WebhookEndpointChange::Create(desired) => {
+                    if !is_created(existing, &created, desired) {
+                        created.push(desired);
+                        requests.push(EndpointRequest::Create(desired));
+                    }
+                }
+                WebhookEndpointChange::Update { id, desired, fields, removed_metadata } => {
+                    let params = update_params(desired, fields, removed_metadata);
+                    requests.push(EndpointRequest::Update(id, params));
+                }
+                WebhookEndpointChange::Replace { id, desired } => {
+                    // create the new endpoint first so that no events are missed
+                    if !is_created(existing, &created, desired) {
+                        created.push(desired);
+                        requests.push(EndpointRequest::Create(desired));
+                    }
+                    requests.push(EndpointRequest::Delete(id));
+                }
+                WebhookEndpointChange::Delete { id, .. } => {
+                    requests.push(EndpointRequest::Delete(id));
+                }
+            }
+        }
+        requests
+    }
+}
+
+/// A request made when applying a plan.
+enum EndpointRequest<'a> {
+    Create(&'a DesiredWebhookEndpoint),
+    Update(&'a WebhookEndpointId, UpdateWebhookEndpoint<'a>),
+    Delete(&'a WebhookEndpointId),
+}
+
+impl EndpointRequest<'_> {
+    /// Send the request, returning the endpoint it created, if any.
+    fn send(self, client: &Client) -> Response<Option<CreatedWebhookEndpoint>> {
+        match self {
+            EndpointRequest::Create(desired) => {
+                let url = desired.url.clone();
+                and_then(WebhookEndpoint::create(client, desired.create_params()), |endpoint| {
+                    Ok(Some(CreatedWebhookEndpoint {
+                        id: endpoint.id,
+                        url: endpoint.url.unwrap_or(url),
+                        secret: endpoint.secret,
+                    }))
+                })
+            }
+            EndpointRequest::Update(id, params) => {
+                and_then(WebhookEndpoint::update(client, id, params), |_| Ok(None))
+            }
+            EndpointRequest::Delete(id) => skip_missing(WebhookEndpoint::delete(client, id)),
+        }
+    }
+}
+
+fn list_params<'a>() -> ListWebhookEndpoints<'a> {
+    ListWebhookEndpoints { limit: Some(100), ..ListWebhookEndpoints::new() }
+}
+
+fn update_params<'a>(
+    desired: &'a DesiredWebhookEndpoint,
+    fields: &[&str],
+    removed_metadata: &[String],
+) -> UpdateWebhookEndpoint<'a> {
+    let mut params = UpdateWebhookEndpoint::new();
+    for field in fields {
+        match *field {
+            "enabled_events" => params.enabled_events = Some(desired.enabled_events.clone()),
+            "description" => {
+                // N.B. an empty description removes it
+                params.description = Some(desired.description.clone().unwrap_or_default())
+            }
+            "metadata" => {
+                // metadata keys are removed by setting them to an empty string
+                let mut metadata = desired.metadata.clone();
+                metadata.extend(removed_metadata.iter().map(|key| (key.clone(), String::new())));
+                params.metadata = Some(metadata);
+            }
+            "disabled" => params.disabled = Some(false),
+            _ => {}
+        }
+    }
+    params
+}
+
+/// Whether an endpoint with the url and api version of the desired one exists, or is
+/// created by an earlier request.
+fn is_created(
+    existing: &[WebhookEndpoint],
+    created: &[&DesiredWebhookEndpoint],
+    desired: &DesiredWebhookEndpoint,
+) -> bool {
+    let api_version_matches = |api_version: Option<ApiVersion>| {
+        desired.api_version.is_none() || desired.api_version == api_version
+    };
+    existing.iter().any(|endpoint| {
+        !endpoint.deleted
+            && endpoint.url.as_deref() == Some(desired.url.as_str())
+            && api_version_matches(endpoint.api_version)
+    }) || created
+        .iter()
+        .any(|other| other.url == desired.url && api_version_matches(other.api_version))
+}
+
+/// Treat deleting an endpoint which doesn't exist anymore as a success.
+#[cfg(feature = "async")]
+fn skip_missing<T: Send + 'static>(
+    response: Response<T>,
+) -> Response<Option<CreatedWebhookEndpoint>> {
+    use futures_util::FutureExt;
+    Box::pin(response.map(skip_missing_result))
+}
+
+#[cfg(feature = "blocking")]
+fn skip_missing<T>(response: Response<T>) -> Response<Option<CreatedWebhookEndpoint>> {
+    skip_missing_result(response)
+}
+
+fn skip_missing_result<T>(
+    res: Result<T, StripeError>,
+) -> Result<Option<CreatedWebhookEndpoint>, StripeError> {
+    match res {
+        Err(StripeError::Stripe(err)) if err.http_status == 404 => Ok(None),
+        res => res.map(|_| None),
+    }
+}
+
+fn event_names(events: &[EventFilter]) -> Vec<&'static str> {
+    let mut names: Vec<_> = events.iter().map(|event| event.as_str()).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DesiredWebhookEndpoint, WebhookEndpointChange, WebhookEndpointReconciler};
+    use crate::{ApiVersion, EventFilter, Metadata, WebhookEndpoint, WebhookEndpointStatus};
+
+    fn endpoint(id: &str, url: &str, events: Vec<EventFilter>) -> WebhookEndpoint {
+        WebhookEndpoint {
+            id: id.parse().unwrap(),
+            url: Some(url.to_string()),
+            enabled_events: Some(events),
+            status: Some(WebhookEndpointStatus::Enabled),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let existing = vec![
+            endpoint("we_1", "https://example.com/billing", vec![EventFilter::InvoicePaid]),
+            WebhookEndpoint {
+                metadata: Some(Metadata::from([("team".into(), "ops".into())])),
+                ..endpoint("we_2", "https://example.com/customers", vec![])
+            },
+            WebhookEndpoint {
+                api_version: Some(ApiVersion::V2020_08_27),
+                ..endpoint("we_3", "https://example.com/connect", vec![EventFilter::All])
+            },
+            endpoint("we_4", "https://example.com/old", vec![EventFilter::All]),
+        ];
+        let reconciler = WebhookEndpointReconciler::new([
+            DesiredWebhookEndpoint::new(
+                "https://example.com/billing",
+                vec![EventFilter::InvoicePaid],
+            ),
+            DesiredWebhookEndpoint::new(
+                "https://example.com/customers",
+                vec![EventFilter::CustomerCreated],
+            )
+            .description("customers"),
+            DesiredWebhookEndpoint::new("https://example.com/connect", vec![EventFilter::All])
+                .api_version(ApiVersion::V2023_10_16),
+            DesiredWebhookEndpoint::new("https://example.com/new", vec![EventFilter::All]),
+        ]);
+
+        let plan = reconciler.diff(&existing);
+        assert_eq!(plan.changes.len(), 3);
+        assert!(matches!(
+            &plan.changes[0],
+            WebhookEndpointChange::Update { id, fields, removed_metadata, .. }
+                if id.as_str() == "we_2"
+                    && fields == &["enabled_events", "description", "metadata"]
+                    && removed_metadata == &["team"]
+        ));
+        assert!(matches!(
+            &plan.changes[1],
+            WebhookEndpointChange::Replace { id, .. } if id.as_str() == "we_3"
+        ));
+        assert!(matches!(
+            &plan.changes[2],
+            WebhookEndpointChange::Create(desired) if desired.url == "https://example.com/new"
+        ));
+
+        // the endpoints which aren't desired are only deleted when pruning
+        let plan = reconciler.prune(true).diff(&existing);
+        assert!(matches!(
+            plan.changes.last(),
+            Some(WebhookEndpointChange::Delete { id, .. }) if id.as_str() == "we_4"
+        ));
+        assert_eq!(
+            plan.to_string().lines().filter_map(|line| line.split(' ').next()).collect::<Vec<_>>(),
+            ["~", "-/+", "+", "-"]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn apply() {
+        use httpmock::prelude::*;
+
+        use crate::Client;
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        let list = server.mock(|when, then| {
+            when.method(GET).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "url": "/v1/webhook_endpoints",
+                "has_more": false,
+                "data": [{
+                    "id": "we_1",
+                    "object": "webhook_endpoint",
+                    "url": "https://example.com/old",
+                    "enabled_events": ["*"],
+                    "status": "enabled"
+                }]
+            }));
+        });
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "id": "we_2",
+                "object": "webhook_endpoint",
+                "url": "https://example.com/new",
+                "enabled_events": ["invoice.paid"],
+                "secret": "whsec_123",
+                "status": "enabled"
+            }));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/v1/webhook_endpoints/we_1");
+            then.status(404).json_body(serde_json::json!({
+                "error": { "type": "invalid_request_error", "code": "resource_missing" }
+            }));
+        });
+
+        let reconciler = WebhookEndpointReconciler::new([DesiredWebhookEndpoint::new(
+            "https://example.com/new",
+            vec![EventFilter::InvoicePaid],
+        )])
+        .prune(true);
+        let plan = reconciler.plan(&client).await.unwrap();
+        let created = reconciler.apply(&client, &plan).await.unwrap();
+
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].id.as_str(), "we_2");
+        assert_eq!(created[0].secret.as_deref(), Some("whsec_123"));
+        list.assert_hits_async(2).await;
+        create.assert_hits_async(1).await;
+        delete.assert_hits_async(1).await;
+
+        // applying the plan again doesn't create the endpoint twice
+        list.delete_async().await;
+        let list = server.mock(|when, then| {
+            when.method(GET).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "url": "/v1/webhook_endpoints",
+                "has_more": false,
+                "data": [{
+                    "id": "we_2",
+                    "object": "webhook_endpoint",
+                    "url": "https://example.com/new",
+                    "enabled_events": ["invoice.paid"],
+                    "status": "enabled"
+                }]
+            }));
+        });
+        let created = reconciler.apply(&client, &plan).await.unwrap();
+
+        assert!(created.is_empty());
+        list.assert_hits_async(1).await;
+        create.assert_hits_async(1).await;
+        delete.assert_hits_async(2).await;
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn apply_blocking() {
+        use httpmock::prelude::*;
+
+        use crate::Client;
+
+        let server = MockServer::start();
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        let mut list = server.mock(|when, then| {
+            when.method(GET).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "url": "/v1/webhook_endpoints",
+                "has_more": false,
+                "data": []
+            }));
+        });
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "id": "we_1",
+                "object": "webhook_endpoint",
+                "url": "https://example.com/new",
+                "enabled_events": ["invoice.paid"],
+                "secret": "whsec_123",
+                "status": "enabled"
+            }));
+        });
+
+        let reconciler = WebhookEndpointReconciler::new([DesiredWebhookEndpoint::new(
+            "https://example.com/new",
+            vec![EventFilter::InvoicePaid],
+        )]);
+        let plan = reconciler.plan(&client).unwrap();
+        let created = reconciler.apply(&client, &plan).unwrap();
+
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].secret.as_deref(), Some("whsec_123"));
+        list.assert_hits(2);
+        create.assert_hits(1);
+
+        // applying the plan again lists the endpoints again and doesn't create it twice
+        list.delete();
+        list = server.mock(|when, then| {
+            when.method(GET).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "url": "/v1/webhook_endpoints",
+                "has_more": false,
+                "data": [{
+                    "id": "we_1",
+                    "object": "webhook_endpoint",
+                    "url": "https://example.com/new",
+                    "enabled_events": ["invoice.paid"],
+                    "status": "enabled"
+                }]
+            }));
+        });
+        let created = reconciler.apply(&client, &plan).unwrap();
+
+        assert!(created.is_empty());
+        list.assert_hits(1);
+        create.assert_hits(1);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn apply_update() {
+        use httpmock::prelude::*;
+
+        use crate::Client;
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_123");
+
+        server.mock(|when, then| {
+            when.method(GET).path("/v1/webhook_endpoints");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "url": "/v1/webhook_endpoints",
+                "has_more": false,
+                "data": [{
+                    "id": "we_1",
+                    "object": "webhook_endpoint",
+                    "url": "https://example.com/billing",
+                    "description": "edited by hand",
+                    "enabled_events": ["invoice.paid"],
+                    "status": "enabled"
+                }]
+            }));
+        });
+        // only the events differ, so the description edited by hand is kept
+        let update = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/webhook_endpoints/we_1")
+                .body("enabled_events[0]=invoice.paid&enabled_events[1]=invoice.voided");
+            then.status(200).json_body(serde_json::json!({
+                "id": "we_1",
+                "object": "webhook_endpoint",
+                "url": "https://example.com/billing",
+                "status": "enabled"
+            }));
+        });
+
+        let reconciler = WebhookEndpointReconciler::new([DesiredWebhookEndpoint::new(
+            "https://example.com/billing",
+            vec![EventFilter::InvoicePaid, EventFilter::InvoiceVoided],
+        )
+        .description("edited by hand")]);
+        let plan = reconciler.plan(&client).await.unwrap();
+        assert!(reconciler.apply(&client, &plan).await.unwrap().is_empty());
+        update.assert_hits_async(1).await;
+    }
+}
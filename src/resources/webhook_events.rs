@@ -1,15 +1,21 @@
 use std::collections::HashMap;
+#[cfg(feature = "webhook-events")]
+use std::time::Duration;
 
 use chrono::Utc;
 #[cfg(feature = "webhook-events")]
 use hmac::{Hmac, Mac};
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 #[cfg(feature = "webhook-events")]
 use sha2::Sha256;
 use smart_default::SmartDefault;
 
-use crate::error::WebhookError;
+use crate::error::{ApiVersionMismatch, WebhookError};
+use crate::generated::core::version::VERSION;
+use crate::params::{AsCursor, Object};
 use crate::resources::*;
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash, SmartDefault)]
@@ -420,64 +426,226 @@ pub struct NotificationEventData {
     pub previous_attributes: Option<HashMap<String, Value>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum EventObject {
-    Account(Account),
-    #[serde(rename = "capability")]
-    AccountCapabilities(AccountCapabilities),
-    Application(Application),
-    ApplicationFee(ApplicationFee),
-    #[serde(rename = "fee_refund")]
-    ApplicationFeeRefund(ApplicationFeeRefund),
-    Balance(Balance),
-    BankAccount(BankAccount),
-    #[serde(rename = "billing_portal.configuration")]
-    BillingPortalConfiguration(BillingPortalConfiguration),
-    Card(Card),
-    Charge(Charge),
-    #[serde(rename = "checkout.session")]
-    CheckoutSession(CheckoutSession),
-    Coupon(Coupon),
-    Customer(Customer),
-    Discount(Discount),
-    Dispute(Dispute),
-    File(File),
-    Invoice(Invoice),
-    #[serde(rename = "invoiceitem")]
-    InvoiceItem(InvoiceItem),
-    #[serde(rename = "issuing.authorization")]
-    IssuingAuthorization(IssuingAuthorization),
-    #[serde(rename = "issuing.card")]
-    IssuingCard(IssuingCard),
-    #[serde(rename = "issuing.cardholder")]
-    IssuingCardholder(IssuingCardholder),
-    #[serde(rename = "issuing.dispute")]
-    IssuingDispute(IssuingDispute),
-    #[serde(rename = "issuing.transaction")]
-    IssuingTransaction(IssuingTransaction),
-    Mandate(Mandate),
-    PaymentIntent(PaymentIntent),
-    PaymentLink(PaymentLink),
-    PaymentMethod(PaymentMethod),
-    Payout(Payout),
-    Person(Person),
-    Plan(Plan),
-    Price(Price),
-    Product(Product),
-    PromotionCode(PromotionCode),
-    Quote(Quote),
-    Refund(Refund),
-    Review(Review),
-    SetupIntent(SetupIntent),
-    Subscription(Subscription),
-    SubscriptionSchedule(SubscriptionSchedule),
-    TaxId(TaxId),
-    TaxRate(TaxRate),
-    #[serde(rename = "test_helpers.test_clock")]
-    TestHelpersTestClock(TestHelpersTestClock),
-    Topup(Topup),
-    Transfer(Transfer),
+/// The id of an object which can be the object of an event.
+trait EventObjectId {
+    fn event_object_id(&self) -> Option<String>;
+}
+
+impl<T: Object> EventObjectId for T
+where
+    T::Id: IntoObjectId,
+{
+    fn event_object_id(&self) -> Option<String> {
+        self.id().into_object_id()
+    }
+}
+
+// N.B. capabilities are identified by their account
+impl EventObjectId for AccountCapabilities {
+    fn event_object_id(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The id types of objects, where `()` is used for objects without an id.
+trait IntoObjectId {
+    fn into_object_id(self) -> Option<String>;
+}
+
+impl IntoObjectId for () {
+    fn into_object_id(self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: AsCursor> IntoObjectId for T {
+    fn into_object_id(self) -> Option<String> {
+        Some(self.as_ref().to_string())
+    }
+}
+
+macro_rules! event_objects {
+    ($($(#[$attr:meta])* $variant:ident($type:ident) => $object:literal,)*) => {
+        /// The object an event is about, tagged by its `object` field.
+        ///
+        /// Objects of a type which isn't modelled, or whose feature isn't enabled, are kept
+        /// as [`EventObject::Unknown`] instead of failing to deserialize the event.
+        #[derive(Clone, Debug)]
+        pub enum EventObject {
+            $($(#[$attr])* $variant($type),)*
+            /// An object of a type which isn't modelled by this library.
+            Unknown {
+                /// The value of the `object` field, such as `radar.early_fraud_warning`.
+                object: String,
+                /// The object as it was received.
+                raw: Value,
+            },
+        }
+
+        impl EventObject {
+            /// The value of the `object` field of this object, such as `customer`.
+            pub fn object(&self) -> &str {
+                match self {
+                    $($(#[$attr])* EventObject::$variant(_) => $object,)*
+                    EventObject::Unknown { object, .. } => object,
+                }
+            }
+
+            /// The id of this object, if it has one.
+            pub fn id(&self) -> Option<String> {
+                match self {
+                    $($(#[$attr])* EventObject::$variant(inner) => inner.event_object_id(),)*
+                    EventObject::Unknown { raw, .. } => {
+                        raw.get("id").and_then(Value::as_str).map(str::to_string)
+                    }
+                }
+            }
+        }
+
+        impl Serialize for EventObject {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value = match self {
+                    $($(#[$attr])* EventObject::$variant(inner) => serde_json::to_value(inner),)*
+                    EventObject::Unknown { raw, .. } => return raw.serialize(serializer),
+                };
+                let mut value = value.map_err(S::Error::custom)?;
+                if let Value::Object(fields) = &mut value {
+                    fields.insert("object".to_string(), self.object().into());
+                }
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for EventObject {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let raw = Value::deserialize(deserializer)?;
+                let object = match raw.get("object") {
+                    Some(Value::String(object)) => object.clone(),
+                    _ => return Err(D::Error::missing_field("object")),
+                };
+                // N.B. the path of the error within the object is kept in its message, its
+                // position is added by the deserializer of the event
+                let res = match object.as_str() {
+                    $($(#[$attr])* $object => {
+                        serde_path_to_error::deserialize(raw).map(EventObject::$variant)
+                    })*
+                    _ => return Ok(EventObject::Unknown { object, raw }),
+                };
+                res.map_err(D::Error::custom)
+            }
+        }
+
+        $(
+            $(#[$attr])*
+            impl FromEventObject for $type {
+                const OBJECT: &'static str = $object;
+
+                fn from_event_object(object: EventObject) -> Result<Self, EventObject> {
+                    match object {
+                        EventObject::$variant(inner) => Ok(inner),
+                        object => Err(object),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+event_objects! {
+    Account(Account) => "account",
+    AccountCapabilities(AccountCapabilities) => "capability",
+    #[cfg(feature = "connect")]
+    AccountLink(AccountLink) => "account_link",
+    Application(Application) => "application",
+    ApplicationFee(ApplicationFee) => "application_fee",
+    ApplicationFeeRefund(ApplicationFeeRefund) => "fee_refund",
+    Balance(Balance) => "balance",
+    BalanceTransaction(BalanceTransaction) => "balance_transaction",
+    BankAccount(BankAccount) => "bank_account",
+    BillingPortalConfiguration(BillingPortalConfiguration) => "billing_portal.configuration",
+    #[cfg(feature = "billing")]
+    BillingPortalSession(BillingPortalSession) => "billing_portal.session",
+    Card(Card) => "card",
+    CashBalance(CashBalance) => "cash_balance",
+    Charge(Charge) => "charge",
+    CheckoutSession(CheckoutSession) => "checkout.session",
+    #[cfg(feature = "checkout")]
+    CheckoutSessionItem(CheckoutSessionItem) => "item",
+    ConnectCollectionTransfer(ConnectCollectionTransfer) => "connect_collection_transfer",
+    Coupon(Coupon) => "coupon",
+    #[cfg(feature = "billing")]
+    CreditNote(CreditNote) => "credit_note",
+    #[cfg(feature = "billing")]
+    CreditNoteLineItem(CreditNoteLineItem) => "credit_note_line_item",
+    Customer(Customer) => "customer",
+    #[cfg(feature = "billing")]
+    CustomerBalanceTransaction(CustomerBalanceTransaction) => "customer_balance_transaction",
+    Discount(Discount) => "discount",
+    Dispute(Dispute) => "dispute",
+    EphemeralKey(EphemeralKey) => "ephemeral_key",
+    File(File) => "file",
+    FileLink(FileLink) => "file_link",
+    Invoice(Invoice) => "invoice",
+    InvoiceItem(InvoiceItem) => "invoiceitem",
+    InvoiceLineItem(InvoiceLineItem) => "line_item",
+    IssuingAuthorization(IssuingAuthorization) => "issuing.authorization",
+    IssuingCard(IssuingCard) => "issuing.card",
+    IssuingCardholder(IssuingCardholder) => "issuing.cardholder",
+    IssuingDispute(IssuingDispute) => "issuing.dispute",
+    #[cfg(feature = "issuing")]
+    IssuingToken(IssuingToken) => "issuing.token",
+    IssuingTransaction(IssuingTransaction) => "issuing.transaction",
+    #[cfg(feature = "connect")]
+    LoginLink(LoginLink) => "login_link",
+    Mandate(Mandate) => "mandate",
+    PaymentIntent(PaymentIntent) => "payment_intent",
+    PaymentLink(PaymentLink) => "payment_link",
+    PaymentMethod(PaymentMethod) => "payment_method",
+    Payout(Payout) => "payout",
+    Person(Person) => "person",
+    Plan(Plan) => "plan",
+    PlatformTaxFee(PlatformTaxFee) => "platform_tax_fee",
+    Price(Price) => "price",
+    Product(Product) => "product",
+    PromotionCode(PromotionCode) => "promotion_code",
+    Quote(Quote) => "quote",
+    Refund(Refund) => "refund",
+    ReserveTransaction(ReserveTransaction) => "reserve_transaction",
+    Review(Review) => "review",
+    ScheduledQueryRun(ScheduledQueryRun) => "scheduled_query_run",
+    SetupAttempt(SetupAttempt) => "setup_attempt",
+    SetupIntent(SetupIntent) => "setup_intent",
+    ShippingRate(ShippingRate) => "shipping_rate",
+    Source(Source) => "source",
+    Subscription(Subscription) => "subscription",
+    SubscriptionItem(SubscriptionItem) => "subscription_item",
+    SubscriptionSchedule(SubscriptionSchedule) => "subscription_schedule",
+    #[cfg(feature = "tax-calculation")]
+    TaxCalculation(TaxCalculation) => "tax.calculation",
+    #[cfg(feature = "tax-calculation")]
+    TaxCalculationLineItem(TaxCalculationLineItem) => "tax.calculation_line_item",
+    TaxCode(TaxCode) => "tax_code",
+    TaxDeductedAtSource(TaxDeductedAtSource) => "tax_deducted_at_source",
+    TaxId(TaxId) => "tax_id",
+    TaxRate(TaxRate) => "tax_rate",
+    #[cfg(feature = "terminal")]
+    TerminalConfiguration(TerminalConfiguration) => "terminal.configuration",
+    #[cfg(feature = "terminal")]
+    TerminalConnectionToken(TerminalConnectionToken) => "terminal.connection_token",
+    #[cfg(feature = "terminal")]
+    TerminalLocation(TerminalLocation) => "terminal.location",
+    #[cfg(feature = "terminal")]
+    TerminalReader(TerminalReader) => "terminal.reader",
+    TestHelpersTestClock(TestHelpersTestClock) => "test_helpers.test_clock",
+    Token(Token) => "token",
+    Topup(Topup) => "topup",
+    Transfer(Transfer) => "transfer",
+    TransferReversal(TransferReversal) => "transfer_reversal",
+    #[cfg(feature = "billing")]
+    UsageRecord(UsageRecord) => "usage_record",
+    #[cfg(feature = "billing")]
+    UsageRecordSummary(UsageRecordSummary) => "usage_record_summary",
+    WebhookEndpoint(WebhookEndpoint) => "webhook_endpoint",
 }
 
 impl Default for EventObject {
@@ -486,13 +654,39 @@ impl Default for EventObject {
     }
 }
 
+impl Event {
+    /// Check whether the event was rendered with the api version the models of this crate
+    /// match, returning the mismatch if it wasn't.
+    ///
+    /// The version of webhook events is set by the endpoint, or by the default version of
+    /// the account, so it may differ from the version used by the [`Client`](crate::Client).
+    pub fn api_version_mismatch(&self) -> Option<ApiVersionMismatch> {
+        (self.api_version.as_deref() != Some(VERSION.as_str()))
+            .then(|| ApiVersionMismatch { expected: VERSION, found: self.api_version.clone() })
+    }
+}
+
+/// An object which can be the subject of an event.
+pub trait FromEventObject: Sized {
+    /// The value of the `object` field of this type.
+    const OBJECT: &'static str;
+
+    /// Extract this type from an event object, returning the object if it is of another type.
+    #[allow(clippy::result_large_err)]
+    fn from_event_object(object: EventObject) -> Result<Self, EventObject>;
+}
+
 #[cfg(feature = "webhook-events")]
 pub struct Webhook {
     current_timestamp: i64,
+    tolerance: Duration,
 }
 
 #[cfg(feature = "webhook-events")]
 impl Webhook {
+    /// How far the timestamp of a signature may be from the current time by default.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
     /// Construct an event from a webhook payload and signature.
     ///
     /// # Errors
@@ -500,9 +694,31 @@ impl Webhook {
     /// This function will return a WebhookError if:
     ///  - the provided signature is invalid
     ///  - the provided secret is invalid
-    ///  - the signature timestamp is older than 5 minutes
+    ///  - the signature timestamp is more than 5 minutes away from the current time, in the
+    ///    past or in the future
     pub fn construct_event(payload: &str, sig: &str, secret: &str) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+        Self::now(Self::DEFAULT_TOLERANCE).do_construct_event(payload.as_bytes(), sig, &[secret])
+    }
+
+    /// Construct an event from a webhook payload and signature, rejecting events rendered
+    /// with another api version than the one this crate matches.
+    ///
+    /// Use [`Webhook::construct_event`] and [`Event::api_version_mismatch`] to accept those
+    /// events and only report the mismatch.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - any of the conditions of [`Webhook::construct_event`] apply
+    ///  - the api version of the event is not the version of this crate, or is unknown
+    pub fn construct_event_strict(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+    ) -> Result<Event, WebhookError> {
+        let webhook = Self::now(Self::DEFAULT_TOLERANCE);
+        webhook.do_verify_signature(payload.as_bytes(), sig, &[secret])?;
+        Ok(webhook.verifier(&[secret]).strict(true).parse_event(payload.as_bytes())?)
     }
 
     /// Construct an event from a webhook payload and signature, verifying its signature
@@ -523,69 +739,257 @@ impl Webhook {
         secret: &str,
         timestamp: i64,
     ) -> Result<Event, WebhookError> {
-        Self { current_timestamp: timestamp }.do_construct_event(payload, sig, secret)
+        Self { current_timestamp: timestamp, tolerance: Self::DEFAULT_TOLERANCE }
+            .do_construct_event(payload.as_bytes(), sig, &[secret])
     }
 
-    fn do_construct_event(
-        self,
-        payload: &str,
+    /// Construct an event from the raw bytes of a webhook payload, accepting a signature
+    /// made with any of the provided secrets.
+    ///
+    /// This allows an endpoint secret to be rolled without rejecting any events: stripe
+    /// signs events with both the old and the new secret until the old one expires.
+    ///
+    /// Events of any api version are accepted, use [`WebhookVerifier::strict`] to reject
+    /// the events of other versions.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    /// - none of the signatures match any of the secrets
+    /// - one of the provided secrets is invalid
+    /// - the signature timestamp is further than `tolerance` from the current time, in the
+    ///   past or in the future
+    pub fn construct_event_with_secrets(
+        payload: &[u8],
         sig: &str,
-        secret: &str,
+        secrets: &[&str],
+        tolerance: Duration,
     ) -> Result<Event, WebhookError> {
-        // Get Stripe signature from header
-        let signature = Signature::parse(sig)?;
-        let signed_payload = format!("{}.{}", signature.t, payload);
+        Self::now(tolerance).do_construct_event(payload, sig, secrets)
+    }
+
+    /// Verify the signature of the raw bytes of a webhook payload without parsing it,
+    /// accepting a signature made with any of the provided secrets.
+    ///
+    /// # Errors
+    ///
+    /// See [`Webhook::construct_event_with_secrets`].
+    pub fn verify_signature(
+        payload: &[u8],
+        sig: &str,
+        secrets: &[&str],
+        tolerance: Duration,
+    ) -> Result<(), WebhookError> {
+        Self::now(tolerance).do_verify_signature(payload, sig, secrets)
+    }
 
-        // Compute HMAC with the SHA256 hash function, using endpoing secret as key
-        // and signed_payload string as the message.
+    /// Compute the `v1` signature stripe would send for a payload, as a hex string.
+    ///
+    /// This is helpful for testing webhook handlers, see [`Webhook::generate_test_header`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`WebhookError::BadKey`] if the secret is invalid.
+    pub fn sign(
+        payload: impl AsRef<[u8]>,
+        secret: &str,
+        timestamp: i64,
+    ) -> Result<String, WebhookError> {
+        let mac = Self::mac(payload.as_ref(), secret, timestamp)?;
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// Generate a valid `Stripe-Signature` header for a payload, like the Stripe CLI does
+    /// when forwarding events.
+    ///
+    /// ```
+    /// use stripe::Webhook;
+    ///
+    /// let payload = r#"{"id": "evt_123", "object": "event", "type": "customer.created"}"#;
+    /// let header = Webhook::generate_test_header(payload, "whsec_123", 1533204620).unwrap();
+    /// assert!(header.starts_with("t=1533204620,v1="));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`Webhook::sign`].
+    pub fn generate_test_header(
+        payload: impl AsRef<[u8]>,
+        secret: &str,
+        timestamp: i64,
+    ) -> Result<String, WebhookError> {
+        Ok(format!("t={},v1={}", timestamp, Self::sign(payload, secret, timestamp)?))
+    }
+
+    /// Compute the HMAC of a payload with the SHA256 hash function, using the endpoint
+    /// secret as key and `{timestamp}.{payload}` as the message.
+    pub(crate) fn mac(
+        payload: &[u8],
+        secret: &str,
+        timestamp: i64,
+    ) -> Result<Hmac<Sha256>, WebhookError> {
         let mut mac =
             Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
-        mac.update(signed_payload.as_bytes());
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        Ok(mac)
+    }
 
-        let sig = hex::decode(signature.v1).map_err(|_| WebhookError::BadSignature)?;
+    fn now(tolerance: Duration) -> Self {
+        Self { current_timestamp: Utc::now().timestamp(), tolerance }
+    }
 
-        mac.verify_slice(sig.as_slice()).map_err(|_| WebhookError::BadSignature)?;
+    fn do_construct_event(
+        self,
+        payload: &[u8],
+        sig: &str,
+        secrets: &[&str],
+    ) -> Result<Event, WebhookError> {
+        self.do_verify_signature(payload, sig, secrets)?;
+        Ok(serde_json::from_slice(payload)?)
+    }
 
-        // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
-            return Err(WebhookError::BadTimestamp(signature.t));
-        }
+    fn do_verify_signature(
+        &self,
+        payload: &[u8],
+        sig: &str,
+        secrets: &[&str],
+    ) -> Result<(), WebhookError> {
+        // N.B. the header is parsed here to keep the errors of `Signature::parse`
+        let signature = Signature::parse(sig)?;
+        Ok(self.verifier(secrets).verify_signature(payload, &signature)?)
+    }
 
-        Ok(serde_json::from_str(payload)?)
+    /// A verifier for the given secrets, using the timestamp and tolerance of this webhook.
+    fn verifier(&self, secrets: &[&str]) -> WebhookVerifier {
+        let current_timestamp = self.current_timestamp;
+        WebhookVerifier::with_secrets(secrets)
+            .tolerance(self.tolerance)
+            .clock(move || current_timestamp)
     }
 }
 
 #[cfg(feature = "webhook-events")]
 #[derive(Debug)]
-struct Signature<'r> {
-    t: i64,
-    v1: &'r str,
+pub(crate) struct Signature<'r> {
+    pub(crate) t: i64,
+    pub(crate) v1: Vec<&'r str>,
 }
 
 #[cfg(feature = "webhook-events")]
 impl<'r> Signature<'r> {
-    fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
-        let headers: HashMap<&str, &str> = raw
-            .split(',')
-            .map(|header| {
-                let mut key_and_value = header.split('=');
-                let key = key_and_value.next();
-                let value = key_and_value.next();
-                (key, value)
-            })
-            .filter_map(|(key, value)| match (key, value) {
-                (Some(key), Some(value)) => Some((key, value)),
-                _ => None,
-            })
-            .collect();
-        let t = headers.get("t").ok_or(WebhookError::BadSignature)?;
-        let v1 = headers.get("v1").ok_or(WebhookError::BadSignature)?;
+    pub(crate) fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
+        let mut t = None;
+        let mut v1 = Vec::new();
+        for (key, value) in raw.split(',').filter_map(|header| header.trim().split_once('=')) {
+            match key {
+                "t" => t = Some(value),
+                "v1" => v1.push(value),
+                _ => {}
+            }
+        }
+        let t = t.ok_or(WebhookError::BadSignature)?;
+        if v1.is_empty() {
+            return Err(WebhookError::BadSignature);
+        }
         Ok(Signature { t: t.parse::<i64>().map_err(WebhookError::BadHeader)?, v1 })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_unknown_event_object() {
+        use super::EventObject;
+        use crate::{Event, EventType};
+
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "issfr_123",
+      "object": "radar.early_fraud_warning",
+      "fraud_type": "misc"
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "radar.early_fraud_warning.created"
+}"#;
+        let event: Event = serde_json::from_str(payload).unwrap();
+        assert_eq!(event.type_, EventType::RadarEarlyFraudWarningCreated);
+        assert_eq!(event.data.object.object(), "radar.early_fraud_warning");
+        assert_eq!(event.data.object.id().as_deref(), Some("issfr_123"));
+        match &event.data.object {
+            EventObject::Unknown { raw, .. } => assert_eq!(raw["fraud_type"], "misc"),
+            object => panic!("unexpected object {:?}", object),
+        }
+
+        // unknown objects are serialized as they were received
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["data"]["object"]["fraud_type"], "misc");
+        assert_eq!(value["data"]["object"]["object"], "radar.early_fraud_warning");
+    }
+
+    #[test]
+    fn test_event_object_round_trip() {
+        use super::EventObject;
+
+        let object: EventObject = serde_json::from_value(serde_json::json!({
+            "id": "tok_123",
+            "object": "token",
+            "created": 1533204620,
+            "livemode": false,
+            "type": "card",
+            "used": false
+        }))
+        .unwrap();
+        assert!(matches!(&object, EventObject::Token(token) if token.id.as_str() == "tok_123"));
+        assert_eq!(object.id().as_deref(), Some("tok_123"));
+
+        let value = serde_json::to_value(&object).unwrap();
+        assert_eq!(value["object"], "token");
+        assert_eq!(value["id"], "tok_123");
+
+        // objects of a known type must still be valid
+        let res = serde_json::from_value::<EventObject>(serde_json::json!({
+            "object": "token",
+            "id": 123
+        }));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_event_object_error_path() {
+        use crate::Event;
+
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "tok_123",
+      "object": "token",
+      "created": 1533204620,
+      "livemode": false,
+      "type": "card",
+      "used": false,
+      "card": {"id": "card_123", "exp_month": "twelve"}
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "customer.source.created"
+}"#;
+        let err = serde_json::from_str::<Event>(payload).unwrap_err();
+        assert!(err.to_string().starts_with("card.exp_month: invalid type"), "{}", err);
+        assert!(err.line() > 1);
+    }
+
     #[cfg(feature = "webhook-events")]
     #[test]
     fn test_signature_parse() {
@@ -597,7 +1001,7 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            ["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
 
         let raw_signature_with_test_mode = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
@@ -605,7 +1009,7 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            ["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
     }
 
@@ -652,15 +1056,138 @@ mod tests {
         let secret = "webhook_secret".to_string();
         let signature = format!("t={},v1=82216eca827bcb7b34b8055eb2d2d9e6bc13b9ac39ded14a61e69f70c565f53a,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
 
-        let webhook = super::Webhook { current_timestamp: event_timestamp };
+        let webhook = super::Webhook {
+            current_timestamp: event_timestamp,
+            tolerance: super::Webhook::DEFAULT_TOLERANCE,
+        };
 
         let event = webhook
-            .do_construct_event(payload, &signature, &secret)
+            .do_construct_event(payload.as_bytes(), &signature, &[&secret])
             .expect("Failed to construct event");
 
         assert_eq!(event.type_, super::EventType::InvoiceItemCreated);
         assert_eq!(event.id, "evt_123".parse::<crate::EventId>().unwrap());
         assert_eq!(event.account, "acct_123".parse().ok());
         assert_eq!(event.created, 1533204620);
+
+        // the signature matches the one computed by stripe
+        assert!(
+            signature.contains(&super::Webhook::sign(payload, &secret, event_timestamp).unwrap())
+        );
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_signature_parse_multiple() {
+        use super::Signature;
+
+        let signature = Signature::parse("t=1492774577, v1=abc,v1=def,v0=123").unwrap();
+        assert_eq!(signature.t, 1492774577);
+        assert_eq!(signature.v1, ["abc", "def"]);
+
+        assert!(Signature::parse("t=1492774577,v0=123").is_err());
+        assert!(Signature::parse("v1=abc").is_err());
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_secret_rotation() {
+        use std::time::Duration;
+
+        use super::Webhook;
+        use crate::WebhookError;
+
+        let payload = r#"{"id": "evt_123", "object": "event", "type": "invoiceitem.created"}"#;
+        let timestamp = 1533204620;
+        let webhook = |tolerance| Webhook { current_timestamp: timestamp + 100, tolerance };
+
+        // stripe signs the event with both secrets while the old one is rolled
+        let header = format!(
+            "t={},v1={},v1={}",
+            timestamp,
+            Webhook::sign(payload, "old_secret", timestamp).unwrap(),
+            Webhook::sign(payload, "new_secret", timestamp).unwrap(),
+        );
+
+        for secrets in [&["new_secret"][..], &["old_secret"], &["other_secret", "new_secret"]] {
+            assert!(webhook(Duration::from_secs(300))
+                .do_verify_signature(payload.as_bytes(), &header, secrets)
+                .is_ok());
+        }
+
+        let res = webhook(Duration::from_secs(300)).do_verify_signature(
+            payload.as_bytes(),
+            &header,
+            &["other_secret"],
+        );
+        assert!(matches!(res, Err(WebhookError::BadSignature)));
+
+        let res = webhook(Duration::from_secs(60)).do_verify_signature(
+            payload.as_bytes(),
+            &header,
+            &["new_secret"],
+        );
+        assert!(matches!(res, Err(WebhookError::BadTimestamp(t)) if t == timestamp));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_non_utf8_payload() {
+        use super::Webhook;
+        use crate::WebhookError;
+
+        let payload = b"{\"id\": \"\xff\"}";
+        let timestamp = 1533204620;
+        let mut signature = Webhook::sign(payload, "secret", timestamp).unwrap();
+        let header = format!("t={},v1={}", timestamp, signature);
+        let webhook =
+            Webhook { current_timestamp: timestamp, tolerance: Webhook::DEFAULT_TOLERANCE };
+
+        assert!(webhook.do_verify_signature(payload, &header, &["secret"]).is_ok());
+
+        // a tampered signature is rejected
+        signature.replace_range(..2, "00");
+        let header = format!("t={},v1={}", timestamp, signature);
+        let res = webhook.do_verify_signature(payload, &header, &["secret"]);
+        assert!(matches!(res, Err(WebhookError::BadSignature)));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_api_version() {
+        use super::{Webhook, VERSION};
+        use crate::{
+            ApiVersionMismatch, Customer, EventObject, EventType, TestEvent, WebhookError,
+        };
+
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let event = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .into_event();
+        let sign = |payload: &str| {
+            Webhook::generate_test_header(payload, "secret", chrono::Utc::now().timestamp())
+                .unwrap()
+        };
+
+        let payload = serde_json::to_string(&event).unwrap();
+        let mut event =
+            Webhook::construct_event_strict(&payload, &sign(&payload), "secret").unwrap();
+        assert_eq!(event.api_version_mismatch(), None);
+
+        event.api_version = Some("2017-05-25".to_string());
+        let payload = serde_json::to_string(&event).unwrap();
+        let mismatch =
+            ApiVersionMismatch { expected: VERSION, found: Some("2017-05-25".to_string()) };
+
+        // the lenient mode only reports the mismatch
+        let event = Webhook::construct_event(&payload, &sign(&payload), "secret").unwrap();
+        assert_eq!(event.api_version_mismatch(), Some(mismatch.clone()));
+
+        let res = Webhook::construct_event_strict(&payload, &sign(&payload), "secret");
+        assert!(matches!(res, Err(WebhookError::ApiVersionMismatch(err)) if err == mismatch));
+
+        // the mismatch is reported even if the event doesn't parse
+        let payload = r#"{"id": "evt_123", "api_version": "2017-05-25", "data": 123}"#;
+        let res = Webhook::construct_event_strict(payload, &sign(payload), "secret");
+        assert!(matches!(res, Err(WebhookError::ApiVersionMismatch(err)) if err == mismatch));
     }
 }
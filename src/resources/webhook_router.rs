@@ -0,0 +1,547 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use serde_json::Value;
+
+use crate::error::EventRouterError;
+use crate::ids::EventId;
+use crate::params::Timestamp;
+use crate::resources::{
+    Event, EventStatus, EventType, FromEventObject, NotificationEventData,
+    NotificationEventRequest, ProcessedEventStore,
+};
+
+/// An event whose object has been extracted as the type `T`.
+#[derive(Clone, Debug)]
+pub struct TypedEvent<T> {
+    /// Unique identifier for the event.
+    pub id: EventId,
+    /// The connected account that originated the event.
+    pub account: Option<String>,
+    /// The Stripe API version used to render the object.
+    pub api_version: Option<String>,
+    /// Time at which the event was created.
+    pub created: Timestamp,
+    /// Whether the event happened in live mode.
+    pub livemode: bool,
+    /// Information on the API request that triggered the event.
+    pub request: Option<NotificationEventRequest>,
+    /// The type of the event.
+    pub type_: EventType,
+    /// The object the event is about.
+    pub object: T,
+    /// The previous values of the attributes which changed, for `*.updated` events.
+    pub previous_attributes: Option<HashMap<String, Value>>,
+}
+
+impl<T: FromEventObject> TypedEvent<T> {
+    /// Extract the object of an event as the type `T`, returning the event if it
+    /// has an object of another type.
+    #[allow(clippy::result_large_err)]
+    pub fn from_event(event: Event) -> Result<Self, Event> {
+        let Event {
+            id,
+            account,
+            api_version,
+            created,
+            data,
+            livemode,
+            pending_webhooks,
+            request,
+            type_,
+        } = event;
+        match T::from_event_object(data.object) {
+            Ok(object) => Ok(Self {
+                id,
+                account,
+                api_version,
+                created,
+                livemode,
+                request,
+                type_,
+                object,
+                previous_attributes: data.previous_attributes,
+            }),
+            Err(object) => Err(Event {
+                id,
+                account,
+                api_version,
+                created,
+                data: NotificationEventData {
+                    object,
+                    previous_attributes: data.previous_attributes,
+                },
+                livemode,
+                pending_webhooks,
+                request,
+                type_,
+            }),
+        }
+    }
+}
+
+type HandlerFuture<E> = Pin<Box<dyn Future<Output = Result<(), EventRouterError<E>>> + Send>>;
+type Handler<E> = Box<dyn Fn(Event) -> HandlerFuture<E> + Send + Sync>;
+
+/// Dispatches webhook events to async handlers, which receive the object of the event
+/// as the type they expect.
+///
+/// Handlers can be registered for a single [`EventType`] or for a family of events, such
+/// as `customer.subscription.*`. An event is passed to the handler for its type if there
+/// is one, then to the most specific family which matches it, and finally to the fallback.
+/// Events without any handler are ignored unless a fallback is set.
+///
+/// ```
+/// use stripe::{Event, EventRouter, EventType, Invoice, Subscription, TypedEvent};
+///
+/// # async fn run(event: Event) -> Result<(), stripe::EventRouterError<std::io::Error>> {
+/// let router = EventRouter::new()
+///     .on(EventType::InvoicePaid, |event: TypedEvent<Invoice>| async move {
+///         println!("invoice {} was paid", event.object.id);
+///         Ok(())
+///     })
+///     .on_family("customer.subscription.*", |event: TypedEvent<Subscription>| async move {
+///         println!("subscription {} is now {:?}", event.object.id, event.object.status);
+///         Ok(())
+///     })
+///     .fallback(|event: Event| async move {
+///         println!("unhandled event {}", event.type_);
+///         Ok(())
+///     });
+///
+/// router.dispatch(event).await
+/// # }
+/// ```
+pub struct EventRouter<E> {
+    handlers: HashMap<EventType, Handler<E>>,
+    families: Vec<(String, Handler<E>)>,
+    fallback: Option<Handler<E>>,
+    skip_stale: bool,
+}
+
+/// What [`EventRouter::dispatch_once`] did with an event.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Dispatched {
+    /// The event was passed to its handler.
+    Handled,
+    /// The event was processed before, so it was skipped.
+    Duplicate,
+    /// The event is being processed by another delivery, so it was skipped.
+    ///
+    /// The other delivery may still fail, so this should be answered with a non-2xx
+    /// status, which makes stripe deliver the event again later.
+    InProgress,
+    /// A newer event was processed for the same object, so the event was skipped.
+    Stale,
+}
+
+impl<E: Send + 'static> EventRouter<E> {
+    /// Create a router without any handlers.
+    pub fn new() -> Self {
+        Self { handlers: HashMap::new(), families: Vec::new(), fallback: None, skip_stale: false }
+    }
+
+    /// Handle events of the given type, replacing any handler already registered for it.
+    pub fn on<T, F, Fut>(mut self, type_: EventType, handler: F) -> Self
+    where
+        T: FromEventObject + Send + 'static,
+        F: Fn(TypedEvent<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.handlers.insert(type_, typed_handler(handler));
+        self
+    }
+
+    /// Handle the events of a family, given as a prefix of their type such as
+    /// `customer.subscription.*`, replacing any handler already registered for it.
+    pub fn on_family<T, F, Fut>(mut self, family: &str, handler: F) -> Self
+    where
+        T: FromEventObject + Send + 'static,
+        F: Fn(TypedEvent<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        let mut prefix = family.trim_end_matches('*').to_string();
+        if !prefix.ends_with('.') {
+            prefix.push('.');
+        }
+        self.families.retain(|(existing, _)| *existing != prefix);
+        self.families.push((prefix, typed_handler(handler)));
+        // the most specific family is tried first
+        self.families.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        self
+    }
+
+    /// Handle the events which no other handler matches.
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.fallback = Some(Box::new(move |event| {
+            let fut = handler(event);
+            Box::pin(async move { fut.await.map_err(EventRouterError::Handler) })
+        }));
+        self
+    }
+
+    /// Skip the events created before the newest event processed for the same object,
+    /// when dispatching with [`EventRouter::dispatch_once`].
+    ///
+    /// Stripe doesn't guarantee the order in which events are delivered, so this avoids
+    /// overwriting newer state with an older event. Defaults to false.
+    pub fn skip_stale(mut self, skip_stale: bool) -> Self {
+        self.skip_stale = skip_stale;
+        self
+    }
+
+    /// Pass an event to the handler registered for it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the object of the event is not of the
+    /// type its handler expects, or if the handler fails.
+    pub async fn dispatch(&self, event: Event) -> Result<(), EventRouterError<E>> {
+        let handler = self.handlers.get(&event.type_).or_else(|| {
+            let name = serde_json::to_value(event.type_).ok()?;
+            let name = name.as_str()?;
+            self.families
+                .iter()
+                .find(|(prefix, _)| name.starts_with(prefix.as_str()))
+                .map(|(_, handler)| handler)
+        });
+
+        match handler.or(self.fallback.as_ref()) {
+            Some(handler) => handler(event).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Pass an event to the handler registered for it, unless the store shows it was
+    /// processed before.
+    ///
+    /// The event is claimed in the store first, so that concurrent deliveries of an event
+    /// only run its handler once. The outcome is recorded in the store: events which were
+    /// handled successfully are skipped when they are delivered again, while failed events
+    /// are handled again. If the returned future is dropped, or the handler panics, before
+    /// the outcome is recorded, the claim is released so that the event is handled again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the store fails, or for the same reasons as
+    /// [`EventRouter::dispatch`]. If the handler fails, its error is returned even if
+    /// recording the failure fails too.
+    pub async fn dispatch_once<S>(
+        &self,
+        store: &S,
+        event: Event,
+    ) -> Result<Dispatched, EventRouterError<E>>
+    where
+        S: ProcessedEventStore,
+    {
+        let id = event.id.clone();
+        let created = event.created;
+        if !store.try_begin(&id).await.map_err(EventRouterError::Store)? {
+            let status = store.status(&id).await.map_err(EventRouterError::Store)?;
+            return Ok(match status {
+                Some(EventStatus::Processed) => Dispatched::Duplicate,
+                _ => Dispatched::InProgress,
+            });
+        }
+        // releases the claim if this future is dropped or the handler panics before the
+        // outcome is recorded, so that the event is processed when it is redelivered
+        let claim = Claim { store, id: &id, recorded: false };
+
+        let object = event.data.object.id();
+        if let (true, Some(object)) = (self.skip_stale, &object) {
+            let last = store.last_processed(object).await.map_err(EventRouterError::Store)?;
+            if last.is_some_and(|last| last > created) {
+                store
+                    .record_processed(&id, None, created)
+                    .await
+                    .map_err(EventRouterError::Store)?;
+                claim.recorded();
+                return Ok(Dispatched::Stale);
+            }
+        }
+
+        match self.dispatch(event).await {
+            Ok(()) => {
+                store
+                    .record_processed(&id, object.as_deref(), created)
+                    .await
+                    .map_err(EventRouterError::Store)?;
+                claim.recorded();
+                Ok(Dispatched::Handled)
+            }
+            Err(err) => {
+                if store.record_failed(&id).await.is_ok() {
+                    claim.recorded();
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+/// The claim of [`EventRouter::dispatch_once`] on an event, which is released when it is
+/// dropped before the outcome of the event is recorded.
+struct Claim<'a, S: ProcessedEventStore> {
+    store: &'a S,
+    id: &'a EventId,
+    recorded: bool,
+}
+
+impl<S: ProcessedEventStore> Claim<'_, S> {
+    fn recorded(mut self) {
+        self.recorded = true;
+    }
+}
+
+impl<S: ProcessedEventStore> Drop for Claim<'_, S> {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.store.release(self.id);
+        }
+    }
+}
+
+impl<E: Send + 'static> Default for EventRouter<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> std::fmt::Debug for EventRouter<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventRouter")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field("families", &self.families.iter().map(|(prefix, _)| prefix).collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .field("skip_stale", &self.skip_stale)
+            .finish()
+    }
+}
+
+/// Erase the type of a handler, extracting the object it expects from each event.
+fn typed_handler<T, E, F, Fut>(handler: F) -> Handler<E>
+where
+    T: FromEventObject + Send + 'static,
+    E: Send + 'static,
+    F: Fn(TypedEvent<T>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+{
+    Box::new(move |event| match TypedEvent::<T>::from_event(event) {
+        Ok(event) => {
+            let fut = handler(event);
+            Box::pin(async move { fut.await.map_err(EventRouterError::Handler) })
+        }
+        Err(event) => {
+            let err = EventRouterError::ObjectMismatch {
+                type_: event.type_,
+                expected: T::OBJECT,
+                found: event.data.object.object().to_string(),
+            };
+            Box::pin(std::future::ready(Err(err)))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{EventRouter, TypedEvent};
+    use crate::{
+        Customer, Event, EventObject, EventRouterError, EventType, Subscription,
+        SubscriptionStatus, TestEvent,
+    };
+
+    fn subscription_event(type_: EventType) -> Event {
+        let subscription = Subscription {
+            id: "sub_123".parse().unwrap(),
+            status: SubscriptionStatus::Active,
+            ..Default::default()
+        };
+        TestEvent::new(type_, EventObject::Subscription(subscription)).into_event()
+    }
+
+    #[tokio::test]
+    async fn dispatch() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let record = |name: &'static str| {
+            let calls = calls.clone();
+            move || calls.lock().unwrap().push(name)
+        };
+
+        let (updated, family, fallback) = (record("updated"), record("family"), record("fallback"));
+        let router = EventRouter::<()>::new()
+            .on(EventType::CustomerSubscriptionUpdated, move |event: TypedEvent<Subscription>| {
+                assert_eq!(event.object.status, SubscriptionStatus::Active);
+                updated();
+                async { Ok(()) }
+            })
+            .on_family("customer.subscription.*", move |event: TypedEvent<Subscription>| {
+                assert_eq!(event.object.id.as_str(), "sub_123");
+                family();
+                async { Ok(()) }
+            })
+            .fallback(move |_: Event| {
+                fallback();
+                async { Ok(()) }
+            });
+
+        for type_ in [
+            EventType::CustomerSubscriptionUpdated,
+            EventType::CustomerSubscriptionCreated,
+            EventType::CustomerCreated,
+        ] {
+            router.dispatch(subscription_event(type_)).await.unwrap();
+        }
+
+        assert_eq!(*calls.lock().unwrap(), ["updated", "family", "fallback"]);
+    }
+
+    #[tokio::test]
+    async fn dispatch_errors() {
+        let router = EventRouter::new()
+            .on(EventType::CustomerCreated, |_: TypedEvent<Customer>| async { Ok(()) })
+            .on_family("customer.subscription", |_: TypedEvent<Subscription>| async {
+                Err("failed")
+            });
+
+        let res = router.dispatch(subscription_event(EventType::CustomerCreated)).await;
+        assert!(matches!(
+            res,
+            Err(EventRouterError::ObjectMismatch {
+                type_: EventType::CustomerCreated,
+                expected: "customer",
+                ref found,
+            }) if found == "subscription"
+        ));
+
+        let res = router.dispatch(subscription_event(EventType::CustomerSubscriptionPaused)).await;
+        assert!(matches!(res, Err(EventRouterError::Handler("failed"))));
+
+        // events without a handler are ignored
+        let res = router.dispatch(subscription_event(EventType::InvoicePaid)).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn dispatch_once() {
+        use crate::{Dispatched, EventStatus, InMemoryEventStore, ProcessedEventStore};
+
+        let calls = Arc::new(Mutex::new(0));
+        let router = {
+            let calls = calls.clone();
+            EventRouter::new().skip_stale(true).on_family(
+                "customer.subscription",
+                move |_: TypedEvent<Subscription>| {
+                    *calls.lock().unwrap() += 1;
+                    let failed = *calls.lock().unwrap() == 1;
+                    async move {
+                        if failed {
+                            Err("failed")
+                        } else {
+                            Ok(())
+                        }
+                    }
+                },
+            )
+        };
+        let store = InMemoryEventStore::new();
+        let event = |id: &str, created| {
+            let mut event = subscription_event(EventType::CustomerSubscriptionUpdated);
+            event.id = id.parse().unwrap();
+            event.created = created;
+            event
+        };
+
+        // failed events are handled again when they are redelivered
+        let res = router.dispatch_once(&store, event("evt_2", 20)).await;
+        assert!(matches!(res, Err(EventRouterError::Handler("failed"))));
+        let status = store.status(&"evt_2".parse().unwrap()).await.unwrap();
+        assert_eq!(status, Some(EventStatus::Failed { attempts: 1 }));
+
+        let res = router.dispatch_once(&store, event("evt_2", 20)).await;
+        assert_eq!(res.unwrap(), Dispatched::Handled);
+        let res = router.dispatch_once(&store, event("evt_2", 20)).await;
+        assert_eq!(res.unwrap(), Dispatched::Duplicate);
+
+        // an older event for the same subscription arrived late
+        let res = router.dispatch_once(&store, event("evt_1", 10)).await;
+        assert_eq!(res.unwrap(), Dispatched::Stale);
+        assert_eq!(store.last_processed("sub_123").await.unwrap(), Some(20));
+
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn dispatch_once_concurrently() {
+        use std::time::Duration;
+
+        use crate::{Dispatched, InMemoryEventStore};
+
+        let router = EventRouter::<String>::new().fallback(|_| async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(())
+        });
+        let store = InMemoryEventStore::new();
+        let event = subscription_event(EventType::CustomerSubscriptionUpdated);
+
+        // the second delivery arrives while the first one is being handled
+        let (first, second) = tokio::join!(
+            router.dispatch_once(&store, event.clone()),
+            router.dispatch_once(&store, event.clone())
+        );
+        assert_eq!(first.unwrap(), Dispatched::Handled);
+        assert_eq!(second.unwrap(), Dispatched::InProgress);
+
+        let res = router.dispatch_once(&store, event).await;
+        assert_eq!(res.unwrap(), Dispatched::Duplicate);
+    }
+
+    #[tokio::test]
+    async fn dispatch_once_cancelled() {
+        use std::time::Duration;
+
+        use crate::{Dispatched, InMemoryEventStore};
+
+        let router = Arc::new(EventRouter::<String>::new().fallback(|event: Event| async move {
+            match event.id.as_str() {
+                "evt_slow" => tokio::time::sleep(Duration::from_secs(60)).await,
+                "evt_panic" => panic!("handler panicked"),
+                _ => {}
+            }
+            Ok(())
+        }));
+        let store = Arc::new(InMemoryEventStore::new());
+        let event = |id: &str| {
+            let mut event = subscription_event(EventType::CustomerSubscriptionUpdated);
+            event.id = id.parse().unwrap();
+            event
+        };
+
+        // the connection was closed while the event was being handled
+        let res = tokio::time::timeout(
+            Duration::from_millis(10),
+            router.dispatch_once(&*store, event("evt_slow")),
+        )
+        .await;
+        assert!(res.is_err());
+
+        let handle = tokio::spawn({
+            let (router, store) = (router.clone(), store.clone());
+            async move { router.dispatch_once(&*store, event("evt_panic")).await }
+        });
+        assert!(handle.await.unwrap_err().is_panic());
+
+        // the claims were released, so the redeliveries are handled
+        let router = EventRouter::<String>::new().fallback(|_| async { Ok(()) });
+        for id in ["evt_slow", "evt_panic"] {
+            let res = router.dispatch_once(&*store, event(id)).await;
+            assert_eq!(res.unwrap(), Dispatched::Handled);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::config::spawn_blocking;
+use crate::ids::EventId;
+use crate::params::Timestamp;
+
+/// What is known about an event which was received before.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EventStatus {
+    /// The event was processed successfully, and should not be processed again.
+    Processed,
+    /// Processing the event failed, so it should be processed again when it is redelivered.
+    Failed {
+        /// How many times processing the event failed.
+        attempts: u32,
+    },
+}
+
+/// Records which events were processed, so that the events stripe delivers more than once
+/// are only processed once.
+///
+/// The methods are async, so that stores backed by a database or a file don't block the
+/// executor of the webhook handler.
+///
+/// See [`EventRouter::dispatch_once`](crate::EventRouter::dispatch_once).
+pub trait ProcessedEventStore: Send + Sync {
+    /// Get the status of an event, if it was received before.
+    fn status(&self, id: &EventId) -> impl Future<Output = io::Result<Option<EventStatus>>> + Send;
+
+    /// Claim an event before processing it, returning `false` if it was processed before or
+    /// is being processed by another delivery.
+    ///
+    /// Checking and claiming the event must be atomic, so that concurrent deliveries of an
+    /// event don't both process it. The claim is released once the outcome is recorded.
+    fn try_begin(&self, id: &EventId) -> impl Future<Output = io::Result<bool>> + Send;
+
+    /// Release the claim on an event without recording an outcome, so that it is processed
+    /// when it is redelivered.
+    ///
+    /// This is called when processing the event is cancelled or panics, from a destructor,
+    /// so it must not block. Stores which keep their claims outside of the process should
+    /// give them a lease instead, and may do nothing here.
+    fn release(&self, id: &EventId);
+
+    /// Record that an event was processed successfully.
+    ///
+    /// `object` is the id of the object of the event, if it has one.
+    fn record_processed(
+        &self,
+        id: &EventId,
+        object: Option<&str>,
+        created: Timestamp,
+    ) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// Record that processing an event failed.
+    fn record_failed(&self, id: &EventId) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// The creation time of the newest event processed for an object.
+    fn last_processed(
+        &self,
+        object: &str,
+    ) -> impl Future<Output = io::Result<Option<Timestamp>>> + Send;
+}
+
+/// A [`ProcessedEventStore`] which keeps the events in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryEventStore {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    events: HashMap<EventId, EventStatus>,
+    objects: HashMap<String, Timestamp>,
+    /// The events claimed by a delivery whose outcome isn't recorded yet.
+    in_flight: HashSet<EventId>,
+}
+
+impl State {
+    fn begin(&mut self, id: &EventId) -> bool {
+        self.events.get(id) != Some(&EventStatus::Processed) && self.in_flight.insert(id.clone())
+    }
+
+    fn processed(&mut self, id: EventId, object: Option<String>, created: Timestamp) {
+        self.in_flight.remove(&id);
+        self.events.insert(id, EventStatus::Processed);
+        if let Some(object) = object {
+            let last = self.objects.entry(object).or_insert(created);
+            *last = created.max(*last);
+        }
+    }
+
+    fn failed(&mut self, id: EventId) {
+        self.in_flight.remove(&id);
+        let status = self.events.entry(id).or_insert(EventStatus::Failed { attempts: 0 });
+        if let EventStatus::Failed { attempts } = status {
+            *attempts += 1;
+        }
+    }
+}
+
+impl InMemoryEventStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // the state is valid even if another thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl ProcessedEventStore for InMemoryEventStore {
+    async fn status(&self, id: &EventId) -> io::Result<Option<EventStatus>> {
+        Ok(self.state().events.get(id).copied())
+    }
+
+    async fn try_begin(&self, id: &EventId) -> io::Result<bool> {
+        Ok(self.state().begin(id))
+    }
+
+    fn release(&self, id: &EventId) {
+        self.state().in_flight.remove(id);
+    }
+
+    async fn record_processed(
+        &self,
+        id: &EventId,
+        object: Option<&str>,
+        created: Timestamp,
+    ) -> io::Result<()> {
+        self.state().processed(id.clone(), object.map(str::to_owned), created);
+        Ok(())
+    }
+
+    async fn record_failed(&self, id: &EventId) -> io::Result<()> {
+        self.state().failed(id.clone());
+        Ok(())
+    }
+
+    async fn last_processed(&self, object: &str) -> io::Result<Option<Timestamp>> {
+        Ok(self.state().objects.get(object).copied())
+    }
+}
+
+/// A [`ProcessedEventStore`] which appends each record to a file, one JSON object per line.
+///
+/// The file is read into memory when the store is opened. The records are appended and
+/// synced off the executor, on the blocking thread pool of the runtime, or on a thread of
+/// their own with async-std. This is meant as a reference for implementing a store on top
+/// of a database, and for applications with a single process.
+#[derive(Debug)]
+pub struct FileEventStore {
+    memory: InMemoryEventStore,
+    file: Arc<Mutex<File>>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Record {
+    Processed { id: EventId, object: Option<String>, created: Timestamp },
+    Failed { id: EventId },
+}
+
+impl FileEventStore {
+    /// Open the store at the given path, creating the file if it doesn't exist.
+    ///
+    /// A partial last line, left by a crash while a record was appended, is removed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file can't be opened, or if it contains
+    /// a complete line which isn't a valid record.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(path)?;
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+
+        // records are only valid once their line ends
+        let complete = contents.iter().rposition(|byte| *byte == b'\n').map_or(0, |i| i + 1);
+        if complete < contents.len() {
+            file.set_len(complete as u64)?;
+        }
+
+        let mut state = State::default();
+        for line in contents[..complete].split(|byte| *byte == b'\n') {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            match serde_json::from_slice(line)? {
+                Record::Processed { id, object, created } => state.processed(id, object, created),
+                Record::Failed { id } => state.failed(id),
+            }
+        }
+
+        Ok(Self {
+            memory: InMemoryEventStore { state: Mutex::new(state) },
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+
+    /// Append a record to the file, releasing the claim on its event if this fails.
+    async fn append(&self, id: &EventId, record: Record) -> io::Result<()> {
+        let file = self.file.clone();
+        let res = spawn_blocking(move || {
+            let mut line = serde_json::to_vec(&record)?;
+            line.push(b'\n');
+            let mut file = file.lock().unwrap_or_else(|err| err.into_inner());
+            file.write_all(&line)?;
+            file.sync_data()
+        })
+        .await;
+
+        if res.is_err() {
+            self.memory.release(id);
+        }
+        res
+    }
+}
+
+impl ProcessedEventStore for FileEventStore {
+    async fn status(&self, id: &EventId) -> io::Result<Option<EventStatus>> {
+        self.memory.status(id).await
+    }
+
+    async fn try_begin(&self, id: &EventId) -> io::Result<bool> {
+        // N.B. claims are only kept in memory, an event claimed when the process stopped
+        //      is processed again
+        self.memory.try_begin(id).await
+    }
+
+    fn release(&self, id: &EventId) {
+        self.memory.release(id);
+    }
+
+    async fn record_processed(
+        &self,
+        id: &EventId,
+        object: Option<&str>,
+        created: Timestamp,
+    ) -> io::Result<()> {
+        let record =
+            Record::Processed { id: id.clone(), object: object.map(str::to_owned), created };
+        self.append(id, record).await?;
+        self.memory.record_processed(id, object, created).await
+    }
+
+    async fn record_failed(&self, id: &EventId) -> io::Result<()> {
+        self.append(id, Record::Failed { id: id.clone() }).await?;
+        self.memory.record_failed(id).await
+    }
+
+    async fn last_processed(&self, object: &str) -> io::Result<Option<Timestamp>> {
+        self.memory.last_processed(object).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::{EventStatus, FileEventStore, InMemoryEventStore, ProcessedEventStore};
+    use crate::EventId;
+
+    #[tokio::test]
+    async fn in_memory_store() {
+        let store = InMemoryEventStore::new();
+        let id: EventId = "evt_123".parse().unwrap();
+
+        assert_eq!(store.status(&id).await.unwrap(), None);
+        store.record_failed(&id).await.unwrap();
+        store.record_failed(&id).await.unwrap();
+        assert_eq!(store.status(&id).await.unwrap(), Some(EventStatus::Failed { attempts: 2 }));
+
+        store.record_processed(&id, Some("sub_123"), 20).await.unwrap();
+        store.record_processed(&"evt_122".parse().unwrap(), Some("sub_123"), 10).await.unwrap();
+        assert_eq!(store.status(&id).await.unwrap(), Some(EventStatus::Processed));
+        assert_eq!(store.last_processed("sub_123").await.unwrap(), Some(20));
+        assert_eq!(store.last_processed("sub_456").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn try_begin() {
+        let store = InMemoryEventStore::new();
+        let id: EventId = "evt_123".parse().unwrap();
+
+        // only one delivery claims the event until its outcome is recorded
+        assert!(store.try_begin(&id).await.unwrap());
+        assert!(!store.try_begin(&id).await.unwrap());
+        store.record_failed(&id).await.unwrap();
+
+        assert!(store.try_begin(&id).await.unwrap());
+        store.record_processed(&id, None, 10).await.unwrap();
+        assert!(!store.try_begin(&id).await.unwrap());
+
+        // releasing a claim lets the next delivery claim the event
+        let id: EventId = "evt_456".parse().unwrap();
+        assert!(store.try_begin(&id).await.unwrap());
+        store.release(&id);
+        assert!(store.try_begin(&id).await.unwrap());
+        assert_eq!(store.status(&id).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn file_store() {
+        let path = std::env::temp_dir().join(format!("stripe-events-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = FileEventStore::open(&path).unwrap();
+        store.record_failed(&"evt_123".parse().unwrap()).await.unwrap();
+        store.record_processed(&"evt_456".parse().unwrap(), Some("cus_123"), 10).await.unwrap();
+        drop(store);
+
+        // a crash while appending leaves a partial line, which is removed
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"status":"processed","id":"evt_7"#).unwrap();
+        drop(file);
+
+        // the records are read back when the store is opened again
+        let store = FileEventStore::open(&path).unwrap();
+        let status = store.status(&"evt_123".parse().unwrap()).await.unwrap();
+        assert_eq!(status, Some(EventStatus::Failed { attempts: 1 }));
+        let status = store.status(&"evt_456".parse().unwrap()).await.unwrap();
+        assert_eq!(status, Some(EventStatus::Processed));
+        assert_eq!(store.last_processed("cus_123").await.unwrap(), Some(10));
+
+        store.record_processed(&"evt_789".parse().unwrap(), None, 20).await.unwrap();
+        drop(store);
+        let store = FileEventStore::open(&path).unwrap();
+        let status = store.status(&"evt_789".parse().unwrap()).await.unwrap();
+        assert_eq!(status, Some(EventStatus::Processed));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::error::WebhookError;
+use crate::generated::core::version::VERSION;
+use crate::ids::EventId;
+use crate::params::Timestamp;
+use crate::resources::{Event, EventObject, EventType, NotificationEventData, Webhook};
+
+/// A builder for signed webhook events, used to test webhook handlers without
+/// having to receive events from stripe.
+///
+/// ```
+/// use stripe::{Customer, EventObject, EventType, TestEvent, Webhook};
+///
+/// let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+/// let signed = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+///     .sign("whsec_123")
+///     .unwrap();
+///
+/// let event = Webhook::construct_event(&signed.payload, &signed.signature, "whsec_123").unwrap();
+/// assert_eq!(event.type_, EventType::CustomerCreated);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TestEvent {
+    event: Event,
+}
+
+/// The payload of a webhook request, along with its `Stripe-Signature` header.
+#[derive(Clone, Debug)]
+pub struct SignedEvent {
+    /// The serialized event, to be sent as the body of the request.
+    pub payload: String,
+    /// The value of the `Stripe-Signature` header.
+    pub signature: String,
+}
+
+impl TestEvent {
+    /// Create an event of the given type, created now in test mode.
+    pub fn new(type_: EventType, object: EventObject) -> Self {
+        Self {
+            event: Event {
+                id: "evt_test".parse().unwrap_or_default(),
+                api_version: Some(VERSION.to_string()),
+                created: Utc::now().timestamp(),
+                data: NotificationEventData { object, previous_attributes: None },
+                type_,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the id of the event.
+    pub fn id(mut self, id: EventId) -> Self {
+        self.event.id = id;
+        self
+    }
+
+    /// Set the connected account the event originates from.
+    pub fn account(mut self, account: impl Into<String>) -> Self {
+        self.event.account = Some(account.into());
+        self
+    }
+
+    /// Set the time at which the event was created.
+    pub fn created(mut self, created: Timestamp) -> Self {
+        self.event.created = created;
+        self
+    }
+
+    /// Set whether the event happened in live mode.
+    pub fn livemode(mut self, livemode: bool) -> Self {
+        self.event.livemode = livemode;
+        self
+    }
+
+    /// Set the values of the attributes which changed, for `*.updated` events.
+    pub fn previous_attributes(mut self, attributes: HashMap<String, Value>) -> Self {
+        self.event.data.previous_attributes = Some(attributes);
+        self
+    }
+
+    /// Get the event, without serializing it.
+    pub fn into_event(self) -> Event {
+        self.event
+    }
+
+    /// Serialize the event, signing it with the given secret at the current time.
+    ///
+    /// # Errors
+    ///
+    /// See [`TestEvent::sign_with_timestamp`].
+    pub fn sign(&self, secret: &str) -> Result<SignedEvent, WebhookError> {
+        self.sign_with_timestamp(secret, Utc::now().timestamp())
+    }
+
+    /// Serialize the event, signing it with the given secret at the given time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if the event can't be serialized or
+    /// if the secret is invalid.
+    pub fn sign_with_timestamp(
+        &self,
+        secret: &str,
+        timestamp: i64,
+    ) -> Result<SignedEvent, WebhookError> {
+        let payload = serde_json::to_string(&self.event)?;
+        let signature = Webhook::generate_test_header(&payload, secret, timestamp)?;
+        Ok(SignedEvent { payload, signature })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::TestEvent;
+    use crate::{Customer, EventObject, EventType, Webhook, WebhookError};
+
+    #[test]
+    fn signed_event_round_trip() {
+        let customer = Customer {
+            id: "cus_123".parse().unwrap(),
+            email: Some("test@example.com".to_string()),
+            ..Default::default()
+        };
+        let signed = TestEvent::new(EventType::CustomerUpdated, EventObject::Customer(customer))
+            .id("evt_123".parse().unwrap())
+            .account("acct_123")
+            .previous_attributes(HashMap::from([("email".to_string(), serde_json::Value::Null)]))
+            .sign_with_timestamp("whsec_123", 1533204620)
+            .unwrap();
+
+        let event = Webhook::construct_event_with_timestamp(
+            &signed.payload,
+            &signed.signature,
+            "whsec_123",
+            1533204620,
+        )
+        .unwrap();
+
+        assert_eq!(event.id, "evt_123".parse::<crate::EventId>().unwrap());
+        assert_eq!(event.type_, EventType::CustomerUpdated);
+        assert_eq!(event.account.as_deref(), Some("acct_123"));
+        assert!(event.data.previous_attributes.unwrap().contains_key("email"));
+        match event.data.object {
+            EventObject::Customer(customer) => {
+                assert_eq!(customer.email.as_deref(), Some("test@example.com"))
+            }
+            object => panic!("unexpected object {:?}", object),
+        }
+
+        let res = Webhook::construct_event_with_timestamp(
+            &signed.payload,
+            &signed.signature,
+            "whsec_other",
+            1533204620,
+        );
+        assert!(matches!(res, Err(WebhookError::BadSignature)));
+    }
+}
@@ -0,0 +1,346 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use hmac::Mac;
+use serde::Deserialize;
+
+use crate::error::{ApiVersionMismatch, WebhookVerifyError};
+use crate::generated::core::version::VERSION;
+use crate::params::Timestamp;
+use crate::resources::webhook_events::Signature;
+use crate::resources::{Event, Webhook};
+
+/// The name of the header containing the signatures of a webhook.
+pub const SIGNATURE_HEADER: &str = "Stripe-Signature";
+
+/// A source of the current time, used to check the timestamp of webhook signatures.
+///
+/// This is implemented for closures returning a [`Timestamp`], which makes it possible
+/// to verify recorded webhooks in tests.
+pub trait WebhookClock: Send + Sync {
+    /// The current time, in seconds since the unix epoch.
+    fn now(&self) -> Timestamp;
+}
+
+impl<F: Fn() -> Timestamp + Send + Sync> WebhookClock for F {
+    fn now(&self) -> Timestamp {
+        self()
+    }
+}
+
+/// A [`WebhookClock`] using the system time.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl WebhookClock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Utc::now().timestamp()
+    }
+}
+
+/// Verifies the signatures of webhooks, configured once with the endpoint secrets.
+///
+/// Several secrets can be provided while an endpoint secret is being rolled.
+///
+/// ```
+/// use stripe::{Customer, EventObject, EventType, TestEvent, WebhookVerifier};
+///
+/// let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+/// let signed = TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+///     .sign_with_timestamp("whsec_123", 1533204620)
+///     .unwrap();
+///
+/// let verifier = WebhookVerifier::new("whsec_123").clock(|| 1533204620);
+/// let event = verifier.construct_event(signed.payload.as_bytes(), &signed.signature).unwrap();
+/// assert_eq!(event.type_, EventType::CustomerCreated);
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secrets: Vec<String>,
+    tolerance: Duration,
+    clock: Arc<dyn WebhookClock>,
+    strict: bool,
+}
+
+impl WebhookVerifier {
+    /// Create a verifier for the given endpoint secret, using the system time and the
+    /// default tolerance of 5 minutes.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secrets: vec![secret.into()],
+            tolerance: Webhook::DEFAULT_TOLERANCE,
+            clock: Arc::new(SystemClock),
+            strict: false,
+        }
+    }
+
+    /// Also accept webhooks signed with the given endpoint secret.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// Set how far the timestamp of a signature may be from the current time.
+    ///
+    /// This applies to timestamps ahead of the current time as well, so that a captured
+    /// webhook signed with a timestamp in the future can't be replayed indefinitely.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the clock used to get the current time.
+    pub fn clock(mut self, clock: impl WebhookClock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set whether events rendered with another api version than the one this crate
+    /// matches are rejected, instead of parsing the fields the models have in common.
+    ///
+    /// The version is checked before parsing the event, so the mismatch is reported even
+    /// if the event doesn't parse with the models of this crate.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Create a verifier accepting any of the given endpoint secrets.
+    pub(crate) fn with_secrets(secrets: &[&str]) -> Self {
+        Self {
+            secrets: secrets.iter().map(|secret| secret.to_string()).collect(),
+            tolerance: Webhook::DEFAULT_TOLERANCE,
+            clock: Arc::new(SystemClock),
+            strict: false,
+        }
+    }
+
+    /// Verify the signature of the raw bytes of a webhook payload, given the value of
+    /// its `Stripe-Signature` header.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the header is malformed, if none of its
+    /// signatures match any of the secrets, or if its timestamp is further from the current
+    /// time than the tolerance.
+    pub fn verify(&self, payload: &[u8], signature: &str) -> Result<(), WebhookVerifyError> {
+        let signature =
+            Signature::parse(signature).map_err(|_| WebhookVerifyError::MalformedHeader)?;
+        self.verify_signature(payload, &signature)
+    }
+
+    /// Verify the signatures of a parsed `Stripe-Signature` header.
+    pub(crate) fn verify_signature(
+        &self,
+        payload: &[u8],
+        signature: &Signature<'_>,
+    ) -> Result<(), WebhookVerifyError> {
+        let signatures: Vec<Vec<u8>> =
+            signature.v1.iter().filter_map(|v1| hex::decode(v1).ok()).collect();
+
+        let mut verified = false;
+        for secret in &self.secrets {
+            let mac = Webhook::mac(payload, secret, signature.t)
+                .map_err(|_| WebhookVerifyError::InvalidSecret)?;
+
+            // N.B. `verify_slice` compares the signatures in constant time
+            verified |= signatures.iter().any(|sig| mac.clone().verify_slice(sig).is_ok());
+        }
+        if !verified {
+            return Err(WebhookVerifyError::NoMatchingSignature);
+        }
+
+        let now = self.clock.now();
+        if now.abs_diff(signature.t) > self.tolerance.as_secs() {
+            return Err(WebhookVerifyError::OutsideTolerance { timestamp: signature.t, now });
+        }
+        Ok(())
+    }
+
+    /// Verify a webhook payload, returning the event it contains.
+    ///
+    /// # Errors
+    ///
+    /// See [`WebhookVerifier::verify`]. This function will also return an error if the
+    /// payload isn't a valid event, or in strict mode if the api version of the event
+    /// isn't the version of this crate.
+    pub fn construct_event(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<Event, WebhookVerifyError> {
+        self.verify(payload, signature)?;
+        self.parse_event(payload)
+    }
+
+    /// Parse a verified webhook payload, checking its api version first in strict mode.
+    pub(crate) fn parse_event(&self, payload: &[u8]) -> Result<Event, WebhookVerifyError> {
+        if self.strict {
+            #[derive(Deserialize)]
+            struct Version {
+                api_version: Option<String>,
+            }
+
+            let Version { api_version } = serde_json::from_slice(payload)?;
+            if api_version.as_deref() != Some(VERSION.as_str()) {
+                return Err(WebhookVerifyError::ApiVersionMismatch(ApiVersionMismatch {
+                    expected: VERSION,
+                    found: api_version,
+                }));
+            }
+        }
+        Ok(serde_json::from_slice(payload)?)
+    }
+
+    /// Verify a webhook payload, reading the signature from the headers of the request.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the `Stripe-Signature` header is missing or
+    /// isn't valid UTF-8, and otherwise as [`WebhookVerifier::verify`].
+    #[cfg(feature = "http")]
+    pub fn verify_headers(
+        &self,
+        headers: &http::HeaderMap,
+        payload: &[u8],
+    ) -> Result<(), WebhookVerifyError> {
+        self.verify(payload, signature_header(headers)?)
+    }
+
+    /// Verify a webhook payload, reading the signature from the headers of the request,
+    /// and return the event it contains.
+    ///
+    /// # Errors
+    ///
+    /// See [`WebhookVerifier::verify_headers`] and [`WebhookVerifier::construct_event`].
+    #[cfg(feature = "http")]
+    pub fn construct_event_from_headers(
+        &self,
+        headers: &http::HeaderMap,
+        payload: &[u8],
+    ) -> Result<Event, WebhookVerifyError> {
+        self.construct_event(payload, signature_header(headers)?)
+    }
+
+    /// Verify a buffered webhook request, returning the event it contains.
+    ///
+    /// # Errors
+    ///
+    /// See [`WebhookVerifier::construct_event_from_headers`].
+    #[cfg(feature = "http")]
+    pub fn construct_event_from_request(
+        &self,
+        request: &http::Request<bytes::Bytes>,
+    ) -> Result<Event, WebhookVerifyError> {
+        self.construct_event_from_headers(request.headers(), request.body())
+    }
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // N.B. the secrets are left out so they don't end up in logs
+        f.debug_struct("WebhookVerifier")
+            .field("secrets", &self.secrets.len())
+            .field("tolerance", &self.tolerance)
+            .field("strict", &self.strict)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "http")]
+fn signature_header(headers: &http::HeaderMap) -> Result<&str, WebhookVerifyError> {
+    let header = headers.get(SIGNATURE_HEADER).ok_or(WebhookVerifyError::MissingHeader)?;
+    header.to_str().map_err(|_| WebhookVerifyError::MalformedHeader)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::WebhookVerifier;
+    use crate::{
+        Customer, EventObject, EventType, SignedEvent, TestEvent, Webhook, WebhookVerifyError,
+    };
+
+    const NOW: i64 = 1533204620;
+
+    fn signed(secret: &str, timestamp: i64) -> SignedEvent {
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        TestEvent::new(EventType::CustomerCreated, EventObject::Customer(customer))
+            .sign_with_timestamp(secret, timestamp)
+            .unwrap()
+    }
+
+    #[test]
+    fn verify() {
+        let verifier = WebhookVerifier::new("whsec_old").secret("whsec_new").clock(|| NOW);
+        let event = signed("whsec_new", NOW - 60);
+
+        let res = verifier.construct_event(event.payload.as_bytes(), &event.signature);
+        assert_eq!(res.unwrap().type_, EventType::CustomerCreated);
+
+        let res = verifier.verify(event.payload.as_bytes(), "v1=abc");
+        assert!(matches!(res, Err(WebhookVerifyError::MalformedHeader)));
+
+        let res = verifier.verify(b"{}", &event.signature);
+        assert!(matches!(res, Err(WebhookVerifyError::NoMatchingSignature)));
+
+        let event = signed("whsec_old", NOW - 600);
+        let res = verifier.verify(event.payload.as_bytes(), &event.signature);
+        assert!(matches!(
+            res,
+            Err(WebhookVerifyError::OutsideTolerance { timestamp, now })
+                if timestamp == NOW - 600 && now == NOW
+        ));
+
+        let verifier = verifier.tolerance(Duration::from_secs(900));
+        assert!(verifier.verify(event.payload.as_bytes(), &event.signature).is_ok());
+
+        // timestamps in the future are bounded by the tolerance too
+        let event = signed("whsec_old", NOW + 600);
+        assert!(verifier.verify(event.payload.as_bytes(), &event.signature).is_ok());
+        let event = signed("whsec_old", NOW + 3600);
+        let res = verifier.verify(event.payload.as_bytes(), &event.signature);
+        assert!(matches!(res, Err(WebhookVerifyError::OutsideTolerance { .. })));
+    }
+
+    #[test]
+    fn strict() {
+        let verifier = WebhookVerifier::new("whsec_123").clock(|| NOW).strict(true);
+        let event = signed("whsec_123", NOW);
+        let res = verifier.construct_event(event.payload.as_bytes(), &event.signature);
+        assert_eq!(res.unwrap().type_, EventType::CustomerCreated);
+
+        // the version is checked before the event is parsed
+        let payload = r#"{"id": "evt_123", "api_version": "2017-05-25", "data": 123}"#;
+        let signature = Webhook::generate_test_header(payload, "whsec_123", NOW).unwrap();
+        let res = verifier.construct_event(payload.as_bytes(), &signature);
+        assert!(matches!(
+            res,
+            Err(WebhookVerifyError::ApiVersionMismatch(mismatch))
+                if mismatch.found.as_deref() == Some("2017-05-25")
+        ));
+
+        let res = verifier.strict(false).construct_event(payload.as_bytes(), &signature);
+        assert!(matches!(res, Err(WebhookVerifyError::BadParse(_))));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn verify_request() {
+        let verifier = WebhookVerifier::new("whsec_123").clock(|| NOW);
+        let event = signed("whsec_123", NOW);
+
+        let request = http::Request::post("/webhooks")
+            .header("stripe-signature", &event.signature)
+            .body(bytes::Bytes::from(event.payload.clone()))
+            .unwrap();
+        let res = verifier.construct_event_from_request(&request);
+        assert_eq!(res.unwrap().type_, EventType::CustomerCreated);
+
+        let res = verifier.verify_headers(&http::HeaderMap::new(), event.payload.as_bytes());
+        assert!(matches!(res, Err(WebhookVerifyError::MissingHeader)));
+    }
+}